@@ -32,14 +32,14 @@ impl Freq {
     }
 }
 
-fn part1(input_location: &str) -> i32 {
+pub(crate) fn part1(input_location: &str) -> i32 {
     let mut freq = Freq::new();
     let data = input_reader::read_all_lines(input_location);
     let input = data.iter().map(|s| &s[..]).collect::<Vec<&str>>();
     freq.calibrate_str_all(&input[..])
 }
 
-fn part2(input_location: &str) -> i32 {
+pub(crate) fn part2(input_location: &str) -> i32 {
     use std::collections::HashSet;
 
     let mut freq = Freq::new();
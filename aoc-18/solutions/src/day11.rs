@@ -1,7 +1,7 @@
 use rayon::prelude::*;
 use std::collections::HashMap;
 
-type PointT = (isize, isize); // (x,y)
+pub(crate) type PointT = (isize, isize); // (x,y)
 type DimensionT = (usize, usize); //(width, height)
 
 struct FuelCell {
@@ -80,12 +80,12 @@ impl Battery {
     }
 }
 
-fn part1(input: usize) -> PointT{
+pub(crate) fn part1(input: usize) -> PointT{
     let bt = Battery::new((300, 300), input);
     bt.get_max_power(3).0
 }
 
-fn part2(input: usize) -> (PointT, usize) {
+pub(crate) fn part2(input: usize) -> (PointT, usize) {
     let bt = Battery::new((300, 300), input);
     let v = (1..=300).collect::<Vec<usize>>();
     let ((pos, _power), size) = v.par_iter().map(|size| (bt.get_max_power(*size), size)).max_by_key(|&((_pos, pow), _size)| pow).unwrap();
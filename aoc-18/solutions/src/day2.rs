@@ -4,7 +4,7 @@ use std::collections::HashMap;
 type Map = HashMap<char, u32>;
 type Pair = Option<(String, String)>;
 
-struct Checksum {
+pub(crate) struct Checksum {
     doubles: u32,
     triples: u32,
     processed_inputs: Vec<String>,
@@ -39,11 +39,11 @@ impl Checksum {
         self.processed_inputs.push(String::from(input));
     }
 
-    fn get_checksum(&self) -> u32 {
+    pub(crate) fn get_checksum(&self) -> u32 {
         self.doubles * self.triples
     }
 
-    fn get_common_chars(&self) -> String {
+    pub(crate) fn get_common_chars(&self) -> String {
         let (l, r) = self.closest_pair.clone().unwrap();
 
         l.chars()
@@ -80,7 +80,7 @@ impl Checksum {
     }
 }
 
-fn do_the_job(input_location: &str) -> Checksum {
+pub(crate) fn do_the_job(input_location: &str) -> Checksum {
     let data = input_reader::read_all_lines(input_location);
     let mut checksum = Checksum::new();
 
@@ -128,7 +128,7 @@ impl Fabric {
     }
 }
 
-fn do_the_job(input_location: &str) -> (u32, u32) {
+pub(crate) fn do_the_job(input_location: &str) -> (u32, u32) {
     let data = input_reader::read_all_lines(input_location);
     let mut fabric = Fabric::new();
 
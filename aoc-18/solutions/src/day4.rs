@@ -6,7 +6,7 @@ type GuardId = u32;
 type Minutes = HashSet<u32>;
 type Shifts = Vec<Shift>;
 
-struct Guard {
+pub(crate) struct Guard {
     id: GuardId,
     shifts: Shifts,
 }
@@ -69,7 +69,7 @@ impl Shift {
     }
 }
 
-fn do_the_job(input_location: &str) -> HashMap<u32, Guard> {
+pub(crate) fn do_the_job(input_location: &str) -> HashMap<u32, Guard> {
     let mut data = input_reader::read_all_lines(input_location);
     data.sort();
     let mut guards: HashMap<u32, Guard> = HashMap::new();
@@ -118,7 +118,7 @@ fn do_the_job(input_location: &str) -> HashMap<u32, Guard> {
     guards
 }
 
-fn part1(guards: &HashMap<u32, Guard>) -> u32 {
+pub(crate) fn part1(guards: &HashMap<u32, Guard>) -> u32 {
     let g_id = guards
         .iter()
         .map(|(k, v)| (k, v.total_minutes_slept()))
@@ -131,7 +131,7 @@ fn part1(guards: &HashMap<u32, Guard>) -> u32 {
     guard.id * guard.most_slept_minute().0
 }
 
-fn part2(guards: &HashMap<u32, Guard>) -> u32 {
+pub(crate) fn part2(guards: &HashMap<u32, Guard>) -> u32 {
     let g_id = guards
         .iter()
         .map(|(k, v)| (k, v.most_slept_minute().1))
@@ -42,11 +42,11 @@ fn do_the_job(input: String) -> usize {
     polymer.replace(" ", "").chars().count()
 }
 
-fn part1(input: &str) -> usize {
+pub(crate) fn part1(input: &str) -> usize {
     do_the_job(input_reader::read_all(input))
 }
 
-fn part2(input: &str) -> usize {
+pub(crate) fn part2(input: &str) -> usize {
     let data = input_reader::read_all(input);
     let symbols = get_symbols(&data);
     symbols
@@ -104,7 +104,7 @@ fn point(input: &str) -> Point {
     )
 }
 
-fn part1(input_location: &str) -> (Point, u32) {
+pub(crate) fn part1(input_location: &str) -> (Point, u32) {
     use self::Closest::*;
     use std::collections::HashMap;
 
@@ -128,7 +128,7 @@ fn part1(input_location: &str) -> (Point, u32) {
         .unwrap()
 }
 
-fn part2(input_location: &str) -> usize {
+pub(crate) fn part2(input_location: &str) -> usize {
     let data = input_reader::read_all_lines(input_location);
     let points = data.into_iter().map(|s| point(&s)).collect::<Vec<_>>(); //make points from user input
     let bound_box = BoundingBox::determine_box(&points);
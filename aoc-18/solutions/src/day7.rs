@@ -115,7 +115,7 @@ impl Workers {
     }
 }
 
-fn part1(input: &str) -> String {
+pub(crate) fn part1(input: &str) -> String {
     let data = input_reader::read_all_lines(input);
     let mut steps = HashMap::new();
 
@@ -145,7 +145,7 @@ fn part1(input: &str) -> String {
     result
 }
 
-fn part2(input: &str) -> u32 {
+pub(crate) fn part2(input: &str) -> u32 {
     let data = input_reader::read_all_lines(input);
     let mut steps = HashMap::new();
     let mut workers = Workers::new(5);
@@ -46,7 +46,7 @@ impl Node {
     }
 }
 
-fn do_the_job(input: &str) -> (usize, usize) {
+pub(crate) fn do_the_job(input: &str) -> (usize, usize) {
     let data = input_reader::read_all(input);
     let parsed = data
         .trim()
@@ -55,7 +55,7 @@ impl<'a> Circle<'a> {
     }
 }
 
-fn part1(input: &str) -> usize {
+pub(crate) fn part1(input: &str) -> usize {
     let data = input_reader::read_all(input);
     let arr = data.split_whitespace().collect::<Vec<_>>();
     let players = arr[0].parse::<usize>().unwrap();
@@ -67,7 +67,7 @@ fn part1(input: &str) -> usize {
     game.winning_score()
 }
 
-fn part2(input: &str) -> usize {
+pub(crate) fn part2(input: &str) -> usize {
     let data = input_reader::read_all(input);
     let arr = data.split_whitespace().collect::<Vec<_>>();
     let players = arr[0].parse::<usize>().unwrap();
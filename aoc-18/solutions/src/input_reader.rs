@@ -1,5 +1,6 @@
 use std::fs::File;
 use std::io::prelude::*;
+use std::path::Path;
 
 pub fn read_all(file_name: &str) -> String {
     let mut f = open_file(file_name);
@@ -15,7 +16,11 @@ pub fn read_all_lines(file_name: &str) -> Vec<String> {
     read_all(file_name).lines().map(|s| s.to_owned()).collect()
 }
 
+// plain names like "day5" are looked up in the input folder, anything else is taken as a path
 fn open_file(file_name: &str) -> File {
+    if Path::new(file_name).is_file() {
+        return File::open(file_name).expect("file not found");
+    }
     let relative_path = "input/".to_owned() + file_name;
     File::open(relative_path).expect("file not found")
 }
@@ -1,23 +1,9 @@
 #![feature(try_trait)]
-#![feature(vec_remove_item)]
 
-mod day1;
-mod day10;
-mod day2;
-mod day3;
-mod day4;
-mod day5;
-mod day6;
-mod day7;
-mod day8;
-mod day9;
-mod day11;
-mod day12;
-mod input_reader;
-
-use elapsed::measure_time;
 use lazy_static::lazy_static;
+use solutions::{day1, day10, day11, day12, day2, day3, day4, day5, day6, day7, day8, day9};
 use std::collections::HashMap;
+use std::time::Instant;
 
 fn main() -> Result<(), std::option::NoneError> {
     use std::env;
@@ -30,8 +16,14 @@ fn main() -> Result<(), std::option::NoneError> {
         .collect::<Vec<(&str, &fn())>>();
     v.sort_by_key(|k| k.0);
 
-    let (elapsed, _) = measure_time(|| v.iter().for_each(|t| t.1()));
-    println!("Run Time = {}", elapsed);
+    // per part timings with warmup and baselines: `aoc bench 2018`
+    let start = Instant::now();
+    for (name, day) in v {
+        let day_start = Instant::now();
+        day();
+        println!("{} Run Time = {:.1?}", name, day_start.elapsed());
+    }
+    println!("Run Time = {:.1?}", start.elapsed());
 
     Ok(())
 }
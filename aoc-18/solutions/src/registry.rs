@@ -0,0 +1,92 @@
+use aoc_core::{FnSolution, Registry};
+use std::path::Path;
+
+use crate::{day1, day11, day2, day3, day4, day5, day6, day7, day8, day9};
+
+pub const YEAR: u16 = 2018;
+
+// days 10 and 12 only print their results and are left out
+pub fn register(registry: &mut Registry) {
+    registry.register(
+        YEAR,
+        1,
+        FnSolution::from_path(location)
+            .part1(|loc| day1::part1(loc))
+            .part2(|loc| day1::part2(loc)),
+    );
+    registry.register(
+        YEAR,
+        2,
+        FnSolution::from_path(|path| location(path).map(|loc| day2::do_the_job(&loc)))
+            .part1(|checksum| checksum.get_checksum())
+            .part2(|checksum| checksum.get_common_chars()),
+    );
+    registry.register(
+        YEAR,
+        3,
+        FnSolution::from_path(|path| location(path).map(|loc| day3::do_the_job(&loc)))
+            .part1(|&(overlap, _)| overlap)
+            .part2(|&(_, claim)| claim),
+    );
+    registry.register(
+        YEAR,
+        4,
+        FnSolution::from_path(|path| location(path).map(|loc| day4::do_the_job(&loc)))
+            .part1(|guards| day4::part1(guards))
+            .part2(|guards| day4::part2(guards)),
+    );
+    registry.register(
+        YEAR,
+        5,
+        FnSolution::from_path(location)
+            .part1(|loc| day5::part1(loc))
+            .part2(|loc| day5::part2(loc)),
+    );
+    registry.register(
+        YEAR,
+        6,
+        FnSolution::from_path(location)
+            .part1(|loc| day6::part1(loc).1)
+            .part2(|loc| day6::part2(loc)),
+    );
+    registry.register(
+        YEAR,
+        7,
+        FnSolution::from_path(location)
+            .part1(|loc| day7::part1(loc))
+            .part2(|loc| day7::part2(loc)),
+    );
+    registry.register(
+        YEAR,
+        8,
+        FnSolution::from_path(|path| location(path).map(|loc| day8::do_the_job(&loc)))
+            .part1(|&(sum, _)| sum)
+            .part2(|&(_, root)| root),
+    );
+    registry.register(
+        YEAR,
+        9,
+        FnSolution::from_path(location)
+            .part1(|loc| day9::part1(loc))
+            .part2(|loc| day9::part2(loc)),
+    );
+    registry.register(
+        YEAR,
+        11,
+        FnSolution::from_text(|text| text.trim().parse::<usize>())
+            .part1(|&serial| {
+                let (x, y) = day11::part1(serial);
+                format!("{},{}", x, y)
+            })
+            .part2(|&serial| {
+                let ((x, y), size) = day11::part2(serial);
+                format!("{},{},{}", x, y, size)
+            }),
+    );
+}
+
+fn location(path: &Path) -> Result<String, String> {
+    path.to_str()
+        .map(str::to_owned)
+        .ok_or_else(|| format!("Input path '{}' is not valid UTF-8", path.display()))
+}
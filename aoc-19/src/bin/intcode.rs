@@ -0,0 +1,374 @@
+use aoc_19::intcode::ascii::Ascii;
+use aoc_19::intcode::asm::assemble;
+use aoc_19::intcode::cfg::{build, to_dot};
+use aoc_19::intcode::debugger::{parse_command, Command, Debugger};
+use aoc_19::intcode::disasm::{disassemble, listing, source, Sweep};
+use aoc_19::intcode::reference;
+use aoc_19::intcode::trace::{first_difference, read_trace, write_trace, Filter, TraceEvent};
+use aoc_19::intcode::{format_program, parse_program, Intcode, OpCode};
+use aoc_core::Stats;
+use std::env;
+use std::fs;
+use std::io::{self, BufRead, Write};
+use std::process;
+use std::time::{Duration, Instant};
+
+const USAGE: &str = "Usage: intcode disasm [--reachable] [--source] <program>
+       intcode asm <source>
+       intcode cfg <program>
+       intcode debug <program> [inputs...]
+       intcode ascii <program> [--script <commands>]
+       intcode trace <program> <trace> [inputs...]
+       intcode replay <trace> [--at <address>] [--op <mnemonic>] [--writes <address>]
+                      [--taken <mnemonic>]
+       intcode compare <trace> <trace>
+       intcode bench [--iterations <n>] <program> [inputs...]
+
+Commands:
+    disasm    print an annotated listing of the program, a linear sweep unless
+              --reachable only decodes what control flow from address 0 reaches,
+              --source leaves out addresses and raw words so `asm` reads it back
+    asm       assemble mnemonics into the comma-separated program format
+    cfg       print the control-flow graph of the reachable code in Graphviz DOT format,
+              writes into the program's own code in red
+    debug     step through the program with breakpoints and watchpoints, 'help' lists
+              the commands and an empty line repeats the last one
+    ascii     run a program that talks in ASCII, typed lines are sent to it and its output
+              printed, --script replays the lines of a file first
+    trace     run the program to halt, recording every executed instruction to a file
+    replay    print a recorded trace, only the instructions matching all filters given
+    compare   show where two traces of the same program first differ
+    bench     time runs of the program to halt, 10 by default after one warmup run, and
+              the same runs on the interpreter from before opcodes were cached";
+
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+    let result = match args.first().map(String::as_str) {
+        Some("disasm") => disasm(&args[1..]),
+        Some("asm") => asm(&args[1..]),
+        Some("cfg") => cfg(&args[1..]),
+        Some("debug") => debug(&args[1..]),
+        Some("ascii") => ascii(&args[1..]),
+        Some("trace") => trace(&args[1..]),
+        Some("replay") => replay(&args[1..]),
+        Some("compare") => compare(&args[1..]),
+        Some("bench") => bench(&args[1..]),
+        Some("help") | Some("--help") | Some("-h") => {
+            println!("{}", USAGE);
+            Ok(())
+        }
+        _ => Err(USAGE.to_string()),
+    };
+
+    if let Err(e) = result {
+        eprintln!("{}", e);
+        process::exit(2);
+    }
+}
+
+fn disasm(args: &[String]) -> Result<(), String> {
+    let mut sweep = Sweep::Linear;
+    let mut as_source = false;
+    let mut path = None;
+    for arg in args {
+        match arg.as_str() {
+            "--reachable" => sweep = Sweep::Reachable,
+            "--source" => as_source = true,
+            _ if path.is_none() => path = Some(arg),
+            _ => return Err(format!("Unexpected argument '{}'\n\n{}", arg, USAGE)),
+        }
+    }
+
+    let program = load(path.ok_or_else(|| USAGE.to_string())?)?;
+    let lines = disassemble(&program, sweep);
+    if as_source {
+        print!("{}", source(&lines));
+    } else {
+        print!("{}", listing(&lines));
+    }
+    Ok(())
+}
+
+fn asm(args: &[String]) -> Result<(), String> {
+    let path = match args {
+        [path] => path,
+        _ => return Err(USAGE.to_string()),
+    };
+
+    let text = read(path)?;
+    let program = assemble(&text).map_err(|e| format!("{}:{}", path, e))?;
+    println!("{}", format_program(&program));
+    Ok(())
+}
+
+fn cfg(args: &[String]) -> Result<(), String> {
+    let path = match args {
+        [path] => path,
+        _ => return Err(USAGE.to_string()),
+    };
+
+    let program = load(path)?;
+    print!("{}", to_dot(&build(&program)));
+    Ok(())
+}
+
+fn debug(args: &[String]) -> Result<(), String> {
+    let (path, inputs) = args.split_first().ok_or_else(|| USAGE.to_string())?;
+    let inputs = numbers(inputs)?;
+    let mut debugger = Debugger::new(Intcode::new(&load(path)?, &inputs, false));
+    println!("{}", debugger.execute(Command::Info));
+
+    let stdin = io::stdin();
+    let mut lines = stdin.lock().lines();
+    let mut last = None;
+    loop {
+        print!("(icdb) ");
+        io::stdout().flush().map_err(|e| e.to_string())?;
+        let line = match lines.next() {
+            Some(line) => line.map_err(|e| e.to_string())?,
+            None => break,
+        };
+
+        let command = if line.trim().is_empty() {
+            match last.clone() {
+                Some(command) => command,
+                None => continue,
+            }
+        } else {
+            match parse_command(&line) {
+                Ok(command) => command,
+                Err(e) => {
+                    println!("{}", e);
+                    continue;
+                }
+            }
+        };
+        if command == Command::Quit {
+            break;
+        }
+        println!("{}", debugger.execute(command.clone()));
+        last = Some(command);
+    }
+    Ok(())
+}
+
+fn ascii(args: &[String]) -> Result<(), String> {
+    let (path, script) = match args {
+        [path] => (path, String::new()),
+        [path, flag, script] if flag == "--script" => (path, read(script)?),
+        _ => return Err(USAGE.to_string()),
+    };
+
+    let mut ascii = Ascii::new(Intcode::new(&load(path)?, &[], false));
+    let mut script = script.lines();
+    let stdin = io::stdin();
+    let mut typed = stdin.lock().lines();
+    loop {
+        print!("{}", ascii.run().map_err(|e| e.to_string())?);
+        io::stdout().flush().map_err(|e| e.to_string())?;
+        if ascii.is_halted() {
+            break;
+        }
+
+        // scripted lines are echoed, so the transcript reads as if they were typed
+        let line = match script.next() {
+            Some(line) => {
+                println!("{}", line);
+                line.to_string()
+            }
+            None => match typed.next() {
+                Some(line) => line.map_err(|e| e.to_string())?,
+                None => {
+                    return Err(String::from(
+                        "Input closed while the program waits for a line",
+                    ))
+                }
+            },
+        };
+        ascii.send_line(&line);
+    }
+    Ok(())
+}
+
+fn trace(args: &[String]) -> Result<(), String> {
+    let (program, trace, inputs) = match args {
+        [program, trace, inputs @ ..] => (program, trace, numbers(inputs)?),
+        _ => return Err(USAGE.to_string()),
+    };
+
+    let mut intcode = Intcode::new(&load(program)?, &inputs, false);
+    intcode.record_trace();
+    let result = intcode.run_to_halt();
+    let events = intcode.take_trace();
+    let file =
+        fs::File::create(trace).map_err(|e| format!("Could not create '{}': {}", trace, e))?;
+    write_trace(&events, io::BufWriter::new(file))
+        .map_err(|e| format!("Could not write '{}': {}", trace, e))?;
+
+    let outputs = intcode
+        .outputs
+        .iter()
+        .map(isize::to_string)
+        .collect::<Vec<_>>();
+    println!("{}", outputs.join(","));
+    eprintln!("Recorded {} instructions to '{}'", events.len(), trace);
+    // the trace up to a fault is the interesting part, so it is written either way
+    result.map_err(|e| e.to_string())
+}
+
+fn replay(args: &[String]) -> Result<(), String> {
+    let mut filters = Vec::new();
+    let mut path = None;
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        let mut value = || {
+            args.next()
+                .ok_or_else(|| format!("'{}' needs a value", arg))
+        };
+        let filter = match arg.as_str() {
+            "--at" => Filter::At(number(value()?)?),
+            "--op" => Filter::Op(mnemonic(value()?)?),
+            "--writes" => Filter::WritesTo(number(value()?)?),
+            "--taken" => Filter::Taken(mnemonic(value()?)?),
+            _ if path.is_none() => {
+                path = Some(arg);
+                continue;
+            }
+            _ => return Err(format!("Unexpected argument '{}'\n\n{}", arg, USAGE)),
+        };
+        filters.push(filter);
+    }
+
+    let events = load_trace(path.ok_or_else(|| USAGE.to_string())?)?;
+    let stdout = io::stdout();
+    let mut out = io::BufWriter::new(stdout.lock());
+    let written = events
+        .iter()
+        .enumerate()
+        .filter(|(_, event)| filters.iter().all(|filter| filter.matches(event)))
+        .try_for_each(|(step, event)| writeln!(out, "{:>10}  {}", step, event));
+    match written {
+        // piped into `head`
+        Err(e) if e.kind() == io::ErrorKind::BrokenPipe => Ok(()),
+        result => result.map_err(|e| e.to_string()),
+    }
+}
+
+fn compare(args: &[String]) -> Result<(), String> {
+    let (a, b) = match args {
+        [a, b] => (load_trace(a)?, load_trace(b)?),
+        _ => return Err(USAGE.to_string()),
+    };
+
+    let step = match first_difference(&a, &b) {
+        Some(step) => step,
+        None => {
+            println!("Traces match for all {} instructions", a.len());
+            return Ok(());
+        }
+    };
+    let show = |event: Option<&TraceEvent>| match event {
+        Some(event) => event.to_string(),
+        None => String::from("  (trace ended)"),
+    };
+    println!("Traces differ at step {}", step);
+    if step > 0 {
+        println!("  both: {}", a[step - 1]);
+    }
+    println!("  a:    {}", show(a.get(step)));
+    println!("  b:    {}", show(b.get(step)));
+    Ok(())
+}
+
+fn bench(args: &[String]) -> Result<(), String> {
+    let (iterations, args) = match args {
+        [flag, n, rest @ ..] if flag == "--iterations" => (number(n)?, rest),
+        _ => (10, args),
+    };
+    let (path, inputs) = args.split_first().ok_or_else(|| USAGE.to_string())?;
+    let program = load(path)?;
+    let inputs = numbers(inputs)?;
+
+    let run = || {
+        let mut intcode = Intcode::new(&program, &inputs, false);
+        let start = Instant::now();
+        intcode.run_to_halt().map_err(|e| e.to_string())?;
+        Ok::<_, String>((start.elapsed(), intcode.outputs))
+    };
+    let run_reference = || {
+        let start = Instant::now();
+        let outputs = reference::run(&program, &inputs).map_err(|e| e.to_string())?;
+        Ok::<_, String>((start.elapsed(), outputs))
+    };
+
+    let (_, outputs) = run()?;
+    if run_reference()?.1 != outputs {
+        return Err(String::from(
+            "The interpreter from before opcodes were cached gives different outputs",
+        ));
+    }
+    let mut samples = Vec::with_capacity(iterations);
+    let mut reference_samples = Vec::with_capacity(iterations);
+    for _ in 0..iterations.max(1) {
+        samples.push(run()?.0);
+        reference_samples.push(run_reference()?.0);
+    }
+
+    // instructions are counted once from a trace, so tracing does not skew the timed runs
+    let mut traced = Intcode::new(&program, &inputs, false);
+    traced.record_trace();
+    traced.run_to_halt().map_err(|e| e.to_string())?;
+    let instructions = traced.take_trace().len();
+
+    let outputs = outputs.iter().map(isize::to_string).collect::<Vec<_>>();
+    println!("Outputs: {}", outputs.join(","));
+    println!("{} instructions", instructions);
+    let stats = Stats::from_samples(samples).expect("At least one iteration");
+    let reference_stats = Stats::from_samples(reference_samples).expect("At least one iteration");
+    for (name, stats) in [("vm", &stats), ("before", &reference_stats)].iter() {
+        println!(
+            "{:<8}min {:?}, median {:?}, max {:?}, {:.1}M instructions/s",
+            name,
+            stats.min,
+            stats.median,
+            stats.max,
+            instructions as f64 / seconds(stats.median) / 1e6
+        );
+    }
+    println!(
+        "{:.1}x faster than before",
+        seconds(reference_stats.median) / seconds(stats.median)
+    );
+    Ok(())
+}
+
+fn seconds(duration: Duration) -> f64 {
+    duration.max(Duration::from_nanos(1)).as_secs_f64()
+}
+
+fn numbers(args: &[String]) -> Result<Vec<isize>, String> {
+    args.iter().map(|arg| number(arg)).collect()
+}
+
+fn number<T: std::str::FromStr>(arg: &str) -> Result<T, String> {
+    arg.parse()
+        .map_err(|_| format!("'{}' is not a valid number here", arg))
+}
+
+fn mnemonic(arg: &str) -> Result<OpCode, String> {
+    OpCode::from_mnemonic(arg).ok_or_else(|| format!("Unknown mnemonic '{}'", arg))
+}
+
+fn load_trace(path: &str) -> Result<Vec<TraceEvent>, String> {
+    let file = fs::File::open(path).map_err(|e| format!("Could not read '{}': {}", path, e))?;
+    read_trace(io::BufReader::new(file)).map_err(|e| format!("'{}': {}", path, e))
+}
+
+fn read(path: &str) -> Result<String, String> {
+    fs::read_to_string(path).map_err(|e| format!("Could not read '{}': {}", path, e))
+}
+
+fn load(path: &str) -> Result<Vec<isize>, String> {
+    let text = read(path)?;
+    parse_program(&text).map_err(|e| format!("'{}' is not an Intcode program: {}", path, e))
+}
@@ -175,12 +175,12 @@ impl Day10Runner {
         Self { path, part }
     }
 
-    fn part1(&self) -> usize {
+    pub(crate) fn part1(&self) -> usize {
         let map = self.load();
         let (_, c) = map.location_with_most_direct_line_of_sight();
         c
     }
-    fn part2(&self) -> usize {
+    pub(crate) fn part2(&self) -> usize {
         let mut map = self.load();
         let (p, _) = map.location_with_most_direct_line_of_sight();
         let last_destroyed = map.destroy_n(&p, 200);
@@ -188,8 +188,8 @@ impl Day10Runner {
     }
 
     fn load(&self) -> Map {
-        let text = crate::input_reader::read_sparated_values_from_input(self.path.as_ref(), "\r\n")
-            .expect("map lines");
+        let text =
+            crate::input_reader::read_lines_from_input(self.path.as_ref()).expect("map lines");
         Map::parsed(&text)
     }
 }
@@ -1,5 +1,5 @@
 use crate::days::*;
-use day5::{Intcode, ProgramResult};
+use crate::intcode::{Intcode, ProgramResult, VmError};
 use std::collections::HashMap;
 use std::fmt;
 
@@ -123,19 +123,19 @@ impl Robot {
     }
 
     // returns number of cells colored at least once
-    fn run(&mut self) -> usize {
+    fn run(&mut self) -> Result<usize, VmError> {
         let mut counter = 0usize;
         loop {
-            match self.brain.run_program() {
+            match self.brain.run_program()? {
                 ProgramResult::Halt => {
                     break;
                 }
-                ProgramResult::OutputPause(Some(val)) => {
+                ProgramResult::OutputPause(val) => {
                     let is_first_paint = !self.paint(self.position, Color::from(val));
                     if is_first_paint {
                         counter += 1;
                     }
-                    if let ProgramResult::OutputPause(Some(rot)) = self.brain.run_program() {
+                    if let ProgramResult::OutputPause(rot) = self.brain.run_program()? {
                         self.rotate(Rotate::from(rot));
                         self.move_robot();
                     } else {
@@ -146,10 +146,9 @@ impl Robot {
                     let input = self.color_at(self.position).to_num();
                     self.brain.add_inputs(&[input])
                 }
-                ProgramResult::OutputPause(None) => panic!("Unexpected output without value"),
             }
         }
-        counter
+        Ok(counter)
     }
 
     fn color_at(&self, index: (isize, isize)) -> Color {
@@ -193,17 +192,17 @@ impl Day11Runner {
         Self { path, part }
     }
 
-    fn part1(&self) -> usize {
+    pub(crate) fn part1(&self) -> Result<usize, VmError> {
         let intcode = self.load();
         let mut robot = Robot::new(intcode, Color::Black);
         robot.run()
     }
-    fn part2(&self) -> usize {
+    // the painted hull spells out the answer
+    pub(crate) fn part2(&self) -> Result<String, VmError> {
         let intcode = self.load();
         let mut robot = Robot::new(intcode, Color::White);
-        let res = robot.run();
-        println!("{}", robot);
-        res
+        robot.run()?;
+        Ok(robot.to_string())
     }
 
     fn load(&self) -> Intcode {
@@ -215,10 +214,13 @@ impl Day11Runner {
 impl Runner for Day11Runner {
     fn run(&self) -> String {
         let result = match self.part {
-            Parts::Part1 => self.part1(),
+            Parts::Part1 => self.part1().map(|count| count.to_string()),
             Parts::Part2 => self.part2(),
         };
 
-        format!("Result: {}", result)
+        match result {
+            Ok(result) => format!("Result: {}", result),
+            Err(e) => format!("Error: {}", e),
+        }
     }
 }
@@ -52,10 +52,6 @@ impl System {
             }
         }
 
-        self.moons
-            .iter()
-            .for_each(|x| println!("{}", x.cycle.unwrap()));
-
         lcm_multiple(
             self.moons
                 .iter()
@@ -232,22 +228,18 @@ impl Day12Runner {
         Self { path, part }
     }
 
-    fn part1(&self) -> u64 {
+    pub(crate) fn part1(&self) -> u64 {
         let mut system = self.load();
         system.simulate_time(1000);
         system.total_energy() as u64
     }
-    fn part2(&self) -> u64 {
-        use std::time::Instant;
-        let now = Instant::now();
+    pub(crate) fn part2(&self) -> u64 {
         let mut system = self.load();
-        let res = system.time_steps_until_initial_position();
-        println!("Time until result {}s", now.elapsed().as_secs());
-        res
+        system.time_steps_until_initial_position()
     }
 
     fn load(&self) -> System {
-        let text = crate::input_reader::read_sparated_values_from_input(self.path.as_ref(), "\r\n");
+        let text = crate::input_reader::read_lines_from_input(self.path.as_ref());
         System::parsed(&text.expect("Could not read instructions"))
     }
 }
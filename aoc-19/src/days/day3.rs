@@ -1,5 +1,5 @@
 use crate::days::*;
-use crate::input_reader::{read_sparated_values_from_input, ParseError, Parser};
+use crate::input_reader::{read_lines_from_input, ParseError, Parser};
 
 enum Direction {
     Right(usize),
@@ -196,16 +196,16 @@ impl Day3Runner {
         Self { path, part }
     }
 
-    fn part1(&self) -> usize {
+    pub(crate) fn part1(&self) -> usize {
         let board = self.load();
         board.closest_wire_intersection()
     }
-    fn part2(&self) -> usize {
+    pub(crate) fn part2(&self) -> usize {
         let board = self.load();
         board.closest_wire_intersection_by_steps()
     }
     fn load(&self) -> CircuitBoard {
-        let text = read_sparated_values_from_input(self.path.as_ref(), "\r\n");
+        let text = read_lines_from_input(self.path.as_ref());
         CircuitBoard::parsed(
             text.expect("Could not read wires")
                 .iter()
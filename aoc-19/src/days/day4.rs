@@ -1,5 +1,5 @@
 use crate::days::*;
-use crate::input_reader::{read_sparated_values_from_input, ParseError, Parser};
+use crate::input_reader::{read_lines_from_input, ParseError, Parser};
 const PASSWORD_LEN: usize = 6;
 
 #[derive(Copy, Clone)]
@@ -132,16 +132,16 @@ impl Day4Runner {
         Self { path, part }
     }
 
-    fn part1(&self) -> usize {
+    pub(crate) fn part1(&self) -> usize {
         let mut secure_contaier = self.load();
         secure_contaier.generate_passwords();
         secure_contaier.valid_password_count()
     }
-    fn part2(&self) -> usize {
+    pub(crate) fn part2(&self) -> usize {
         0
     }
     fn load(&self) -> SecureContainer {
-        let text = read_sparated_values_from_input(self.path.as_ref(), "\r\n");
+        let text = read_lines_from_input(self.path.as_ref());
         SecureContainer::parsed(&text.expect("Could not read wires")[0])
     }
 }
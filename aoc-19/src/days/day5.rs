@@ -1,365 +1,5 @@
 use crate::days::*;
-use std::collections::VecDeque;
-enum ExecutionResult {
-    Halt,
-    Output,
-    Input,
-    Continue,
-}
-
-#[derive(Debug)]
-enum OpCode {
-    Add,
-    Multiply,
-    Halt,
-    Input,
-    Output,
-    JumpIfTrue,
-    JumpIfFalse,
-    LessThan,
-    Equals,
-    RelativeBaseOffset,
-}
-
-impl OpCode {
-    // returns tuple that represents (parameter count, store count)
-    fn parameter_count(&self) -> (usize, bool) {
-        match self {
-            OpCode::Add => (2, true),
-            OpCode::Multiply => (2, true),
-            OpCode::Halt => (0, false),
-            OpCode::Input => (0, true),
-            OpCode::Output => (1, false),
-            OpCode::JumpIfTrue => (2, false),
-            OpCode::JumpIfFalse => (2, false),
-            OpCode::LessThan => (2, true),
-            OpCode::Equals => (2, true),
-            OpCode::RelativeBaseOffset => (1, false),
-        }
-    }
-
-    fn from_value(value: usize) -> Option<Self> {
-        match value {
-            1 => Some(Self::Add),
-            2 => Some(Self::Multiply),
-            3 => Some(Self::Input),
-            4 => Some(Self::Output),
-            5 => Some(Self::JumpIfTrue),
-            6 => Some(Self::JumpIfFalse),
-            7 => Some(Self::LessThan),
-            8 => Some(Self::Equals),
-            9 => Some(Self::RelativeBaseOffset),
-            99 => Some(Self::Halt),
-            _ => None,
-        }
-    }
-}
-
-#[derive(Debug)]
-enum ParameterMode {
-    Position,
-    Immediate,
-    Relative,
-}
-
-impl ParameterMode {
-    fn from_value(value: usize) -> Option<Self> {
-        match value {
-            2 => Some(Self::Relative),
-            1 => Some(Self::Immediate),
-            0 => Some(Self::Position),
-            _ => None,
-        }
-    }
-}
-
-struct Parameter {
-    mode: ParameterMode,
-    index: usize,
-}
-
-impl Parameter {
-    fn new(mode: ParameterMode, index: usize) -> Self {
-        Self { mode, index }
-    }
-
-    fn from_value(mode: usize, index: usize) -> Self {
-        let mode = ParameterMode::from_value(mode).unwrap();
-        Self::new(mode, index)
-    }
-}
-
-struct Instruction {
-    opcode: OpCode,
-    parameters: Vec<Parameter>,
-    store: Option<Parameter>,
-}
-
-impl Instruction {
-    fn new(opcode: OpCode, parameters: Vec<Parameter>, store: Option<Parameter>) -> Self {
-        Self {
-            opcode,
-            parameters,
-            store,
-        }
-    }
-
-    fn pointer_offset(&self) -> usize {
-        let store_len = if let Some(_) = self.store { 1 } else { 0 };
-        // opcode + parameter num + store location if it exists
-        self.parameters.len() + store_len + 1
-    }
-}
-
-#[derive(Clone)]
-pub struct Intcode {
-    instructions: Vec<isize>,
-    instruction_pointer: usize,
-    inputs: VecDeque<isize>,
-    pub outputs: Vec<isize>,
-    diagnostic_code: Option<isize>,
-    pointer_jumped: bool,
-    pause_on_output: bool,
-    halted: bool,
-    relative_base: isize,
-}
-
-impl Intcode {
-    fn new(instructions: &[isize], inputs: &[isize], pause_on_output: bool) -> Self {
-        let instructions = instructions.iter().map(|&x| x).collect::<Vec<_>>();
-        let inputs = inputs.iter().map(|&x| x).collect::<VecDeque<_>>();
-        Intcode {
-            instructions,
-            instruction_pointer: 0,
-            inputs,
-            outputs: vec![],
-            diagnostic_code: None,
-            pointer_jumped: false,
-            pause_on_output,
-            halted: false,
-            relative_base: 0,
-        }
-    }
-
-    fn current_instruction(&self) -> Instruction {
-        let mut meta_value = self.instructions[self.instruction_pointer] as usize;
-        let opcode = OpCode::from_value(meta_value % 100).unwrap();
-        meta_value /= 100;
-
-        let param_count = opcode.parameter_count();
-        let mut params = Vec::<Parameter>::with_capacity(param_count.0);
-
-        for i in 1..=param_count.0 {
-            let operand = Parameter::from_value(meta_value % 10, self.instruction_pointer + i);
-            meta_value /= 10;
-            params.push(operand);
-        }
-
-        let store = if param_count.1 {
-            Some(Parameter::from_value(
-                meta_value % 10,
-                self.instruction_pointer + param_count.0 + 1,
-            ))
-        } else {
-            None
-        };
-
-        Instruction::new(opcode, params, store)
-    }
-
-    fn parameter_value(&self, param: &Parameter) -> isize {
-        match param.mode {
-            ParameterMode::Immediate => self.at(param.index),
-            ParameterMode::Position => self.at(self.at(param.index) as usize),
-            ParameterMode::Relative => {
-                self.at((self.relative_base + self.at(param.index)) as usize)
-            }
-        }
-    }
-
-    fn at(&self, index: usize) -> isize {
-        *self.instructions.get(index).unwrap_or(&0)
-    }
-
-    fn store(&mut self, store_param: &Parameter, value: isize) {
-        let location = match store_param.mode {
-            ParameterMode::Position => self.at(store_param.index) as usize,
-            ParameterMode::Relative => (self.relative_base + self.at(store_param.index)) as usize,
-            ParameterMode::Immediate => {
-                panic!("Immediate paramter mode not allowed for store operation")
-            }
-        };
-
-        if location >= self.instructions.len() {
-            let to_add = location - self.instructions.len() + 1;
-            let mut slice: Vec<isize> = (0..to_add).map(|_| 0).collect();
-            self.instructions.append(&mut slice);
-        }
-        self.instructions[location] = value;
-    }
-
-    fn execute_instruction(&mut self, instruction: &Instruction) -> ExecutionResult {
-        match instruction.opcode {
-            OpCode::Halt => {
-                self.halted = true;
-                ExecutionResult::Halt
-            }
-            OpCode::Output => {
-                let param = instruction.parameters.first().unwrap();
-                let val = self.parameter_value(param);
-                self.outputs.push(val);
-                self.diagnostic_code = Some(val);
-                if self.pause_on_output {
-                    ExecutionResult::Output
-                } else {
-                    ExecutionResult::Continue
-                }
-            }
-            OpCode::Input => {
-                self.diagnostic_code = None;
-                if let Some(value) = self.inputs.pop_front() {
-                    self.store(instruction.store.as_ref().unwrap(), value);
-                    ExecutionResult::Continue
-                } else {
-                    ExecutionResult::Input
-                }
-            }
-            OpCode::Add => {
-                self.diagnostic_code = None;
-                let first_operand = self.parameter_value(&instruction.parameters[0]);
-                let second_operand = self.parameter_value(&instruction.parameters[1]);
-
-                self.store(
-                    instruction.store.as_ref().unwrap(),
-                    first_operand + second_operand,
-                );
-                ExecutionResult::Continue
-            }
-            OpCode::Multiply => {
-                self.diagnostic_code = None;
-                let first_operand = self.parameter_value(&instruction.parameters[0]);
-                let second_operand = self.parameter_value(&instruction.parameters[1]);
-
-                self.store(
-                    instruction.store.as_ref().unwrap(),
-                    first_operand * second_operand,
-                );
-                ExecutionResult::Continue
-            }
-            OpCode::JumpIfTrue => {
-                self.diagnostic_code = None;
-                let first_operand = self.parameter_value(&instruction.parameters[0]);
-                let second_operand = self.parameter_value(&instruction.parameters[1]);
-
-                if first_operand != 0 {
-                    self.instruction_pointer = second_operand as usize;
-                    self.pointer_jumped = true;
-                }
-                ExecutionResult::Continue
-            }
-            OpCode::JumpIfFalse => {
-                self.diagnostic_code = None;
-                let first_operand = self.parameter_value(&instruction.parameters[0]);
-                let second_operand = self.parameter_value(&instruction.parameters[1]);
-
-                if first_operand == 0 {
-                    self.instruction_pointer = second_operand as usize;
-                    self.pointer_jumped = true;
-                }
-                ExecutionResult::Continue
-            }
-            OpCode::Equals => {
-                self.diagnostic_code = None;
-                let first_operand = self.parameter_value(&instruction.parameters[0]);
-                let second_operand = self.parameter_value(&instruction.parameters[1]);
-
-                if first_operand == second_operand {
-                    self.store(instruction.store.as_ref().unwrap(), 1);
-                } else {
-                    self.store(instruction.store.as_ref().unwrap(), 0);
-                }
-                ExecutionResult::Continue
-            }
-            OpCode::LessThan => {
-                self.diagnostic_code = None;
-                let first_operand = self.parameter_value(&instruction.parameters[0]);
-                let second_operand = self.parameter_value(&instruction.parameters[1]);
-
-                if first_operand < second_operand {
-                    self.store(instruction.store.as_ref().unwrap(), 1);
-                } else {
-                    self.store(instruction.store.as_ref().unwrap(), 0);
-                }
-                ExecutionResult::Continue
-            }
-            OpCode::RelativeBaseOffset => {
-                self.diagnostic_code = None;
-                let first_operand = self.parameter_value(&instruction.parameters[0]);
-
-                self.relative_base += first_operand;
-                ExecutionResult::Continue
-            }
-        }
-    }
-
-    pub fn run_program(&mut self) -> ProgramResult {
-        if self.halted {
-            ProgramResult::Halt
-        } else {
-            loop {
-                let instruction = self.current_instruction();
-                match self.execute_instruction(&instruction) {
-                    ExecutionResult::Halt => break ProgramResult::Halt,
-                    ExecutionResult::Continue => {
-                        self.advance_to_next_instruction(&instruction);
-                    }
-                    ExecutionResult::Output => {
-                        self.advance_to_next_instruction(&instruction);
-                        break self
-                            .outputs
-                            .last()
-                            .map_or(ProgramResult::OutputPause(None), |&x| {
-                                ProgramResult::OutputPause(Some(x))
-                            });
-                    }
-                    ExecutionResult::Input => break ProgramResult::InputPause,
-                }
-            }
-        }
-    }
-
-    pub fn add_inputs(&mut self, inputs: &[isize]) {
-        self.inputs.extend(inputs);
-    }
-    pub fn is_halted(&self) -> bool {
-        self.halted
-    }
-
-    fn advance_to_next_instruction(&mut self, current_instruction: &Instruction) {
-        if !self.pointer_jumped {
-            self.instruction_pointer += current_instruction.pointer_offset();
-        }
-        self.pointer_jumped = false;
-    }
-
-    pub fn parsed(text: &str, inputs: &[isize], pause_on_output: bool) -> Self {
-        let result: Vec<isize> = text
-            .split(",")
-            .map(|x| x.parse::<isize>().expect("Not valid intcode"))
-            .collect();
-        Self::new(result.as_ref(), inputs, pause_on_output)
-    }
-
-    pub fn set_inputs(&mut self, inputs: &[isize]) {
-        self.inputs = inputs.iter().map(|x| *x).collect();
-    }
-}
-
-pub enum ProgramResult {
-    InputPause,
-    OutputPause(Option<isize>),
-    Halt,
-}
+use crate::intcode::{Intcode, VmError};
 
 pub struct Day5Runner {
     path: String,
@@ -372,15 +12,19 @@ impl Day5Runner {
         Self { path, part }
     }
 
-    fn part1(&self) -> isize {
+    pub(crate) fn part1(&self) -> Result<isize, VmError> {
         let mut intcode = self.load(&[1]);
-        intcode.run_program();
-        intcode.diagnostic_code.unwrap()
+        intcode.run_to_halt()?;
+        Ok(intcode
+            .diagnostic_code()
+            .expect("Program halted without a diagnostic code"))
     }
-    fn part2(&self) -> isize {
+    pub(crate) fn part2(&self) -> Result<isize, VmError> {
         let mut intcode = self.load(&[5]);
-        intcode.run_program();
-        intcode.diagnostic_code.unwrap()
+        intcode.run_to_halt()?;
+        Ok(intcode
+            .diagnostic_code()
+            .expect("Program halted without a diagnostic code"))
     }
 
     fn load(&self, inputs: &[isize]) -> Intcode {
@@ -400,6 +44,9 @@ impl Runner for Day5Runner {
             Parts::Part2 => self.part2(),
         };
 
-        format!("Result: {}", result)
+        match result {
+            Ok(result) => format!("Result: {}", result),
+            Err(e) => format!("Error: {}", e),
+        }
     }
 }
@@ -143,11 +143,11 @@ impl Day6Runner {
         Self { path, part }
     }
 
-    fn part1(&self) -> usize {
+    pub(crate) fn part1(&self) -> usize {
         let map = self.load();
         map.direct_and_indirect_orbits()
     }
-    fn part2(&self) -> usize {
+    pub(crate) fn part2(&self) -> usize {
         let map = self.load();
         let start = map
             .objects
@@ -167,7 +167,7 @@ impl Day6Runner {
     }
 
     fn load(&self) -> Map {
-        let text = crate::input_reader::read_sparated_values_from_input(self.path.as_ref(), "\r\n")
+        let text = crate::input_reader::read_lines_from_input(self.path.as_ref())
             .expect("Could not read orbits");
         Map::parsed(&text)
     }
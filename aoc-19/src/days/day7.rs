@@ -1,54 +1,37 @@
 use crate::days::*;
-use day5::Intcode;
+use crate::intcode::network::{Network, NetworkError, Route};
+use crate::intcode::{Intcode, VmError};
 
 struct Amplifiers {
     original: Intcode,
 }
 
 impl Amplifiers {
-    fn output_for_phase_configuration(&self, phases: &[usize]) -> isize {
+    fn output_for_phase_configuration(&self, phases: &[usize]) -> Result<isize, VmError> {
         let mut inputs: Vec<isize> = vec![0, 0];
 
         for &phase in phases {
             inputs[0] = phase as isize;
             let mut amp = self.original.clone();
             amp.set_inputs(&inputs);
-            amp.run_program();
-            inputs[1] = *amp.outputs.last().unwrap();
+            amp.run_to_halt()?;
+            inputs[1] = amp.last_output().expect("Amplifier produced no output");
         }
-        inputs[1]
+        Ok(inputs[1])
     }
 
-    fn output_feedback_loop(&self, phases: &[usize]) -> isize {
-        let mut amps: Vec<Intcode> = Vec::new();
-        let mut phases: Vec<isize> = phases.iter().map(|&x| x as isize).collect();
-
-        (0..phases.len()).for_each(|_| amps.push(self.original.clone()));
-        let mut last_output = 0isize;
-        let mut halted_counter = 0;
-        let amps_len = amps.len();
-
-        'outer: loop {
-            for amp in amps.iter_mut() {
-                if amp.is_halted() {
-                    if halted_counter == amps_len {
-                        break 'outer;
-                    } else {
-                        halted_counter += 1;
-                        continue;
-                    }
-                }
-                if !phases.is_empty() {
-                    let phase = phases.pop().unwrap();
-                    amp.add_inputs(&[phase]);
-                }
-
-                amp.add_inputs(&[last_output]);
-                amp.run_program();
-                last_output = *amp.outputs.last().unwrap();
-            }
+    // every amplifier feeds the next and the last one feeds the first, until they all halt
+    fn output_feedback_loop(&self, phases: &[usize]) -> Result<isize, NetworkError> {
+        let mut network = Network::new();
+        for (amp, &phase) in phases.iter().enumerate() {
+            network.add(self.original.clone(), Route::Pipe((amp + 1) % phases.len()));
+            network.send(amp, &[phase as isize]);
         }
-        *amps.last().unwrap().outputs.last().unwrap()
+        network.send(0, &[0]);
+
+        network.run()?;
+        let last = network.machine(phases.len() - 1);
+        Ok(last.last_output().expect("Amplifier produced no output"))
     }
 }
 
@@ -67,60 +50,59 @@ impl Day7Runner {
         Self { path, part }
     }
 
-    fn part1(&self) -> isize {
+    pub(crate) fn part1(&self) -> Result<isize, VmError> {
         use superslice::*;
         let mut phases: [usize; 5] = [0, 1, 2, 3, 4];
         let amp = Amplifiers {
-            original: self.load(false),
+            original: self.load(),
         };
         let mut max_output = isize::MIN;
 
         for _ in 0..factorial(phases.len()) {
             phases.next_permutation();
-            let output = amp.output_for_phase_configuration(&phases);
+            let output = amp.output_for_phase_configuration(&phases)?;
 
             if output > max_output {
                 max_output = output;
             }
         }
-        max_output
+        Ok(max_output)
     }
-    fn part2(&self) -> isize {
+    pub(crate) fn part2(&self) -> Result<isize, NetworkError> {
         use superslice::*;
         let mut phases = (5..=9).collect::<Vec<usize>>();
         let amp = Amplifiers {
-            original: self.load(true),
+            original: self.load(),
         };
         let mut max_output = isize::MIN;
 
         for _ in 0..factorial(phases.len()) {
             phases.next_permutation();
-            let output = amp.output_feedback_loop(&phases);
+            let output = amp.output_feedback_loop(&phases)?;
 
             if output > max_output {
                 max_output = output;
             }
         }
-        max_output
+        Ok(max_output)
     }
 
-    fn load(&self, pause_on_output: bool) -> Intcode {
+    fn load(&self) -> Intcode {
         let text = crate::input_reader::read_sparated_values_from_input(self.path.as_ref(), "\r\n");
-        Intcode::parsed(
-            &text.expect("Could not read instructions")[0],
-            &[],
-            pause_on_output,
-        )
+        Intcode::parsed(&text.expect("Could not read instructions")[0], &[], false)
     }
 }
 
 impl Runner for Day7Runner {
     fn run(&self) -> String {
         let result = match self.part {
-            Parts::Part1 => self.part1(),
-            Parts::Part2 => self.part2(),
+            Parts::Part1 => self.part1().map_err(|e| e.to_string()),
+            Parts::Part2 => self.part2().map_err(|e| e.to_string()),
         };
 
-        format!("Result: {}", result)
+        match result {
+            Ok(result) => format!("Result: {}", result),
+            Err(e) => format!("Error: {}", e),
+        }
     }
 }
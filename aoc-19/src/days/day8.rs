@@ -125,18 +125,17 @@ impl Day8Runner {
         Self { path, part }
     }
 
-    fn part1(&self) -> usize {
+    pub(crate) fn part1(&self) -> usize {
         let img = self.load();
         img.checksum()
     }
-    fn part2(&self) -> usize {
-        let img = self.load();
-        println!("{}", img);
-        0
+    // the image spells out the answer
+    pub(crate) fn part2(&self) -> String {
+        self.load().to_string()
     }
 
     fn load(&self) -> Image {
-        let text = crate::input_reader::read_sparated_values_from_input(self.path.as_ref(), "\r\n");
+        let text = crate::input_reader::read_lines_from_input(self.path.as_ref());
         Image::parsed(&text.expect("Could not read instructions")[0], 25, 6)
     }
 }
@@ -144,7 +143,7 @@ impl Day8Runner {
 impl Runner for Day8Runner {
     fn run(&self) -> String {
         let result = match self.part {
-            Parts::Part1 => self.part1(),
+            Parts::Part1 => self.part1().to_string(),
             Parts::Part2 => self.part2(),
         };
 
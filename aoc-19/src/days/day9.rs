@@ -1,5 +1,5 @@
 use crate::days::*;
-use day5::Intcode;
+use crate::intcode::{Intcode, VmError};
 
 pub struct Day9Runner {
     path: String,
@@ -12,17 +12,17 @@ impl Day9Runner {
         Self { path, part }
     }
 
-    fn part1(&self) -> isize {
+    pub(crate) fn part1(&self) -> Result<isize, VmError> {
         let mut intcode = self.load(false);
         intcode.set_inputs(&[1]);
-        intcode.run_program();
-        *intcode.outputs.last().unwrap()
+        intcode.run_to_halt()?;
+        Ok(intcode.last_output().expect("BOOST produced no output"))
     }
-    fn part2(&self) -> isize {
+    pub(crate) fn part2(&self) -> Result<isize, VmError> {
         let mut intcode = self.load(false);
         intcode.set_inputs(&[2]);
-        intcode.run_program();
-        *intcode.outputs.last().unwrap()
+        intcode.run_to_halt()?;
+        Ok(intcode.last_output().expect("BOOST produced no output"))
     }
 
     fn load(&self, pause_on_output: bool) -> Intcode {
@@ -42,6 +42,9 @@ impl Runner for Day9Runner {
             Parts::Part2 => self.part2(),
         };
 
-        format!("Result: {}", result)
+        match result {
+            Ok(result) => format!("Result: {}", result),
+            Err(e) => format!("Error: {}", e),
+        }
     }
 }
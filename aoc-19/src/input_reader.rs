@@ -11,6 +11,15 @@ pub fn read_sparated_values_from_input(
     contents
 }
 
+// splits on both "\n" and "\r\n", unlike a fixed separator
+pub fn read_lines_from_input(path: &str) -> Result<Vec<String>, ReaderError> {
+    use std::fs;
+
+    fs::read_to_string(path)
+        .map_err(|_| ReaderError {})
+        .map(|text| text.lines().map(String::from).collect())
+}
+
 pub trait Parser {
     type R;
 
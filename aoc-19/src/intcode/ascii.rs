@@ -0,0 +1,102 @@
+use super::{Intcode, ProgramResult, VmError};
+
+const NEWLINE: isize = b'\n' as isize;
+
+// printable ASCII as text, anything else, usually the answer, as a number on its own line, so
+// control codes never reach the terminal
+pub fn render(values: &[isize]) -> String {
+    let mut text = String::new();
+    for &value in values {
+        if is_printable(value) {
+            text.push(value as u8 as char);
+        } else {
+            if !text.is_empty() && !text.ends_with('\n') {
+                text.push('\n');
+            }
+            text.push_str(&format!("{}\n", value));
+        }
+    }
+    text
+}
+
+fn is_printable(value: isize) -> bool {
+    value == NEWLINE || value == b'\t' as isize || (b' ' as isize..=b'~' as isize).contains(&value)
+}
+
+// the codes of the line's characters and the newline ending it
+pub fn encode_line(line: &str) -> Vec<isize> {
+    line.chars()
+        .map(|c| c as isize)
+        .chain(std::iter::once(NEWLINE))
+        .collect()
+}
+
+// talks to a program a line at a time
+pub struct Ascii {
+    pub vm: Intcode,
+    shown: usize,
+}
+
+impl Ascii {
+    pub fn new(vm: Intcode) -> Self {
+        Ascii { vm, shown: 0 }
+    }
+
+    // runs until the program waits for a line or halts, returning what it printed meanwhile
+    pub fn run(&mut self) -> Result<String, VmError> {
+        while let ProgramResult::OutputPause(_) = self.vm.run_program()? {}
+        let text = render(&self.vm.outputs[self.shown..]);
+        self.shown = self.vm.outputs.len();
+        Ok(text)
+    }
+
+    pub fn send_line(&mut self, line: &str) {
+        self.vm.add_inputs(&encode_line(line));
+    }
+
+    pub fn is_halted(&self) -> bool {
+        self.vm.is_halted()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::intcode::asm::assemble;
+
+    #[test]
+    fn large_values_are_shown_as_numbers() {
+        assert_eq!("ok\n", render(&[111, 107, 10]));
+        assert_eq!("#.\n1234567\n-1\n", render(&[35, 46, 1234567, -1]));
+        assert_eq!("a\t\n0\n27\n127\nb", render(&[97, 9, 10, 0, 27, 127, 98]));
+        assert_eq!(vec![71, 79, 10], encode_line("GO"));
+    }
+
+    #[test]
+    fn lines_go_back_and_forth() {
+        // prompts, then echoes one line and reports how long it was
+        let program = assemble(
+            "
+                    out #63
+            loop:   in -> [c]
+                    out [c]
+                    add [length], #1 -> [length]
+                    eq [c], #10 -> [done]
+                    jf [done], #loop
+                    out [length]
+                    hlt
+            c:      data 0
+            done:   data 0
+            length: data 1000
+            ",
+        )
+        .unwrap();
+        let mut ascii = Ascii::new(Intcode::new(&program, &[], true));
+
+        assert_eq!(Ok(String::from("?")), ascii.run());
+        assert!(!ascii.is_halted());
+        ascii.send_line("hi");
+        assert_eq!(Ok(String::from("hi\n1003\n")), ascii.run());
+        assert!(ascii.is_halted());
+    }
+}
@@ -0,0 +1,313 @@
+use std::collections::HashMap;
+use std::fmt;
+
+use super::{OpCode, ParameterMode};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AsmError {
+    // 1-based line of the source
+    pub line: usize,
+    pub msg: String,
+}
+
+impl AsmError {
+    fn new(line: usize, msg: impl Into<String>) -> Self {
+        Self {
+            line,
+            msg: msg.into(),
+        }
+    }
+}
+
+impl fmt::Display for AsmError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "line {}: {}", self.line, self.msg)
+    }
+}
+
+impl std::error::Error for AsmError {}
+
+// a number, or a label that stands for the address it marks
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Value {
+    Number(isize),
+    Label(String),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Operand {
+    mode: ParameterMode,
+    value: Value,
+    negated: bool,
+}
+
+#[derive(Debug)]
+enum Statement {
+    Instruction {
+        opcode: OpCode,
+        operands: Vec<Operand>,
+    },
+    Data(Vec<Value>),
+}
+
+impl Statement {
+    fn size(&self) -> usize {
+        match self {
+            Statement::Instruction { operands, .. } => operands.len() + 1,
+            Statement::Data(values) => values.len(),
+        }
+    }
+}
+
+// Source is one statement per line, `;` starts a comment:
+//
+//     start:  in -> [rb+1]
+//             jt [rb+1], #start
+//             add [12], #5 -> [rb+3]
+//             hlt
+//     table:  data 1, 2, start
+//
+// Operands are `[n]` by position, `#n` immediate and `[rb+n]` relative, where `n` is a number or
+// a label. Mnemonics are case insensitive, so the disassembler's output assembles back.
+pub fn assemble(source: &str) -> Result<Vec<isize>, AsmError> {
+    let mut labels = HashMap::new();
+    let mut statements = Vec::new();
+    let mut address = 0;
+
+    for (idx, line) in source.lines().enumerate() {
+        let number = idx + 1;
+        let mut text = line.split(';').next().unwrap_or_default().trim();
+
+        while let Some((label, rest)) = split_label(text) {
+            if labels.insert(label.to_string(), address).is_some() {
+                return Err(AsmError::new(
+                    number,
+                    format!("Label '{}' defined twice", label),
+                ));
+            }
+            text = rest.trim();
+        }
+        if text.is_empty() {
+            continue;
+        }
+
+        let statement = parse_statement(text).map_err(|msg| AsmError::new(number, msg))?;
+        address += statement.size();
+        statements.push((number, statement));
+    }
+
+    let mut program = Vec::with_capacity(address);
+    for (number, statement) in statements {
+        let resolve = |value: &Value| match value {
+            Value::Number(n) => Ok(*n),
+            Value::Label(label) => labels
+                .get(label)
+                .map(|&address| address as isize)
+                .ok_or_else(|| AsmError::new(number, format!("Unknown label '{}'", label))),
+        };
+
+        match statement {
+            Statement::Instruction { opcode, operands } => {
+                let modes = operands
+                    .iter()
+                    .rev()
+                    .fold(0, |modes, operand| modes * 10 + operand.mode.value());
+                program.push(modes * 100 + opcode.value());
+                for operand in &operands {
+                    let value = resolve(&operand.value)?;
+                    if !operand.negated {
+                        program.push(value);
+                        continue;
+                    }
+                    let negated = value.checked_neg().ok_or_else(|| {
+                        AsmError::new(number, format!("Offset -({}) does not fit", value))
+                    })?;
+                    program.push(negated);
+                }
+            }
+            Statement::Data(values) => {
+                for value in &values {
+                    program.push(resolve(value)?);
+                }
+            }
+        }
+    }
+
+    Ok(program)
+}
+
+fn split_label(text: &str) -> Option<(&str, &str)> {
+    let (label, rest) = text.split_once(':')?;
+    if is_label(label) {
+        Some((label, rest))
+    } else {
+        None
+    }
+}
+
+fn is_label(text: &str) -> bool {
+    let mut chars = text.chars();
+    chars
+        .next()
+        .is_some_and(|c| c.is_ascii_alphabetic() || c == '_')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
+
+fn parse_statement(text: &str) -> Result<Statement, String> {
+    let (mnemonic, rest) = match text.split_once(char::is_whitespace) {
+        Some((mnemonic, rest)) => (mnemonic, rest.trim()),
+        None => (text, ""),
+    };
+
+    if mnemonic.eq_ignore_ascii_case("data") {
+        let values = split_list(rest)
+            .map(parse_value)
+            .collect::<Result<Vec<_>, _>>()?;
+        if values.is_empty() {
+            return Err(String::from("'data' needs at least one value"));
+        }
+        return Ok(Statement::Data(values));
+    }
+
+    let opcode = OpCode::from_mnemonic(mnemonic)
+        .ok_or_else(|| format!("Unknown mnemonic '{}'", mnemonic))?;
+    let (parameter_count, has_store) = opcode.parameter_count();
+
+    let (parameters, store) = match rest.split_once("->") {
+        Some((parameters, store)) => (parameters, Some(store.trim())),
+        None => (rest, None),
+    };
+    let mut operands = split_list(parameters)
+        .map(parse_operand)
+        .collect::<Result<Vec<_>, _>>()?;
+
+    if operands.len() != parameter_count {
+        return Err(format!(
+            "'{}' takes {} operand(s), got {}",
+            mnemonic,
+            parameter_count,
+            operands.len()
+        ));
+    }
+    match (store, has_store) {
+        (Some(store), true) => {
+            let store = parse_operand(store)?;
+            if store.mode == ParameterMode::Immediate {
+                return Err(format!("'{}' cannot store to an immediate", mnemonic));
+            }
+            operands.push(store);
+        }
+        (None, true) => return Err(format!("'{}' needs a '-> target'", mnemonic)),
+        (Some(_), false) => return Err(format!("'{}' does not store a result", mnemonic)),
+        (None, false) => {}
+    }
+
+    Ok(Statement::Instruction { opcode, operands })
+}
+
+fn split_list(text: &str) -> impl Iterator<Item = &str> {
+    text.split(',')
+        .map(str::trim)
+        .filter(|item| !item.is_empty())
+}
+
+fn parse_operand(text: &str) -> Result<Operand, String> {
+    let operand = |mode, value: &str, negated| {
+        Ok(Operand {
+            mode,
+            value: parse_value(value.trim())?,
+            negated,
+        })
+    };
+
+    if let Some(value) = text.strip_prefix('#') {
+        return operand(ParameterMode::Immediate, value, false);
+    }
+
+    let inner = text
+        .strip_prefix('[')
+        .and_then(|text| text.strip_suffix(']'))
+        .ok_or_else(|| format!("Operand '{}' should be #n, [n] or [rb+n]", text))?
+        .trim();
+    match inner.strip_prefix("rb").map(str::trim_start) {
+        Some("") => operand(ParameterMode::Relative, "0", false),
+        Some(offset) if offset.starts_with('+') => {
+            operand(ParameterMode::Relative, &offset[1..], false)
+        }
+        Some(offset) if offset.starts_with('-') => {
+            operand(ParameterMode::Relative, &offset[1..], true)
+        }
+        _ => operand(ParameterMode::Position, inner, false),
+    }
+}
+
+fn parse_value(text: &str) -> Result<Value, String> {
+    if is_label(text) {
+        Ok(Value::Label(text.to_string()))
+    } else {
+        text.parse()
+            .map(Value::Number)
+            .map_err(|_| format!("'{}' is neither a number nor a label", text))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::intcode::disasm::{disassemble, source, Sweep};
+    use crate::intcode::{Intcode, ProgramResult};
+
+    #[test]
+    fn modes_and_labels_are_encoded() {
+        let program = assemble(
+            "
+            ; echoes its input until it reads a zero
+                    arb #buffer
+            loop:   in -> [rb+0]
+                    jf [rb], #done      ; [rb] is short for [rb+0]
+                    out [rb+0]
+                    jt #1, #loop
+            done:   hlt
+            buffer: data 0
+            table:  data -1, done
+            ",
+        )
+        .unwrap();
+        assert_eq!(
+            vec![109, 13, 203, 0, 1206, 0, 12, 204, 0, 1105, 1, 2, 99, 0, -1, 12],
+            program
+        );
+
+        let mut intcode = Intcode::new(&program, &[3, 4, 0], false);
+        assert!(matches!(intcode.run_program(), Ok(ProgramResult::Halt)));
+        assert_eq!(vec![3, 4], intcode.outputs);
+    }
+
+    #[test]
+    fn disassembly_assembles_back() {
+        let program = [
+            109, 19, 204, -34, 22201, 12, 5, -3, 1105, 1, 13, 1, 2, 99, 0, 0,
+        ];
+        for sweep in [Sweep::Linear, Sweep::Reachable].iter() {
+            let text = source(&disassemble(&program, *sweep));
+            assert_eq!(Ok(program.to_vec()), assemble(&text), "{}", text);
+        }
+    }
+
+    #[test]
+    fn mistakes_point_at_their_line() {
+        let error = |source| assemble(source).unwrap_err().to_string();
+        assert_eq!("line 2: Unknown mnemonic 'mov'", error("hlt\nmov [1], [2]"));
+        assert_eq!("line 1: 'add' needs a '-> target'", error("add #1, #2"));
+        assert_eq!(
+            "line 1: 'in' cannot store to an immediate",
+            error("in -> #3")
+        );
+        assert_eq!("line 1: Unknown label 'nowhere'", error("jt #1, #nowhere"));
+        assert_eq!("line 2: Label 'a' defined twice", error("a: hlt\na: hlt"));
+        assert_eq!(
+            "line 2: Offset -(-9223372036854775808) does not fit",
+            error("hlt\nout [rb--9223372036854775808]")
+        );
+    }
+}
@@ -0,0 +1,384 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use super::disasm::{disassemble, Line, Sweep};
+use super::{OpCode, ParameterMode};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Exit {
+    // runs on into the next block
+    Fallthrough(usize),
+    Taken(usize),
+    NotTaken(usize),
+    // a jump to an address only known at run time
+    Computed,
+    // a taken jump to where no instruction starts, inside one or into data
+    Stray(usize),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Block {
+    pub start: usize,
+    // `Line::Code` only
+    pub lines: Vec<Line>,
+    pub exits: Vec<Exit>,
+}
+
+// a store to a fixed address inside a decoded instruction, so the program rewrites its own code
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CodeWrite {
+    pub from: usize,
+    pub target: usize,
+    // start of the block holding the instruction written to
+    pub block: usize,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Cfg {
+    pub blocks: Vec<Block>,
+    pub code_writes: Vec<CodeWrite>,
+}
+
+// The code is what a reachable sweep of the disassembler decodes. Blocks start at address 0, at
+// every immediate jump target and after every jump, and end at a jump or a halt. Stores through
+// relative mode depend on the relative base, so only position mode ones are checked for writes
+// into code.
+pub fn build(program: &[isize]) -> Cfg {
+    let code = disassemble(program, Sweep::Reachable)
+        .into_iter()
+        .filter(|line| matches!(line, Line::Code { .. }))
+        .collect::<Vec<_>>();
+
+    let mut leaders = BTreeSet::new();
+    leaders.insert(0);
+    for (i, line) in code.iter().enumerate() {
+        let next = code.get(i + 1).map(Line::address);
+        if let Some(next) = next {
+            // running into data cuts code in two as well
+            if next != end(line) || ends_block(line) {
+                leaders.insert(next);
+            }
+        }
+        if let Some((_, Some(target), _)) = jump(line) {
+            leaders.insert(target);
+        }
+    }
+
+    let mut blocks: Vec<Block> = Vec::new();
+    for line in code {
+        match blocks.last_mut() {
+            Some(block) if !leaders.contains(&line.address()) => block.lines.push(line),
+            _ => blocks.push(Block {
+                start: line.address(),
+                lines: vec![line],
+                exits: Vec::new(),
+            }),
+        }
+    }
+
+    let starts = blocks
+        .iter()
+        .map(|block| block.start)
+        .collect::<BTreeSet<_>>();
+    for block in blocks.iter_mut() {
+        let last = block.lines.last().expect("Blocks are never empty");
+        let next = Some(end(last)).filter(|next| starts.contains(next));
+        block.exits = match jump(last) {
+            Some((taken, target, not_taken)) => {
+                let mut exits = Vec::new();
+                if taken {
+                    match target {
+                        Some(target) if starts.contains(&target) => exits.push(Exit::Taken(target)),
+                        Some(target) => exits.push(Exit::Stray(target)),
+                        None => exits.push(Exit::Computed),
+                    }
+                }
+                if let (true, Some(next)) = (not_taken, next) {
+                    exits.push(Exit::NotTaken(next));
+                }
+                exits
+            }
+            None if is_halt(last) => Vec::new(),
+            None => next.map(Exit::Fallthrough).into_iter().collect(),
+        };
+    }
+
+    let code_writes = code_writes(&blocks);
+    Cfg {
+        blocks,
+        code_writes,
+    }
+}
+
+fn end(line: &Line) -> usize {
+    line.address() + line.words().len()
+}
+
+fn is_halt(line: &Line) -> bool {
+    matches!(line, Line::Code { instruction, .. } if instruction.opcode == OpCode::Halt)
+}
+
+fn ends_block(line: &Line) -> bool {
+    is_halt(line) || jump(line).is_some()
+}
+
+// for a jump, whether it can be taken, its target when immediate, and whether it can fall through
+fn jump(line: &Line) -> Option<(bool, Option<usize>, bool)> {
+    let (instruction, words) = match line {
+        Line::Code { instruction, words } => (instruction, words),
+        Line::Data { .. } => return None,
+    };
+    let jumps_when = match instruction.opcode {
+        OpCode::JumpIfTrue => true,
+        OpCode::JumpIfFalse => false,
+        _ => return None,
+    };
+
+    let parameters = instruction.parameters();
+    let (condition, target) = (words[1], words[2]);
+    let (taken, not_taken) = match parameters[0].mode {
+        ParameterMode::Immediate if (condition != 0) == jumps_when => (true, false),
+        ParameterMode::Immediate => (false, true),
+        _ => (true, true),
+    };
+    let target = match parameters[1].mode {
+        ParameterMode::Immediate if target >= 0 => Some(target as usize),
+        _ => None,
+    };
+    Some((taken, target, not_taken))
+}
+
+fn code_writes(blocks: &[Block]) -> Vec<CodeWrite> {
+    // every word of every instruction, with the block it belongs to
+    let mut owners = BTreeMap::new();
+    for block in blocks {
+        for line in &block.lines {
+            for address in line.address()..end(line) {
+                owners.insert(address, block.start);
+            }
+        }
+        // a block running into a word that does not decode is usually waiting for the program
+        // to patch the instruction there
+        let last = block.lines.last().expect("Blocks are never empty");
+        if block.exits.is_empty() && !is_halt(last) && jump(last).is_none() {
+            owners.entry(end(last)).or_insert(block.start);
+        }
+    }
+
+    let mut writes = Vec::new();
+    for line in blocks.iter().flat_map(|block| &block.lines) {
+        let (instruction, words) = match line {
+            Line::Code { instruction, words } => (instruction, words),
+            Line::Data { .. } => continue,
+        };
+        let store = match instruction.store {
+            Some(store) if store.mode == ParameterMode::Position => store,
+            _ => continue,
+        };
+        let target = words[store.index - instruction.address];
+        if target < 0 {
+            continue;
+        }
+        if let Some(&block) = owners.get(&(target as usize)) {
+            writes.push(CodeWrite {
+                from: instruction.address,
+                target: target as usize,
+                block,
+            });
+        }
+    }
+    writes
+}
+
+// Blocks are boxes listing their instructions, instructions writing into code are red and point
+// at the block they change with a dashed edge. Stray jump targets get a red node of their own.
+pub fn to_dot(cfg: &Cfg) -> String {
+    let writers = cfg
+        .code_writes
+        .iter()
+        .map(|write| write.from)
+        .collect::<BTreeSet<_>>();
+
+    let mut out = String::from("digraph intcode {\n");
+    out.push_str("    node [shape=box, fontname=\"monospace\"];\n");
+    let mut computed = false;
+    let mut strays = BTreeSet::new();
+    for block in &cfg.blocks {
+        let label = block
+            .lines
+            .iter()
+            .map(|line| format!("{}: {}\\l", line.address(), line))
+            .collect::<String>();
+        let writes = block
+            .lines
+            .iter()
+            .any(|line| writers.contains(&line.address()));
+        let color = if writes { ", color=red" } else { "" };
+        out.push_str(&format!(
+            "    b{} [label=\"{}\"{}];\n",
+            block.start, label, color
+        ));
+
+        for exit in &block.exits {
+            let edge = match exit {
+                Exit::Fallthrough(next) => format!("b{}", next),
+                Exit::Taken(target) => format!("b{} [label=\"taken\"]", target),
+                Exit::NotTaken(next) => format!("b{} [label=\"not taken\", style=dotted]", next),
+                Exit::Computed => {
+                    computed = true;
+                    String::from("computed [style=dotted]")
+                }
+                Exit::Stray(target) => {
+                    strays.insert(*target);
+                    format!("stray{} [label=\"taken\"]", target)
+                }
+            };
+            out.push_str(&format!("    b{} -> {};\n", block.start, edge));
+        }
+    }
+
+    if computed {
+        out.push_str("    computed [label=\"computed jump\", shape=ellipse];\n");
+    }
+    for target in strays {
+        let inside = cfg
+            .blocks
+            .iter()
+            .flat_map(|block| &block.lines)
+            .any(|line| line.address() < target && target < end(line));
+        let what = if inside {
+            "mid-instruction"
+        } else {
+            "not code"
+        };
+        out.push_str(&format!(
+            "    stray{} [label=\"{}: {}\", shape=ellipse, color=red];\n",
+            target, target, what
+        ));
+    }
+    for write in &cfg.code_writes {
+        let from = cfg
+            .blocks
+            .iter()
+            .find(|block| block.lines.iter().any(|line| line.address() == write.from))
+            .expect("Writes come from decoded instructions");
+        out.push_str(&format!(
+            "    b{} -> b{} [label=\"writes {}\", style=dashed, color=red];\n",
+            from.start, write.block, write.target
+        ));
+    }
+    out.push_str("}\n");
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::intcode::asm::assemble;
+
+    fn starts(cfg: &Cfg) -> Vec<(usize, Vec<Exit>)> {
+        cfg.blocks
+            .iter()
+            .map(|block| (block.start, block.exits.clone()))
+            .collect()
+    }
+
+    #[test]
+    fn blocks_split_at_jumps_and_their_targets() {
+        let program = assemble(
+            "
+                    in -> [n]
+            loop:   add [n], #-1 -> [n]
+                    jt [n], #loop
+                    jf #0, #done
+                    out #1
+            done:   jt [ret], [ret]
+                    hlt
+            n:      data 0
+            ret:    data 0
+            ",
+        )
+        .unwrap();
+        let cfg = build(&program);
+        assert_eq!(
+            vec![
+                (0, vec![Exit::Fallthrough(2)]),
+                (2, vec![Exit::Taken(2), Exit::NotTaken(9)]),
+                // `out #1` after the unconditional jump is never reached
+                (9, vec![Exit::Taken(14)]),
+                (14, vec![Exit::Computed, Exit::NotTaken(17)]),
+                (17, vec![]),
+            ],
+            starts(&cfg)
+        );
+        assert!(cfg.code_writes.is_empty());
+    }
+
+    #[test]
+    fn jumps_into_the_middle_of_code_are_kept() {
+        // 1 is the condition of the jump itself, 99999 at 8 is not an opcode
+        let program = [1005, 9, 1, 1005, 9, 8, 99, 0, 99999, 0];
+        let cfg = build(&program);
+        assert_eq!(
+            vec![
+                (0, vec![Exit::Stray(1), Exit::NotTaken(3)]),
+                (3, vec![Exit::Stray(8), Exit::NotTaken(6)]),
+                (6, vec![]),
+            ],
+            starts(&cfg)
+        );
+
+        let dot = to_dot(&cfg);
+        assert!(
+            dot.contains("    b0 -> stray1 [label=\"taken\"];\n"),
+            "{}",
+            dot
+        );
+        assert!(
+            dot.contains("    stray1 [label=\"1: mid-instruction\", shape=ellipse, color=red];\n")
+        );
+        assert!(dot.contains("    stray8 [label=\"8: not code\", shape=ellipse, color=red];\n"));
+    }
+
+    #[test]
+    fn writes_into_code_are_marked() {
+        // patches the immediate of the `out` before running it
+        let program = assemble(
+            "
+                    add #40, #2 -> [5]
+                    out #0
+                    hlt
+            ",
+        )
+        .unwrap();
+        let cfg = build(&program);
+        assert_eq!(
+            vec![CodeWrite {
+                from: 0,
+                target: 5,
+                block: 0
+            }],
+            cfg.code_writes
+        );
+
+        let dot = to_dot(&cfg);
+        assert!(
+            dot.contains(
+                "    b0 [label=\"0: ADD #40, #2 -> [5]\\l4: OUT #0\\l6: HLT\\l\", color=red];\n"
+            ),
+            "{}",
+            dot
+        );
+        assert!(dot.contains("    b0 -> b0 [label=\"writes 5\", style=dashed, color=red];\n"));
+
+        // the instruction at 4 only decodes once the one before it fixed its opcode
+        let cfg = build(&[3, 4, 1, 4, 1100, 99]);
+        assert_eq!(
+            vec![CodeWrite {
+                from: 0,
+                target: 4,
+                block: 0
+            }],
+            cfg.code_writes
+        );
+    }
+}
@@ -0,0 +1,403 @@
+use std::collections::BTreeSet;
+
+use super::disasm::Line;
+use super::snapshot::Snapshot;
+use super::{Intcode, OpCode, Step};
+
+pub const HELP: &str = "Commands:
+    s, step [n]          run n instructions, one by default
+    c, continue          run until a breakpoint, a watched cell changes, input runs out or halt
+    b, break <at>        stop before the instruction at an address, or any with a mnemonic
+    d, delete <at>       remove a breakpoint
+    w, watch <address>   stop after an instruction changes the cell
+    u, unwatch <address> remove a watchpoint
+    i, info              show registers, pending input, output and break/watchpoints
+    x <address> [count]  show memory, one cell by default and at most 256
+    set <address> <value>
+    input <values...>    queue more input
+    save <file>          write the machine's state to a file
+    load <file>          carry on from a saved state, break/watchpoints are kept
+    h, help
+    q, quit";
+
+// more cells than this would only flood the terminal
+const MAX_EXAMINE: usize = 256;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Breakpoint {
+    Address(usize),
+    Opcode(OpCode),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Command {
+    Step(usize),
+    Continue,
+    Break(Breakpoint),
+    Delete(Breakpoint),
+    Watch(usize),
+    Unwatch(usize),
+    Info,
+    Examine { start: usize, count: usize },
+    Set { address: usize, value: isize },
+    Input(Vec<isize>),
+    Save(String),
+    Load(String),
+    Help,
+    Quit,
+}
+
+pub fn parse_command(line: &str) -> Result<Command, String> {
+    let mut words = line.split_whitespace();
+    let name = words.next().unwrap_or_default();
+    let args = words.collect::<Vec<_>>();
+
+    let command = match (name, args.as_slice()) {
+        ("s", []) | ("step", []) => Command::Step(1),
+        // no steps at all would run on like continue
+        ("s", [n]) | ("step", [n]) => match number(n)? {
+            0 => return Err(String::from("Step needs a count of at least 1")),
+            n => Command::Step(n),
+        },
+        ("c", []) | ("continue", []) => Command::Continue,
+        ("b", [at]) | ("break", [at]) => Command::Break(breakpoint(at)?),
+        ("d", [at]) | ("delete", [at]) => Command::Delete(breakpoint(at)?),
+        ("w", [address]) | ("watch", [address]) => Command::Watch(number(address)?),
+        ("u", [address]) | ("unwatch", [address]) => Command::Unwatch(number(address)?),
+        ("i", []) | ("info", []) => Command::Info,
+        ("x", [start]) => Command::Examine {
+            start: number(start)?,
+            count: 1,
+        },
+        ("x", [start, count]) => Command::Examine {
+            start: number(start)?,
+            count: number(count)?,
+        },
+        ("set", [address, value]) => Command::Set {
+            address: number(address)?,
+            value: number(value)?,
+        },
+        ("input", values) if !values.is_empty() => {
+            Command::Input(values.iter().map(|v| number(v)).collect::<Result<_, _>>()?)
+        }
+        ("save", [path]) => Command::Save(path.to_string()),
+        ("load", [path]) => Command::Load(path.to_string()),
+        ("h", []) | ("help", []) => Command::Help,
+        ("q", []) | ("quit", []) => Command::Quit,
+        _ => return Err(format!("Cannot parse '{}', try 'help'", line.trim())),
+    };
+    Ok(command)
+}
+
+fn number<T: std::str::FromStr>(text: &str) -> Result<T, String> {
+    text.parse()
+        .map_err(|_| format!("'{}' is not a valid number here", text))
+}
+
+fn breakpoint(text: &str) -> Result<Breakpoint, String> {
+    match OpCode::from_mnemonic(text) {
+        Some(opcode) => Ok(Breakpoint::Opcode(opcode)),
+        None => number(text).map(Breakpoint::Address),
+    }
+}
+
+pub struct Debugger {
+    pub vm: Intcode,
+    breakpoints: BTreeSet<Breakpoint>,
+    watchpoints: BTreeSet<usize>,
+}
+
+impl Debugger {
+    pub fn new(vm: Intcode) -> Self {
+        Debugger {
+            vm,
+            breakpoints: BTreeSet::new(),
+            watchpoints: BTreeSet::new(),
+        }
+    }
+
+    // what the command printed, quitting is left to the caller
+    pub fn execute(&mut self, command: Command) -> String {
+        match command {
+            Command::Step(count) => self.run(Some(count)),
+            Command::Continue => self.run(None),
+            Command::Break(breakpoint) => {
+                self.breakpoints.insert(breakpoint);
+                self.breakpoints()
+            }
+            Command::Delete(breakpoint) => {
+                self.breakpoints.remove(&breakpoint);
+                self.breakpoints()
+            }
+            Command::Watch(address) => {
+                self.watchpoints.insert(address);
+                self.watchpoints()
+            }
+            Command::Unwatch(address) => {
+                self.watchpoints.remove(&address);
+                self.watchpoints()
+            }
+            Command::Info => self.info(),
+            Command::Examine { start, count } => {
+                let mut out = (start..=usize::MAX)
+                    .take(count.min(MAX_EXAMINE))
+                    .map(|address| format!("[{}] = {}", address, self.vm.read(address)))
+                    .collect::<Vec<_>>();
+                if out.len() < count {
+                    out.push(format!("Showing {} of {} cells", out.len(), count));
+                }
+                out.join("\n")
+            }
+            Command::Set { address, value } => match self.vm.write(address, value) {
+                Ok(()) => format!("[{}] = {}", address, value),
+                Err(e) => e.to_string(),
+            },
+            Command::Input(values) => {
+                self.vm.add_inputs(&values);
+                self.inputs()
+            }
+            Command::Save(path) => match self.vm.snapshot().write_file(&path) {
+                Ok(()) => format!("Saved to '{}'", path),
+                Err(e) => format!("Could not write '{}': {}", path, e),
+            },
+            Command::Load(path) => match Snapshot::read_file(&path) {
+                Ok(snapshot) => {
+                    self.vm.restore(&snapshot);
+                    self.location()
+                }
+                Err(e) => format!("Could not load '{}': {}", path, e),
+            },
+            Command::Help => HELP.to_string(),
+            Command::Quit => String::new(),
+        }
+    }
+
+    // breakpoints are checked once at least one instruction ran, so continuing moves past the
+    // one that stopped us
+    fn run(&mut self, limit: Option<usize>) -> String {
+        let mut out = Vec::new();
+        let mut steps = 0;
+        loop {
+            let watched = self.watched();
+            match self.vm.step() {
+                Ok(Step::Executed) => {}
+                Ok(Step::Output(value)) => out.push(format!("Output: {}", value)),
+                Ok(Step::NeedsInput) => {
+                    out.push(String::from("Waiting for input, queue some with 'input'"));
+                    break;
+                }
+                Ok(Step::Halted) => {
+                    out.push(String::from("Halted"));
+                    return out.join("\n");
+                }
+                Err(e) => {
+                    out.push(format!("Fault: {}", e));
+                    break;
+                }
+            }
+            steps += 1;
+
+            let changed = watched
+                .iter()
+                .filter(|&&(address, old)| self.vm.read(address) != old)
+                .map(|&(address, old)| {
+                    format!("[{}] changed {} -> {}", address, old, self.vm.read(address))
+                })
+                .collect::<Vec<_>>();
+            if !changed.is_empty() {
+                out.extend(changed);
+                break;
+            }
+            if let Some(breakpoint) = self.hit_breakpoint() {
+                out.push(format!("Breakpoint {}", show_breakpoint(&breakpoint)));
+                break;
+            }
+            if limit == Some(steps) {
+                break;
+            }
+        }
+        out.push(self.location());
+        out.join("\n")
+    }
+
+    fn watched(&self) -> Vec<(usize, isize)> {
+        self.watchpoints
+            .iter()
+            .map(|&address| (address, self.vm.read(address)))
+            .collect()
+    }
+
+    fn hit_breakpoint(&self) -> Option<Breakpoint> {
+        let address = Breakpoint::Address(self.vm.instruction_pointer());
+        if self.breakpoints.contains(&address) {
+            return Some(address);
+        }
+        let opcode = Breakpoint::Opcode(self.vm.current_instruction().ok()?.opcode);
+        self.breakpoints.get(&opcode).copied()
+    }
+
+    // the next instruction, in the disassembler's syntax
+    fn location(&self) -> String {
+        let ip = self.vm.instruction_pointer();
+        if self.vm.is_halted() {
+            return format!("{:>6}  halted", ip);
+        }
+        match self.vm.current_instruction() {
+            Ok(instruction) => {
+                let words = (ip..ip + instruction.size())
+                    .map(|address| self.vm.read(address))
+                    .collect();
+                format!("{:>6}  {}", ip, Line::Code { instruction, words })
+            }
+            Err(e) => format!("{:>6}  {}", ip, e),
+        }
+    }
+
+    fn info(&self) -> String {
+        let outputs = self
+            .vm
+            .outputs
+            .iter()
+            .map(isize::to_string)
+            .collect::<Vec<_>>();
+        [
+            format!(
+                "ip = {}, rb = {}",
+                self.vm.instruction_pointer(),
+                self.vm.relative_base()
+            ),
+            self.location(),
+            self.inputs(),
+            format!("Outputs: {}", outputs.join(", ")),
+            self.breakpoints(),
+            self.watchpoints(),
+        ]
+        .join("\n")
+    }
+
+    fn inputs(&self) -> String {
+        let inputs = self
+            .vm
+            .pending_inputs()
+            .iter()
+            .map(isize::to_string)
+            .collect::<Vec<_>>();
+        format!("Inputs: {}", inputs.join(", "))
+    }
+
+    fn breakpoints(&self) -> String {
+        let breakpoints = self
+            .breakpoints
+            .iter()
+            .map(show_breakpoint)
+            .collect::<Vec<_>>();
+        format!("Breakpoints: {}", breakpoints.join(", "))
+    }
+
+    fn watchpoints(&self) -> String {
+        let watchpoints = self
+            .watchpoints
+            .iter()
+            .map(|address| format!("[{}]", address))
+            .collect::<Vec<_>>();
+        format!("Watchpoints: {}", watchpoints.join(", "))
+    }
+}
+
+fn show_breakpoint(breakpoint: &Breakpoint) -> String {
+    match breakpoint {
+        Breakpoint::Address(address) => format!("at {}", address),
+        Breakpoint::Opcode(opcode) => format!("on {}", opcode.mnemonic()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // reads numbers until a zero, printing the running total kept at 16 after each
+    const SUMS: [isize; 17] = [
+        3, 15, 1006, 15, 14, 1, 15, 16, 16, 4, 16, 1105, 1, 0, 99, 0, 0,
+    ];
+
+    fn debugger(inputs: &[isize]) -> Debugger {
+        Debugger::new(Intcode::new(&SUMS, inputs, false))
+    }
+
+    fn run(debugger: &mut Debugger, line: &str) -> String {
+        debugger.execute(parse_command(line).unwrap())
+    }
+
+    #[test]
+    fn commands_parse_in_short_and_long_form() {
+        assert_eq!(Ok(Command::Step(1)), parse_command("s"));
+        assert_eq!(Ok(Command::Step(5)), parse_command("step 5"));
+        assert_eq!(
+            Ok(Command::Break(Breakpoint::Opcode(OpCode::Output))),
+            parse_command("b out")
+        );
+        assert_eq!(
+            Ok(Command::Break(Breakpoint::Address(9))),
+            parse_command("break 9")
+        );
+        assert_eq!(Ok(Command::Input(vec![1, -2])), parse_command("input 1 -2"));
+        assert!(parse_command("set 1").is_err());
+        assert!(parse_command("x -1").is_err());
+        assert!(parse_command("step 0").is_err());
+    }
+
+    #[test]
+    fn breakpoints_and_watchpoints_stop_execution() {
+        let mut debugger = debugger(&[5, 7]);
+        run(&mut debugger, "b out");
+        assert_eq!(
+            "Breakpoint on OUT\n     9  OUT [16]",
+            run(&mut debugger, "c")
+        );
+        run(&mut debugger, "d out");
+        run(&mut debugger, "w 16");
+        assert_eq!(
+            "Output: 5\n[16] changed 5 -> 12\n     9  OUT [16]",
+            run(&mut debugger, "continue")
+        );
+        run(&mut debugger, "u 16");
+        assert_eq!(
+            "Output: 12\nWaiting for input, queue some with 'input'\n     0  IN -> [15]",
+            run(&mut debugger, "c")
+        );
+    }
+
+    #[test]
+    fn steps_ending_on_a_breakpoint_report_it() {
+        let mut debugger = debugger(&[5]);
+        run(&mut debugger, "b 9");
+        assert_eq!(
+            "Breakpoint at 9\n     9  OUT [16]",
+            run(&mut debugger, "s 3")
+        );
+    }
+
+    #[test]
+    fn memory_and_input_can_be_changed_while_paused() {
+        let mut debugger = debugger(&[]);
+        assert_eq!("[16] = 100", run(&mut debugger, "set 16 100"));
+        assert_eq!("Inputs: 1, 0", run(&mut debugger, "input 1 0"));
+        assert_eq!("     2  JF [15], #14", run(&mut debugger, "s"));
+        assert_eq!("Output: 101\n     0  IN -> [15]", run(&mut debugger, "s 4"));
+        assert_eq!("[15] = 1\n[16] = 101", run(&mut debugger, "x 15 2"));
+        assert_eq!(
+            Some("Showing 256 of 100000 cells"),
+            run(&mut debugger, "x 0 100000").lines().nth(MAX_EXAMINE)
+        );
+        assert_eq!(
+            format!(
+                "[{}] = 0\n[{}] = 0\nShowing 2 of 5 cells",
+                usize::MAX - 1,
+                usize::MAX
+            ),
+            run(&mut debugger, &format!("x {} 5", usize::MAX - 1))
+        );
+        assert_eq!("Halted", run(&mut debugger, "c"));
+        assert!(run(&mut debugger, "info").starts_with("ip = 14, rb = 0\n    14  halted\n"));
+    }
+}
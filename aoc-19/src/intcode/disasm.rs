@@ -0,0 +1,270 @@
+use std::collections::BTreeMap;
+use std::fmt;
+
+use super::{Instruction, OpCode, ParameterMode};
+
+// longest run of data words put on a single line
+const DATA_WIDTH: usize = 8;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Sweep {
+    // decodes every word in order, whatever does not decode is data
+    Linear,
+    // decodes only what control flow from address 0 can reach
+    Reachable,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Line {
+    Code {
+        instruction: Instruction,
+        words: Vec<isize>,
+    },
+    Data {
+        address: usize,
+        words: Vec<isize>,
+    },
+}
+
+impl Line {
+    pub fn address(&self) -> usize {
+        match self {
+            Line::Code { instruction, .. } => instruction.address,
+            Line::Data { address, .. } => *address,
+        }
+    }
+
+    pub fn words(&self) -> &[isize] {
+        match self {
+            Line::Code { words, .. } | Line::Data { words, .. } => words,
+        }
+    }
+}
+
+pub fn operand(mode: ParameterMode, value: isize) -> String {
+    match mode {
+        ParameterMode::Position => format!("[{}]", value),
+        ParameterMode::Immediate => format!("#{}", value),
+        ParameterMode::Relative if value < 0 => format!("[rb-{}]", -value),
+        ParameterMode::Relative => format!("[rb+{}]", value),
+    }
+}
+
+// `ADD [12], #5 -> [rb+3]`, the same syntax the assembler reads
+impl fmt::Display for Line {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Line::Code { instruction, words } => {
+                f.write_str(instruction.opcode.mnemonic())?;
+                for (i, parameter) in instruction.parameters().iter().enumerate() {
+                    let separator = if i == 0 { " " } else { ", " };
+                    write!(f, "{}{}", separator, operand(parameter.mode, words[i + 1]))?;
+                }
+                if let Some(store) = &instruction.store {
+                    let value = words[instruction.parameters().len() + 1];
+                    write!(f, " -> {}", operand(store.mode, value))?;
+                }
+                Ok(())
+            }
+            Line::Data { words, .. } => {
+                let words = words.iter().map(isize::to_string).collect::<Vec<_>>();
+                write!(f, "DATA {}", words.join(", "))
+            }
+        }
+    }
+}
+
+pub fn disassemble(program: &[isize], sweep: Sweep) -> Vec<Line> {
+    let starts = match sweep {
+        Sweep::Linear => linear_starts(program),
+        Sweep::Reachable => reachable_starts(program),
+    };
+
+    let mut lines = Vec::new();
+    let mut address = 0;
+    while address < program.len() {
+        match starts.get(&address) {
+            Some(instruction) => {
+                let end = address + instruction.size();
+                lines.push(Line::Code {
+                    instruction: *instruction,
+                    words: program[address..end].to_vec(),
+                });
+                address = end;
+            }
+            None => {
+                match lines.last_mut() {
+                    Some(Line::Data { words, .. }) if words.len() < DATA_WIDTH => {
+                        words.push(program[address])
+                    }
+                    _ => lines.push(Line::Data {
+                        address,
+                        words: vec![program[address]],
+                    }),
+                }
+                address += 1;
+            }
+        }
+    }
+    lines
+}
+
+// address, raw words and the decoded instruction, one line each
+pub fn listing(lines: &[Line]) -> String {
+    let mut out = String::new();
+    for line in lines {
+        let words = line
+            .words()
+            .iter()
+            .map(isize::to_string)
+            .collect::<Vec<_>>()
+            .join(",");
+        out.push_str(&format!("{:>6}  {:<24} {}\n", line.address(), words, line));
+    }
+    out
+}
+
+// just the instructions, as text the assembler reads back
+pub fn source(lines: &[Line]) -> String {
+    lines.iter().map(|line| format!("{}\n", line)).collect()
+}
+
+// instructions running off the end of the program are data as well, and so are opcodes with
+// stray mode digits, which the VM runs but which cannot be assembled back to the same word
+fn decode_at(program: &[isize], address: usize) -> Option<Instruction> {
+    Instruction::decode(address, |index| program.get(index).copied().unwrap_or(0))
+        .ok()
+        .filter(|instruction| address + instruction.size() <= program.len())
+        .filter(|instruction| instruction.word() == program[address])
+}
+
+fn linear_starts(program: &[isize]) -> BTreeMap<usize, Instruction> {
+    let mut starts = BTreeMap::new();
+    let mut address = 0;
+    while address < program.len() {
+        match decode_at(program, address) {
+            Some(instruction) => {
+                let size = instruction.size();
+                starts.insert(address, instruction);
+                address += size;
+            }
+            None => address += 1,
+        }
+    }
+    starts
+}
+
+// jump targets read from memory are only known at run time, so just immediate ones are followed;
+// the words after an unconditional jump are only decoded when something else jumps there
+fn reachable_starts(program: &[isize]) -> BTreeMap<usize, Instruction> {
+    let mut starts = BTreeMap::new();
+    let mut pending = vec![0];
+    while let Some(address) = pending.pop() {
+        if starts.contains_key(&address) {
+            continue;
+        }
+        let instruction = match decode_at(program, address) {
+            Some(instruction) => instruction,
+            None => continue,
+        };
+
+        match instruction.opcode {
+            OpCode::Halt => {}
+            OpCode::JumpIfTrue | OpCode::JumpIfFalse => {
+                let condition = &instruction.parameters()[0];
+                let target = &instruction.parameters()[1];
+                // an immediate condition either never or always holds, leaving one way unreachable
+                let jumps_when = instruction.opcode == OpCode::JumpIfTrue;
+                let fixed = (condition.mode == ParameterMode::Immediate)
+                    .then(|| (program[condition.index] != 0) == jumps_when);
+
+                let value = program[target.index];
+                if fixed != Some(false) && target.mode == ParameterMode::Immediate && value >= 0 {
+                    pending.push(value as usize);
+                }
+                if fixed != Some(true) {
+                    pending.push(address + instruction.size());
+                }
+            }
+            _ => pending.push(address + instruction.size()),
+        }
+        starts.insert(address, instruction);
+    }
+    starts
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn text(lines: &[Line]) -> Vec<String> {
+        lines.iter().map(Line::to_string).collect()
+    }
+
+    #[test]
+    fn operands_show_their_modes() {
+        let program = [22201, 12, 5, 3, 1105, 1, 9, 4, -2, 99];
+        assert_eq!(
+            vec![
+                "ADD [rb+12], [rb+5] -> [rb+3]",
+                "JT #1, #9",
+                "OUT [-2]",
+                "HLT"
+            ],
+            text(&disassemble(&program, Sweep::Linear))
+        );
+        assert_eq!(
+            "     0  22201,12,5,3             ADD [rb+12], [rb+5] -> [rb+3]\n",
+            listing(&disassemble(&program[..4], Sweep::Linear))
+        );
+    }
+
+    #[test]
+    fn undecodable_words_are_data() {
+        let program = [1101, 1, 2, 10, 99, 99999, 7, 77, 42, 1, 2];
+        assert_eq!(
+            vec!["ADD #1, #2 -> [10]", "HLT", "DATA 99999, 7, 77, 42, 1, 2"],
+            text(&disassemble(&program, Sweep::Reachable))
+        );
+        // a linear sweep decodes past the halt, up to an instruction running off the end
+        assert_eq!(
+            vec![
+                "ADD #1, #2 -> [10]",
+                "HLT",
+                "DATA 99999",
+                "LT [77], [42] -> [1]",
+                "DATA 2"
+            ],
+            text(&disassemble(&program, Sweep::Linear))
+        );
+    }
+
+    #[test]
+    fn reachable_sweep_follows_immediate_jumps() {
+        let program = [1006, 7, 4, 99, 104, 5, 99, 0];
+        assert_eq!(
+            vec!["JF [7], #4", "HLT", "OUT #5", "HLT", "DATA 0"],
+            text(&disassemble(&program, Sweep::Reachable))
+        );
+        let program = [1105, 0, 5, 99, 8, 104, 5, 99];
+        assert_eq!(
+            vec!["JT #0, #5", "HLT", "DATA 8, 104, 5, 99"],
+            text(&disassemble(&program, Sweep::Reachable))
+        );
+    }
+
+    #[test]
+    fn nothing_falls_through_an_unconditional_jump() {
+        let program = [1105, 7, 6, 1, 104, 5, 1106, 0, 10, 99, 99];
+        assert_eq!(
+            vec![
+                "JT #7, #6",
+                "DATA 1, 104, 5",
+                "JF #0, #10",
+                "DATA 99",
+                "HLT"
+            ],
+            text(&disassemble(&program, Sweep::Reachable))
+        );
+    }
+}
@@ -0,0 +1,234 @@
+use std::collections::BTreeMap;
+use std::fmt;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MemoryLimits {
+    // writes up to this far past the end of the dense region grow it, farther ones are sparse
+    pub dense_slack: usize,
+    // highest address a program may write to, besides those of its own image
+    pub max_address: usize,
+    // most cells kept in the sparse map
+    pub max_sparse_cells: usize,
+}
+
+impl Default for MemoryLimits {
+    fn default() -> Self {
+        MemoryLimits {
+            dense_slack: 4096,
+            max_address: isize::MAX as usize,
+            max_sparse_cells: 1 << 20,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MemoryError {
+    PastMaxAddress { max_address: usize },
+    SparseFull { cells: usize },
+}
+
+impl fmt::Display for MemoryError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MemoryError::PastMaxAddress { max_address } => {
+                write!(f, "past the highest writable address {}", max_address)
+            }
+            MemoryError::SparseFull { cells } => {
+                write!(f, "sparse memory is full at {} cells", cells)
+            }
+        }
+    }
+}
+
+// The program image and the cells written just past it are a plain vector, anything farther
+// out lives in a map, so a single write to a huge address does not allocate all the memory
+// below it. Every cell never written reads as zero.
+#[derive(Debug, PartialEq, Eq)]
+pub struct Memory {
+    dense: Vec<isize>,
+    sparse: BTreeMap<usize, isize>,
+    limits: MemoryLimits,
+}
+
+impl Memory {
+    pub fn new(image: &[isize], limits: MemoryLimits) -> Self {
+        Memory {
+            dense: image.to_vec(),
+            sparse: BTreeMap::new(),
+            limits,
+        }
+    }
+
+    // lays memory out again from what `dense` and `sparse` returned
+    pub fn from_parts(
+        dense: Vec<isize>,
+        sparse: impl IntoIterator<Item = (usize, isize)>,
+        limits: MemoryLimits,
+    ) -> Self {
+        let mut memory = Memory {
+            dense,
+            sparse: BTreeMap::new(),
+            limits,
+        };
+        for (address, value) in sparse {
+            match memory.dense.get_mut(address) {
+                Some(cell) => *cell = value,
+                None if value != 0 => {
+                    memory.sparse.insert(address, value);
+                }
+                None => {}
+            }
+        }
+        memory
+    }
+
+    pub fn limits(&self) -> MemoryLimits {
+        self.limits
+    }
+
+    pub fn set_limits(&mut self, limits: MemoryLimits) {
+        self.limits = limits;
+    }
+
+    pub fn read(&self, address: usize) -> isize {
+        match self.dense.get(address) {
+            Some(&value) => value,
+            None => self.read_far(address),
+        }
+    }
+
+    pub fn write(&mut self, address: usize, value: isize) -> Result<(), MemoryError> {
+        match self.dense.get_mut(address) {
+            Some(cell) => {
+                *cell = value;
+                Ok(())
+            }
+            None => self.write_far(address, value),
+        }
+    }
+
+    // kept out of `read` and `write`, which the VM runs for nearly every instruction
+    #[cold]
+    fn read_far(&self, address: usize) -> isize {
+        self.sparse.get(&address).copied().unwrap_or(0)
+    }
+
+    #[cold]
+    fn write_far(&mut self, address: usize, value: isize) -> Result<(), MemoryError> {
+        if address > self.limits.max_address {
+            return Err(MemoryError::PastMaxAddress {
+                max_address: self.limits.max_address,
+            });
+        }
+
+        if address - self.dense.len() < self.limits.dense_slack {
+            self.grow(address + 1);
+            self.dense[address] = value;
+        } else if value == 0 {
+            self.sparse.remove(&address);
+        } else {
+            let cells = self.sparse.len();
+            if cells >= self.limits.max_sparse_cells && !self.sparse.contains_key(&address) {
+                return Err(MemoryError::SparseFull { cells });
+            }
+            self.sparse.insert(address, value);
+        }
+        Ok(())
+    }
+
+    // cells the sparse map held below the new end move into the dense region
+    fn grow(&mut self, len: usize) {
+        let far = self.sparse.split_off(&len);
+        let near = std::mem::replace(&mut self.sparse, far);
+        self.dense.resize(len, 0);
+        for (address, value) in near {
+            self.dense[address] = value;
+        }
+    }
+
+    pub fn dense(&self) -> &[isize] {
+        &self.dense
+    }
+
+    // nonzero cells past the dense region, by address
+    pub fn sparse(&self) -> impl Iterator<Item = (usize, isize)> + '_ {
+        self.sparse
+            .iter()
+            .map(|(&address, &value)| (address, value))
+    }
+}
+
+impl Clone for Memory {
+    fn clone(&self) -> Self {
+        Memory {
+            dense: self.dense.clone(),
+            sparse: self.sparse.clone(),
+            limits: self.limits,
+        }
+    }
+
+    // restoring a snapshot copies into the buffers the machine already has
+    fn clone_from(&mut self, source: &Self) {
+        self.dense.clone_from(&source.dense);
+        self.sparse.clone_from(&source.sparse);
+        self.limits = source.limits;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn limits(dense_slack: usize, max_address: usize, max_sparse_cells: usize) -> MemoryLimits {
+        MemoryLimits {
+            dense_slack,
+            max_address,
+            max_sparse_cells,
+        }
+    }
+
+    #[test]
+    fn far_writes_stay_sparse() {
+        let mut memory = Memory::new(&[1, 2, 3], MemoryLimits::default());
+        memory.write(5, 6).unwrap();
+        memory.write(1_000_000_000_000, 7).unwrap();
+
+        assert_eq!(&[1, 2, 3, 0, 0, 6], memory.dense());
+        assert_eq!(
+            vec![(1_000_000_000_000, 7)],
+            memory.sparse().collect::<Vec<_>>()
+        );
+        assert_eq!(7, memory.read(1_000_000_000_000));
+        assert_eq!(0, memory.read(1_000_000_000_001));
+    }
+
+    #[test]
+    fn growing_the_dense_region_takes_in_sparse_cells() {
+        let mut memory = Memory::new(&[1], limits(4, usize::MAX, 8));
+        memory.write(7, 8).unwrap();
+        memory.write(12, 13).unwrap();
+        memory.write(4, 5).unwrap();
+        memory.write(8, 9).unwrap();
+
+        assert_eq!(&[1, 0, 0, 0, 5, 0, 0, 8, 9], memory.dense());
+        assert_eq!(vec![(12, 13)], memory.sparse().collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn limits_are_enforced() {
+        let mut memory = Memory::new(&[], limits(0, 100, 1));
+        assert_eq!(
+            Err(MemoryError::PastMaxAddress { max_address: 100 }),
+            memory.write(101, 1)
+        );
+        memory.write(50, 1).unwrap();
+        memory.write(50, 2).unwrap();
+        assert_eq!(
+            Err(MemoryError::SparseFull { cells: 1 }),
+            memory.write(60, 1)
+        );
+        // zeroes are not stored, so clearing a cell makes room again
+        memory.write(50, 0).unwrap();
+        memory.write(60, 1).unwrap();
+    }
+}
@@ -0,0 +1,16 @@
+pub mod ascii;
+pub mod asm;
+pub mod cfg;
+pub mod debugger;
+pub mod disasm;
+pub mod memory;
+pub mod network;
+pub mod reference;
+pub mod snapshot;
+pub mod trace;
+mod vm;
+
+pub use vm::{
+    format_program, parse_program, Instruction, Intcode, OpCode, Parameter, ParameterMode,
+    ProgramResult, Step, VmError,
+};
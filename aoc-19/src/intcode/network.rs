@@ -0,0 +1,366 @@
+use std::fmt;
+
+use super::{Intcode, Step, VmError};
+
+// instructions a machine may run before the next one gets its turn, so one busy machine cannot
+// starve the rest
+const QUANTUM: usize = 10_000;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Route {
+    // outputs stay in the machine's own `outputs`
+    Keep,
+    // every output goes to the input of one machine
+    Pipe(usize),
+    // every output goes to the input of each of these machines
+    Broadcast(Vec<usize>),
+    // outputs come in threes, a destination address then the two values sent to it
+    Packets,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum NetworkError {
+    Machine { machine: usize, error: VmError },
+    UnknownAddress { machine: usize, address: isize },
+}
+
+impl fmt::Display for NetworkError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            NetworkError::Machine { machine, error } => write!(f, "Machine {}: {}", machine, error),
+            NetworkError::UnknownAddress { machine, address } => write!(
+                f,
+                "Machine {} sent a packet to unknown address {}",
+                machine, address
+            ),
+        }
+    }
+}
+
+impl std::error::Error for NetworkError {}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Stop {
+    // every machine halted
+    Halted,
+    // every machine is waiting for input nobody is going to send
+    Idle,
+    // the network went idle and the NAT woke machine 0 with the last packet it was sent
+    NatWake(isize, isize),
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Stats {
+    pub instructions: usize,
+    // values put on the machine's input, by other machines or `send`
+    pub inputs: usize,
+    pub outputs: usize,
+    // turns that ended waiting for input
+    pub waits: usize,
+    // times the idle input was handed out instead of blocking
+    pub idle_polls: usize,
+}
+
+struct Machine {
+    vm: Intcode,
+    route: Route,
+    packet: Vec<isize>,
+    stats: Stats,
+}
+
+pub struct Network {
+    machines: Vec<Machine>,
+    nat: Option<isize>,
+    nat_packet: Option<(isize, isize)>,
+    idle_input: Option<isize>,
+}
+
+impl Network {
+    pub fn new() -> Self {
+        Network {
+            machines: Vec::new(),
+            nat: None,
+            nat_packet: None,
+            idle_input: None,
+        }
+    }
+
+    // packets sent to this address are held back, the last one wakes the network when it idles
+    pub fn with_nat(mut self, address: isize) -> Self {
+        self.nat = Some(address);
+        self
+    }
+
+    // handed to a machine waiting on an empty input, once per turn, instead of blocking it
+    pub fn with_idle_input(mut self, value: isize) -> Self {
+        self.idle_input = Some(value);
+        self
+    }
+
+    // machines are numbered, and addressed by packets, in the order they are added
+    pub fn add(&mut self, vm: Intcode, route: Route) -> usize {
+        self.machines.push(Machine {
+            vm,
+            route,
+            packet: Vec::new(),
+            stats: Stats::default(),
+        });
+        self.machines.len() - 1
+    }
+
+    // for machines returned by `add`, routes are checked when they are used
+    pub fn send(&mut self, machine: usize, values: &[isize]) {
+        let machine = &mut self.machines[machine];
+        machine.vm.add_inputs(values);
+        machine.stats.inputs += values.len();
+    }
+
+    pub fn machine(&self, machine: usize) -> &Intcode {
+        &self.machines[machine].vm
+    }
+
+    pub fn stats(&self, machine: usize) -> Stats {
+        self.machines[machine].stats
+    }
+
+    pub fn len(&self) -> usize {
+        self.machines.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.machines.is_empty()
+    }
+
+    // gives every machine a turn in order, until all of them halt or a whole round goes by
+    // without anything being sent or received
+    pub fn run(&mut self) -> Result<Stop, NetworkError> {
+        loop {
+            let mut active = false;
+            for machine in 0..self.machines.len() {
+                if !self.machines[machine].vm.is_halted() {
+                    active |= self.turn(machine)?;
+                }
+            }
+
+            if self.machines.iter().all(|machine| machine.vm.is_halted()) {
+                return Ok(Stop::Halted);
+            }
+            if !active {
+                return match self.nat_packet {
+                    Some((x, y)) if !self.machines.is_empty() => {
+                        self.send(0, &[x, y]);
+                        Ok(Stop::NatWake(x, y))
+                    }
+                    _ => Ok(Stop::Idle),
+                };
+            }
+        }
+    }
+
+    // whether the machine did anything besides waiting for input
+    fn turn(&mut self, index: usize) -> Result<bool, NetworkError> {
+        let mut active = !self.machines[index].vm.pending_inputs().is_empty();
+        let mut polled = false;
+
+        for _ in 0..QUANTUM {
+            let machine = &mut self.machines[index];
+            let step = machine.vm.step().map_err(|error| NetworkError::Machine {
+                machine: index,
+                error,
+            })?;
+
+            match step {
+                Step::Executed => machine.stats.instructions += 1,
+                Step::Output(value) => {
+                    machine.stats.instructions += 1;
+                    machine.stats.outputs += 1;
+                    active = true;
+                    self.route(index, value)?;
+                }
+                Step::NeedsInput => match self.idle_input {
+                    Some(value) if !polled => {
+                        polled = true;
+                        machine.vm.add_inputs(&[value]);
+                        machine.stats.idle_polls += 1;
+                    }
+                    _ => {
+                        machine.stats.waits += 1;
+                        return Ok(active);
+                    }
+                },
+                Step::Halted => {
+                    machine.stats.instructions += 1;
+                    return Ok(active);
+                }
+            }
+        }
+        Ok(true)
+    }
+
+    // pipes and broadcasts may name machines added later, so they are only checked here
+    fn route(&mut self, from: usize, value: isize) -> Result<(), NetworkError> {
+        match self.machines[from].route.clone() {
+            Route::Keep => {}
+            Route::Pipe(to) => self.deliver(from, to, &[value])?,
+            Route::Broadcast(targets) => {
+                for to in targets {
+                    self.deliver(from, to, &[value])?;
+                }
+            }
+            Route::Packets => {
+                let packet = &mut self.machines[from].packet;
+                packet.push(value);
+                if let [address, x, y] = packet[..] {
+                    packet.clear();
+                    if Some(address) == self.nat {
+                        self.nat_packet = Some((x, y));
+                    } else if address >= 0 {
+                        self.deliver(from, address as usize, &[x, y])?;
+                    } else {
+                        return Err(NetworkError::UnknownAddress {
+                            machine: from,
+                            address,
+                        });
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn deliver(&mut self, from: usize, to: usize, values: &[isize]) -> Result<(), NetworkError> {
+        if to >= self.machines.len() {
+            return Err(NetworkError::UnknownAddress {
+                machine: from,
+                address: to as isize,
+            });
+        }
+        self.send(to, values);
+        Ok(())
+    }
+}
+
+impl Default for Network {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::intcode::asm::assemble;
+
+    fn machine(source: &str) -> Intcode {
+        Intcode::new(&assemble(source).unwrap(), &[], false)
+    }
+
+    #[test]
+    fn pipes_pass_values_around_a_ring() {
+        // adds one to what it reads and passes it on, halting once it sent ten
+        let source = "
+            loop:   in -> [value]
+                    add [value], #1 -> [value]
+                    out [value]
+                    lt [value], #10 -> [more]
+                    jt [more], #loop
+                    hlt
+            value:  data 0
+            more:   data 0
+        ";
+        let mut network = Network::new();
+        network.add(machine(source), Route::Pipe(1));
+        network.add(machine(source), Route::Pipe(0));
+        network.send(0, &[0]);
+
+        assert_eq!(Ok(Stop::Halted), network.run());
+        assert_eq!(vec![1, 3, 5, 7, 9, 11], network.machine(0).outputs);
+        assert_eq!(vec![2, 4, 6, 8, 10], network.machine(1).outputs);
+        assert_eq!(6, network.stats(0).inputs);
+        assert_eq!(5, network.stats(1).outputs);
+        assert_eq!(26, network.stats(1).instructions);
+    }
+
+    #[test]
+    fn nat_wakes_an_idle_network() {
+        // machine 0 sends 7 and 8 to machine 1, which sends their sum to the NAT
+        let sender = "
+                    in -> [address]
+                    out #1
+                    out #7
+                    out #8
+            poll:   in -> [x]
+                    jt #1, #poll
+            address: data 0
+            x:      data 0
+        ";
+        let adder = "
+                    in -> [address]
+            poll:   in -> [x]
+                    eq [x], #-1 -> [idle]
+                    jt [idle], #poll
+                    in -> [y]
+                    add [x], [y] -> [x]
+                    out #255
+                    out [x]
+                    out #0
+                    jt #1, #poll
+            address: data 0
+            x:      data 0
+            y:      data 0
+            idle:   data 0
+        ";
+        let mut network = Network::new().with_nat(255).with_idle_input(-1);
+        for (address, source) in [sender, adder].iter().enumerate() {
+            network.add(machine(source), Route::Packets);
+            network.send(address, &[address as isize]);
+        }
+
+        assert_eq!(Ok(Stop::NatWake(15, 0)), network.run());
+        assert_eq!(
+            vec![15, 0],
+            network
+                .machine(0)
+                .pending_inputs()
+                .iter()
+                .copied()
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(Ok(Stop::NatWake(15, 0)), network.run());
+        assert_eq!(3, network.stats(1).outputs);
+        assert!(network.stats(0).idle_polls > 0);
+    }
+
+    #[test]
+    fn packets_to_nowhere_are_errors() {
+        let mut network = Network::new();
+        network.add(machine("out #4\nout #1\nout #2\nhlt"), Route::Packets);
+        assert_eq!(
+            Err(NetworkError::UnknownAddress {
+                machine: 0,
+                address: 4
+            }),
+            network.run()
+        );
+
+        let mut network = Network::new();
+        network.add(machine("out #1\nhlt"), Route::Pipe(1));
+        assert_eq!(
+            Err(NetworkError::UnknownAddress {
+                machine: 0,
+                address: 1
+            }),
+            network.run()
+        );
+
+        let mut network = Network::new();
+        network.add(machine("out #1\nhlt"), Route::Broadcast(vec![0, 3]));
+        assert_eq!(
+            Err(NetworkError::UnknownAddress {
+                machine: 0,
+                address: 3
+            }),
+            network.run()
+        );
+    }
+}
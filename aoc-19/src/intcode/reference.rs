@@ -0,0 +1,193 @@
+use std::collections::VecDeque;
+
+use super::{OpCode, ParameterMode, VmError};
+
+struct Parameter {
+    mode: ParameterMode,
+    index: usize,
+}
+
+struct Instruction {
+    opcode: OpCode,
+    parameters: Vec<Parameter>,
+    store: Option<Parameter>,
+}
+
+struct Reference {
+    memory: Vec<isize>,
+    instruction_pointer: usize,
+    relative_base: isize,
+    inputs: VecDeque<isize>,
+    outputs: Vec<isize>,
+}
+
+// The interpreter as it was before opcodes were cached: every step decodes its instruction
+// again into freshly allocated parameters, and memory is a plain `Vec` grown by every store
+// past its end. Only kept so `intcode bench` has a baseline to compare the VM against, it runs
+// the program to halt on inputs given up front and returns what it output.
+pub fn run(program: &[isize], inputs: &[isize]) -> Result<Vec<isize>, VmError> {
+    let mut vm = Reference {
+        memory: program.to_vec(),
+        instruction_pointer: 0,
+        relative_base: 0,
+        inputs: inputs.iter().copied().collect(),
+        outputs: Vec::new(),
+    };
+    while vm.step()? {}
+    Ok(vm.outputs)
+}
+
+impl Reference {
+    fn decode(&self) -> Result<Instruction, VmError> {
+        let address = self.instruction_pointer;
+        let word = self.at(address);
+        let opcode = OpCode::from_value(word % 100).ok_or(VmError::UnknownOpcode {
+            address,
+            opcode: word,
+        })?;
+        let mut modes = word / 100;
+
+        let (param_count, has_store) = opcode.parameter_count();
+        let mut parameters = Vec::with_capacity(param_count);
+        let mut next_parameter = |index: usize| {
+            let mode = ParameterMode::from_value(modes % 10).ok_or(VmError::BadMode {
+                address,
+                mode: modes % 10,
+            })?;
+            modes /= 10;
+            Ok(Parameter { mode, index })
+        };
+
+        for i in 1..=param_count {
+            parameters.push(next_parameter(address + i)?);
+        }
+        let store = if has_store {
+            let store = next_parameter(address + param_count + 1)?;
+            if store.mode == ParameterMode::Immediate {
+                return Err(VmError::BadMode { address, mode: 1 });
+            }
+            Some(store)
+        } else {
+            None
+        };
+
+        Ok(Instruction {
+            opcode,
+            parameters,
+            store,
+        })
+    }
+
+    fn at(&self, index: usize) -> isize {
+        *self.memory.get(index).unwrap_or(&0)
+    }
+
+    fn address(&self, target: isize) -> Result<usize, VmError> {
+        if target < 0 {
+            Err(VmError::NegativeAddress {
+                address: self.instruction_pointer,
+                target,
+            })
+        } else {
+            Ok(target as usize)
+        }
+    }
+
+    fn relative(&self, offset: isize) -> Result<isize, VmError> {
+        self.relative_base
+            .checked_add(offset)
+            .ok_or(VmError::RelativeOverflow {
+                address: self.instruction_pointer,
+                base: self.relative_base,
+                offset,
+            })
+    }
+
+    fn value(&self, param: &Parameter) -> Result<isize, VmError> {
+        let value = match param.mode {
+            ParameterMode::Immediate => self.at(param.index),
+            ParameterMode::Position => self.at(self.address(self.at(param.index))?),
+            ParameterMode::Relative => self.at(self.address(self.relative(self.at(param.index))?)?),
+        };
+        Ok(value)
+    }
+
+    fn store(&mut self, param: &Parameter, value: isize) -> Result<(), VmError> {
+        let target = match param.mode {
+            ParameterMode::Position => self.address(self.at(param.index))?,
+            ParameterMode::Relative => self.address(self.relative(self.at(param.index))?)?,
+            ParameterMode::Immediate => unreachable!("Decoding rejects immediate stores"),
+        };
+        if target >= self.memory.len() {
+            self.memory.resize(target + 1, 0);
+        }
+        self.memory[target] = value;
+        Ok(())
+    }
+
+    // false once the program halted
+    fn step(&mut self) -> Result<bool, VmError> {
+        let ip = self.instruction_pointer;
+        let instruction = self.decode()?;
+        let operand = |i: usize| self.value(&instruction.parameters[i]);
+        let overflow = VmError::ArithmeticOverflow { address: ip };
+        let mut next = ip + 1 + instruction.parameters.len() + instruction.store.iter().count();
+
+        let stored = match instruction.opcode {
+            OpCode::Halt => return Ok(false),
+            OpCode::Output => {
+                let value = operand(0)?;
+                self.outputs.push(value);
+                None
+            }
+            OpCode::Input => match self.inputs.pop_front() {
+                Some(value) => Some(value),
+                None => return Err(VmError::InputExhausted { address: ip }),
+            },
+            OpCode::Add => Some(operand(0)?.checked_add(operand(1)?).ok_or(overflow)?),
+            OpCode::Multiply => Some(operand(0)?.checked_mul(operand(1)?).ok_or(overflow)?),
+            OpCode::LessThan => Some((operand(0)? < operand(1)?) as isize),
+            OpCode::Equals => Some((operand(0)? == operand(1)?) as isize),
+            OpCode::JumpIfTrue | OpCode::JumpIfFalse => {
+                let (condition, target) = (operand(0)?, operand(1)?);
+                if (condition != 0) == (instruction.opcode == OpCode::JumpIfTrue) {
+                    next = self.address(target)?;
+                }
+                None
+            }
+            OpCode::RelativeBaseOffset => {
+                self.relative_base = self.relative(operand(0)?)?;
+                None
+            }
+        };
+
+        if let (Some(value), Some(store)) = (stored, &instruction.store) {
+            self.store(store, value)?;
+        }
+        self.instruction_pointer = next;
+        Ok(true)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::intcode::Intcode;
+
+    #[test]
+    fn runs_like_the_vm() {
+        let quine = [
+            109, 1, 204, -1, 1001, 100, 1, 100, 1008, 100, 16, 101, 1006, 101, 0, 99,
+        ];
+        assert_eq!(Ok(quine.to_vec()), run(&quine, &[]));
+
+        let program = [3, 9, 8, 9, 10, 9, 4, 9, 99, -1, 8];
+        let mut intcode = Intcode::new(&program, &[8], false);
+        intcode.run_to_halt().unwrap();
+        assert_eq!(Ok(intcode.outputs), run(&program, &[8]));
+        assert_eq!(
+            Err(VmError::InputExhausted { address: 0 }),
+            run(&program, &[])
+        );
+    }
+}
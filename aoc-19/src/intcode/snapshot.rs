@@ -0,0 +1,315 @@
+use std::collections::VecDeque;
+use std::fmt;
+use std::path::Path;
+
+use super::memory::{Memory, MemoryLimits};
+use super::{format_program, parse_program};
+
+const HEADER: &str = "intcode snapshot 1";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SnapshotError {
+    // 1-based line of the file, 0 when the file as a whole is the problem
+    pub line: usize,
+    pub msg: String,
+}
+
+impl SnapshotError {
+    fn new(line: usize, msg: impl Into<String>) -> Self {
+        Self {
+            line,
+            msg: msg.into(),
+        }
+    }
+}
+
+impl fmt::Display for SnapshotError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.line {
+            0 => f.write_str(&self.msg),
+            line => write!(f, "line {}: {}", line, self.msg),
+        }
+    }
+}
+
+impl std::error::Error for SnapshotError {}
+
+// Everything a machine needs to carry on from where it was, taken with `Intcode::snapshot`.
+// Restoring one with `Intcode::restore` reuses the machine's buffers, so trying every branch
+// from a paused state costs little more than copying its memory.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Snapshot {
+    pub(super) memory: Memory,
+    pub(super) instruction_pointer: usize,
+    pub(super) relative_base: isize,
+    pub(super) inputs: VecDeque<isize>,
+    pub(super) outputs: Vec<isize>,
+    pub(super) diagnostic_code: Option<isize>,
+    pub(super) pause_on_output: bool,
+    pub(super) halted: bool,
+}
+
+// One `key value` line per field, lists in the program's own comma-separated format:
+//
+//     intcode snapshot 1
+//     ip 12
+//     rb -3
+//     memory 1101,1,2,3,99
+//     sparse 1000000000000=7
+impl fmt::Display for Snapshot {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let inputs = self.inputs.iter().copied().collect::<Vec<_>>();
+        let sparse = self
+            .memory
+            .sparse()
+            .map(|(address, value)| format!("{}={}", address, value))
+            .collect::<Vec<_>>();
+        let limits = self.memory.limits();
+
+        writeln!(f, "{}", HEADER)?;
+        writeln!(f, "ip {}", self.instruction_pointer)?;
+        writeln!(f, "rb {}", self.relative_base)?;
+        writeln!(f, "halted {}", self.halted)?;
+        writeln!(f, "pause_on_output {}", self.pause_on_output)?;
+        match self.diagnostic_code {
+            Some(code) => writeln!(f, "diagnostic {}", code)?,
+            None => writeln!(f, "diagnostic -")?,
+        }
+        field(f, "inputs", &format_program(&inputs))?;
+        field(f, "outputs", &format_program(&self.outputs))?;
+        writeln!(
+            f,
+            "limits {} {} {}",
+            limits.dense_slack, limits.max_address, limits.max_sparse_cells
+        )?;
+        field(f, "memory", &format_program(self.memory.dense()))?;
+        field(f, "sparse", &sparse.join(","))
+    }
+}
+
+// an empty list leaves just the key
+fn field(f: &mut fmt::Formatter<'_>, key: &str, value: &str) -> fmt::Result {
+    match value {
+        "" => writeln!(f, "{}", key),
+        _ => writeln!(f, "{} {}", key, value),
+    }
+}
+
+impl Snapshot {
+    pub fn parse(text: &str) -> Result<Self, SnapshotError> {
+        let mut lines = text.lines().enumerate().map(|(idx, line)| (idx + 1, line));
+        match lines.next() {
+            Some((_, HEADER)) => {}
+            _ => return Err(SnapshotError::new(1, "Not an Intcode snapshot")),
+        }
+
+        let mut fields = Fields::default();
+        for (number, line) in lines {
+            // files touched by hand tend to pick up blank lines
+            let line = line.trim();
+            if line.is_empty() {
+                continue;
+            }
+            let (key, value) = line.split_once(' ').unwrap_or((line, ""));
+            fields
+                .set(key, value.trim())
+                .map_err(|msg| SnapshotError::new(number, msg))?;
+        }
+        fields.into_snapshot()
+    }
+
+    pub fn read_file(path: impl AsRef<Path>) -> Result<Self, SnapshotError> {
+        let path = path.as_ref();
+        let text = std::fs::read_to_string(path).map_err(|e| {
+            SnapshotError::new(0, format!("Could not read '{}': {}", path.display(), e))
+        })?;
+        Self::parse(&text)
+    }
+
+    pub fn write_file(&self, path: impl AsRef<Path>) -> std::io::Result<()> {
+        std::fs::write(path, self.to_string())
+    }
+}
+
+#[derive(Default)]
+struct Fields {
+    instruction_pointer: Option<usize>,
+    relative_base: Option<isize>,
+    halted: Option<bool>,
+    pause_on_output: Option<bool>,
+    diagnostic_code: Option<Option<isize>>,
+    inputs: Option<Vec<isize>>,
+    outputs: Option<Vec<isize>>,
+    limits: Option<MemoryLimits>,
+    dense: Option<Vec<isize>>,
+    sparse: Option<Vec<(usize, isize)>>,
+}
+
+impl Fields {
+    fn set(&mut self, key: &str, value: &str) -> Result<(), String> {
+        match key {
+            "ip" => set(&mut self.instruction_pointer, number(value)?),
+            "rb" => set(&mut self.relative_base, number(value)?),
+            "halted" => set(&mut self.halted, number(value)?),
+            "pause_on_output" => set(&mut self.pause_on_output, number(value)?),
+            "diagnostic" if value == "-" => set(&mut self.diagnostic_code, None),
+            "diagnostic" => set(&mut self.diagnostic_code, Some(number(value)?)),
+            "inputs" => set(&mut self.inputs, list(value)?),
+            "outputs" => set(&mut self.outputs, list(value)?),
+            "limits" => {
+                let limits = value
+                    .split_whitespace()
+                    .map(number)
+                    .collect::<Result<Vec<_>, _>>()?;
+                match limits[..] {
+                    [dense_slack, max_address, max_sparse_cells] => set(
+                        &mut self.limits,
+                        MemoryLimits {
+                            dense_slack,
+                            max_address,
+                            max_sparse_cells,
+                        },
+                    ),
+                    _ => Err(String::from("'limits' takes three numbers")),
+                }
+            }
+            "memory" => set(&mut self.dense, list(value)?),
+            "sparse" => {
+                let cells = value
+                    .split(',')
+                    .filter(|cell| !cell.is_empty())
+                    .map(|cell| match cell.split_once('=') {
+                        Some((address, value)) => Ok((number(address)?, number(value)?)),
+                        None => Err(format!("Sparse cell '{}' should be address=value", cell)),
+                    })
+                    .collect::<Result<Vec<_>, _>>()?;
+                set(&mut self.sparse, cells)
+            }
+            _ => Err(format!("Unknown field '{}'", key)),
+        }
+    }
+
+    fn into_snapshot(self) -> Result<Snapshot, SnapshotError> {
+        let missing = |name: &str| SnapshotError::new(0, format!("Snapshot has no '{}'", name));
+        let dense = self.dense.ok_or_else(|| missing("memory"))?;
+        let sparse = self.sparse.ok_or_else(|| missing("sparse"))?;
+        let limits = self.limits.ok_or_else(|| missing("limits"))?;
+
+        Ok(Snapshot {
+            memory: Memory::from_parts(dense, sparse, limits),
+            instruction_pointer: self.instruction_pointer.ok_or_else(|| missing("ip"))?,
+            relative_base: self.relative_base.ok_or_else(|| missing("rb"))?,
+            inputs: self.inputs.ok_or_else(|| missing("inputs"))?.into(),
+            outputs: self.outputs.ok_or_else(|| missing("outputs"))?,
+            diagnostic_code: self.diagnostic_code.ok_or_else(|| missing("diagnostic"))?,
+            pause_on_output: self
+                .pause_on_output
+                .ok_or_else(|| missing("pause_on_output"))?,
+            halted: self.halted.ok_or_else(|| missing("halted"))?,
+        })
+    }
+}
+
+fn set<T>(field: &mut Option<T>, value: T) -> Result<(), String> {
+    match field.replace(value) {
+        Some(_) => Err(String::from("Field given twice")),
+        None => Ok(()),
+    }
+}
+
+fn number<T: std::str::FromStr>(text: &str) -> Result<T, String> {
+    text.parse()
+        .map_err(|_| format!("'{}' is not a valid value here", text))
+}
+
+fn list(text: &str) -> Result<Vec<isize>, String> {
+    if text.is_empty() {
+        return Ok(Vec::new());
+    }
+    parse_program(text).map_err(|e| format!("'{}' is not a list of numbers: {}", text, e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::intcode::asm::assemble;
+    use crate::intcode::{Intcode, ProgramResult};
+
+    // keeps a running total of its inputs far out in memory, printing it after each one
+    fn machine() -> Intcode {
+        let program = assemble(
+            "
+            loop:   in -> [value]
+                    add [1000000000000], [value] -> [1000000000000]
+                    out [1000000000000]
+                    jt #1, #loop
+            value:  data 0
+            ",
+        )
+        .unwrap();
+        Intcode::new(&program, &[5], false)
+    }
+
+    #[test]
+    fn branches_can_be_tried_from_one_state() {
+        let mut intcode = machine();
+        assert!(matches!(
+            intcode.run_program(),
+            Ok(ProgramResult::InputPause)
+        ));
+        let paused = intcode.snapshot();
+
+        let mut totals = Vec::new();
+        for input in 1..=3 {
+            intcode.restore(&paused);
+            intcode.add_inputs(&[input]);
+            intcode.run_program().unwrap();
+            totals.push(intcode.last_output());
+        }
+        assert_eq!(vec![Some(6), Some(7), Some(8)], totals);
+
+        intcode.restore(&paused);
+        assert_eq!(paused, intcode.snapshot());
+    }
+
+    #[test]
+    fn snapshots_survive_the_file_format() {
+        let mut intcode = machine();
+        intcode.add_inputs(&[-2, 9]);
+        intcode.run_program().unwrap();
+        let snapshot = intcode.snapshot();
+
+        let text = snapshot.to_string();
+        assert!(text.contains("\nsparse 1000000000000=12\n"), "{}", text);
+        assert_eq!(Ok(snapshot.clone()), Snapshot::parse(&text));
+        assert_eq!(
+            Ok(snapshot.clone()),
+            Snapshot::parse(&format!("{}\n  \n", text.replacen('\n', "\n\n", 2)))
+        );
+
+        let mut resumed = Intcode::from(Snapshot::parse(&text).unwrap());
+        resumed.add_inputs(&[100]);
+        resumed.run_program().unwrap();
+        assert_eq!(vec![5, 3, 12, 112], resumed.outputs);
+    }
+
+    #[test]
+    fn damaged_snapshots_are_rejected() {
+        let text = machine().snapshot().to_string();
+        let error = |text: &str| Snapshot::parse(text).unwrap_err().to_string();
+
+        assert_eq!("line 1: Not an Intcode snapshot", error("ip 0"));
+        assert_eq!(
+            "line 2: 'x' is not a valid value here",
+            error(&text.replace("ip 0", "ip x"))
+        );
+        assert_eq!(
+            "line 12: Field given twice",
+            error(&format!("{}rb 1\n", text))
+        );
+        assert_eq!(
+            "Snapshot has no 'outputs'",
+            error(&text.replace("outputs\n", ""))
+        );
+    }
+}
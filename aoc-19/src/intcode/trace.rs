@@ -0,0 +1,279 @@
+use std::fmt;
+use std::io::{self, Read, Write};
+
+use super::{OpCode, ParameterMode};
+
+// marks the file format, bumped whenever the encoding changes
+const MAGIC: &[u8; 4] = b"ICT2";
+
+const WRITES: u8 = 1;
+const JUMPED: u8 = 2;
+
+// one executed instruction, with the values its parameters read before it ran
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TraceEvent {
+    pub ip: usize,
+    pub opcode: OpCode,
+    // the mode digits of the opcode word, `10` for `1002`
+    pub modes: u8,
+    pub operands: Vec<isize>,
+    pub write: Option<(usize, isize)>,
+    pub jumped: bool,
+}
+
+impl TraceEvent {
+    // one per parameter in operand order, the store target last
+    pub fn modes(&self) -> impl Iterator<Item = Option<ParameterMode>> + '_ {
+        let (count, has_store) = self.opcode.parameter_count();
+        (0..count + has_store as usize).map(move |i| {
+            ParameterMode::from_value(self.modes as isize / 10isize.pow(i as u32) % 10)
+        })
+    }
+}
+
+// `ADD (iip) 3, 4 -> [10] = 7`, and `JT (ii) 1, 9 taken` for jumps
+impl fmt::Display for TraceEvent {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:>6}  {}", self.ip, self.opcode.mnemonic())?;
+        let modes = self
+            .modes()
+            .map(|mode| match mode {
+                Some(ParameterMode::Position) => 'p',
+                Some(ParameterMode::Immediate) => 'i',
+                Some(ParameterMode::Relative) => 'r',
+                None => '?',
+            })
+            .collect::<String>();
+        if !modes.is_empty() {
+            write!(f, " ({})", modes)?;
+        }
+        let operands = self
+            .operands
+            .iter()
+            .map(isize::to_string)
+            .collect::<Vec<_>>();
+        if !operands.is_empty() {
+            write!(f, " {}", operands.join(", "))?;
+        }
+        if let Some((address, value)) = self.write {
+            write!(f, " -> [{}] = {}", address, value)?;
+        }
+        if self.jumped {
+            f.write_str(" taken")?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Filter {
+    At(usize),
+    Op(OpCode),
+    WritesTo(usize),
+    Taken(OpCode),
+}
+
+impl Filter {
+    pub fn matches(&self, event: &TraceEvent) -> bool {
+        match *self {
+            Filter::At(ip) => event.ip == ip,
+            Filter::Op(opcode) => event.opcode == opcode,
+            Filter::WritesTo(address) => event.write.map(|(at, _)| at) == Some(address),
+            Filter::Taken(opcode) => event.opcode == opcode && event.jumped,
+        }
+    }
+}
+
+// index of the first step at which the traces disagree, a trace ending early counts as well
+pub fn first_difference(a: &[TraceEvent], b: &[TraceEvent]) -> Option<usize> {
+    a.iter()
+        .zip(b)
+        .position(|(a, b)| a != b)
+        .or_else(|| (a.len() != b.len()).then(|| a.len().min(b.len())))
+}
+
+// Every event is the ip as a varint, the opcode, a flags byte and the modes, the operands as zigzag varints
+// (as many as the opcode reads) and the written address and value when it stores something.
+pub fn write_trace(events: &[TraceEvent], mut out: impl Write) -> io::Result<()> {
+    let mut buffer = Vec::with_capacity(events.len() * 6);
+    buffer.extend_from_slice(MAGIC);
+    for event in events {
+        put_unsigned(&mut buffer, event.ip as u64);
+        let mut flags = 0;
+        if event.write.is_some() {
+            flags |= WRITES;
+        }
+        if event.jumped {
+            flags |= JUMPED;
+        }
+        buffer.push(event.opcode.value() as u8);
+        buffer.push(flags);
+        buffer.push(event.modes);
+        for &operand in &event.operands {
+            put_signed(&mut buffer, operand);
+        }
+        if let Some((address, value)) = event.write {
+            put_unsigned(&mut buffer, address as u64);
+            put_signed(&mut buffer, value);
+        }
+    }
+    out.write_all(&buffer)
+}
+
+pub fn read_trace(mut input: impl Read) -> io::Result<Vec<TraceEvent>> {
+    let mut bytes = Vec::new();
+    input.read_to_end(&mut bytes)?;
+    if !bytes.starts_with(MAGIC) {
+        return Err(invalid("Not an Intcode trace"));
+    }
+
+    let mut bytes = bytes[MAGIC.len()..].iter().copied();
+    let mut events = Vec::new();
+    while let Some(first) = bytes.next() {
+        let ip = get_unsigned(first, &mut bytes)? as usize;
+        let opcode = next(&mut bytes).and_then(|value| {
+            OpCode::from_value(value as isize).ok_or_else(|| invalid("Bad opcode"))
+        })?;
+        let flags = next(&mut bytes)?;
+        let modes = next(&mut bytes)?;
+        let operands = (0..opcode.parameter_count().0)
+            .map(|_| get_signed(&mut bytes))
+            .collect::<io::Result<_>>()?;
+        let write = if flags & WRITES != 0 {
+            let address = next(&mut bytes).and_then(|first| get_unsigned(first, &mut bytes))?;
+            Some((address as usize, get_signed(&mut bytes)?))
+        } else {
+            None
+        };
+        let event = TraceEvent {
+            ip,
+            opcode,
+            modes,
+            operands,
+            write,
+            jumped: flags & JUMPED != 0,
+        };
+        if event.modes().any(|mode| mode.is_none()) {
+            return Err(invalid("Bad parameter mode"));
+        }
+        events.push(event);
+    }
+    Ok(events)
+}
+
+fn invalid(msg: &str) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, msg)
+}
+
+fn next(bytes: &mut impl Iterator<Item = u8>) -> io::Result<u8> {
+    bytes
+        .next()
+        .ok_or_else(|| invalid("Trace ends in the middle of an event"))
+}
+
+// seven bits per byte, lowest first, the high bit set on all but the last
+fn put_unsigned(buffer: &mut Vec<u8>, mut value: u64) {
+    while value >= 0x80 {
+        buffer.push(value as u8 | 0x80);
+        value >>= 7;
+    }
+    buffer.push(value as u8);
+}
+
+fn get_unsigned(first: u8, bytes: &mut impl Iterator<Item = u8>) -> io::Result<u64> {
+    let mut value = u64::from(first & 0x7f);
+    let mut byte = first;
+    let mut shift = 7;
+    while byte & 0x80 != 0 {
+        if shift >= 64 {
+            return Err(invalid("Varint too long"));
+        }
+        byte = next(bytes)?;
+        value |= u64::from(byte & 0x7f) << shift;
+        shift += 7;
+    }
+    Ok(value)
+}
+
+// zigzag keeps small negative numbers short as well
+fn put_signed(buffer: &mut Vec<u8>, value: isize) {
+    let value = value as i64;
+    put_unsigned(buffer, ((value << 1) ^ (value >> 63)) as u64);
+}
+
+fn get_signed(bytes: &mut impl Iterator<Item = u8>) -> io::Result<isize> {
+    let value = next(bytes).and_then(|first| get_unsigned(first, bytes))?;
+    Ok(((value >> 1) as i64 ^ -((value & 1) as i64)) as isize)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::intcode::Intcode;
+
+    fn traced(program: &[isize], inputs: &[isize]) -> Vec<TraceEvent> {
+        let mut intcode = Intcode::new(program, inputs, false);
+        intcode.record_trace();
+        intcode.run_to_halt().unwrap();
+        intcode.take_trace()
+    }
+
+    #[test]
+    fn records_operands_writes_and_jumps() {
+        // doubles the input kept at 20 while it is below 10, then prints it
+        let program = [
+            3, 20, 1, 20, 20, 20, 1007, 20, 10, 21, 1005, 21, 2, 4, 20, 99,
+        ];
+        let events = traced(&program, &[1]);
+        let text = events.iter().map(TraceEvent::to_string).collect::<Vec<_>>();
+        assert_eq!(
+            vec![
+                "     0  IN (p) -> [20] = 1",
+                "     2  ADD (ppp) 1, 1 -> [20] = 2",
+                "     6  LT (pip) 2, 10 -> [21] = 1",
+                "    10  JT (pi) 1, 2 taken",
+                "     2  ADD (ppp) 2, 2 -> [20] = 4",
+            ],
+            text[..5].to_vec()
+        );
+        assert_eq!("    15  HLT", events.last().unwrap().to_string());
+
+        let taken = events
+            .iter()
+            .filter(|e| Filter::Taken(OpCode::JumpIfTrue).matches(e));
+        assert_eq!(3, taken.count());
+        let writes = events.iter().filter(|e| Filter::WritesTo(21).matches(e));
+        assert_eq!(4, writes.count());
+    }
+
+    #[test]
+    fn traces_survive_the_file_format() {
+        let quine = [
+            109, 1, 204, -1, 1001, 100, 1, 100, 1008, 100, 16, 101, 1006, 101, 0, 99,
+        ];
+        let mut events = traced(&quine, &[]);
+        events[0].write = Some((1 << 40, -(1 << 50)));
+
+        let mut file = Vec::new();
+        write_trace(&events, &mut file).unwrap();
+        assert_eq!(
+            Ok(events.clone()),
+            read_trace(&file[..]).map_err(|e| e.to_string())
+        );
+
+        assert!(read_trace(&file[..file.len() - 1]).is_err());
+        assert!(read_trace(&b"ICT1"[..]).is_err());
+        let mut bad_mode = file.clone();
+        bad_mode[7] = 3;
+        assert_eq!(
+            "Bad parameter mode",
+            read_trace(&bad_mode[..]).unwrap_err().to_string()
+        );
+
+        let mut changed = events.clone();
+        changed[7].jumped = !changed[7].jumped;
+        assert_eq!(Some(7), first_difference(&events, &changed));
+        assert_eq!(Some(3), first_difference(&events, &events[..3]));
+        assert_eq!(None, first_difference(&events, &events));
+    }
+}
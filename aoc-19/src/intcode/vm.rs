@@ -0,0 +1,851 @@
+use std::collections::VecDeque;
+use std::fmt;
+use std::num::ParseIntError;
+
+use super::memory::{Memory, MemoryError, MemoryLimits};
+use super::snapshot::Snapshot;
+use super::trace::TraceEvent;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum VmError {
+    UnknownOpcode {
+        address: usize,
+        opcode: isize,
+    },
+    BadMode {
+        address: usize,
+        mode: isize,
+    },
+    NegativeAddress {
+        address: usize,
+        target: isize,
+    },
+    InputExhausted {
+        address: usize,
+    },
+    MemoryLimit {
+        address: usize,
+        target: usize,
+        error: MemoryError,
+    },
+    RelativeOverflow {
+        address: usize,
+        base: isize,
+        offset: isize,
+    },
+    ArithmeticOverflow {
+        address: usize,
+    },
+}
+
+impl fmt::Display for VmError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            VmError::UnknownOpcode { address, opcode } => {
+                write!(f, "Unknown opcode {} at address {}", opcode, address)
+            }
+            VmError::BadMode { address, mode } => {
+                write!(f, "Bad parameter mode {} at address {}", mode, address)
+            }
+            VmError::NegativeAddress { address, target } => write!(
+                f,
+                "Negative address {} used by instruction at address {}",
+                target, address
+            ),
+            VmError::InputExhausted { address } => {
+                write!(f, "Input exhausted at address {}", address)
+            }
+            VmError::MemoryLimit {
+                address,
+                target,
+                error,
+            } => write!(
+                f,
+                "Cannot write address {} from instruction at address {}, {}",
+                target, address, error
+            ),
+            VmError::RelativeOverflow {
+                address,
+                base,
+                offset,
+            } => write!(
+                f,
+                "Relative base {} offset by {} overflows at address {}",
+                base, offset, address
+            ),
+            VmError::ArithmeticOverflow { address } => {
+                write!(f, "Arithmetic overflow at address {}", address)
+            }
+        }
+    }
+}
+
+impl std::error::Error for VmError {}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum OpCode {
+    Add,
+    Multiply,
+    Halt,
+    Input,
+    Output,
+    JumpIfTrue,
+    JumpIfFalse,
+    LessThan,
+    Equals,
+    RelativeBaseOffset,
+}
+
+impl OpCode {
+    pub const ALL: [OpCode; 10] = [
+        OpCode::Add,
+        OpCode::Multiply,
+        OpCode::Input,
+        OpCode::Output,
+        OpCode::JumpIfTrue,
+        OpCode::JumpIfFalse,
+        OpCode::LessThan,
+        OpCode::Equals,
+        OpCode::RelativeBaseOffset,
+        OpCode::Halt,
+    ];
+
+    // returns tuple that represents (parameter count, store count)
+    pub fn parameter_count(&self) -> (usize, bool) {
+        match self {
+            OpCode::Add => (2, true),
+            OpCode::Multiply => (2, true),
+            OpCode::Halt => (0, false),
+            OpCode::Input => (0, true),
+            OpCode::Output => (1, false),
+            OpCode::JumpIfTrue => (2, false),
+            OpCode::JumpIfFalse => (2, false),
+            OpCode::LessThan => (2, true),
+            OpCode::Equals => (2, true),
+            OpCode::RelativeBaseOffset => (1, false),
+        }
+    }
+
+    pub fn mnemonic(&self) -> &'static str {
+        match self {
+            OpCode::Add => "ADD",
+            OpCode::Multiply => "MUL",
+            OpCode::Halt => "HLT",
+            OpCode::Input => "IN",
+            OpCode::Output => "OUT",
+            OpCode::JumpIfTrue => "JT",
+            OpCode::JumpIfFalse => "JF",
+            OpCode::LessThan => "LT",
+            OpCode::Equals => "EQ",
+            OpCode::RelativeBaseOffset => "ARB",
+        }
+    }
+
+    pub fn from_mnemonic(mnemonic: &str) -> Option<Self> {
+        Self::ALL
+            .iter()
+            .copied()
+            .find(|opcode| opcode.mnemonic().eq_ignore_ascii_case(mnemonic))
+    }
+
+    pub fn value(&self) -> isize {
+        match self {
+            OpCode::Add => 1,
+            OpCode::Multiply => 2,
+            OpCode::Input => 3,
+            OpCode::Output => 4,
+            OpCode::JumpIfTrue => 5,
+            OpCode::JumpIfFalse => 6,
+            OpCode::LessThan => 7,
+            OpCode::Equals => 8,
+            OpCode::RelativeBaseOffset => 9,
+            OpCode::Halt => 99,
+        }
+    }
+
+    pub fn from_value(value: isize) -> Option<Self> {
+        match value {
+            1 => Some(Self::Add),
+            2 => Some(Self::Multiply),
+            3 => Some(Self::Input),
+            4 => Some(Self::Output),
+            5 => Some(Self::JumpIfTrue),
+            6 => Some(Self::JumpIfFalse),
+            7 => Some(Self::LessThan),
+            8 => Some(Self::Equals),
+            9 => Some(Self::RelativeBaseOffset),
+            99 => Some(Self::Halt),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ParameterMode {
+    Position,
+    Immediate,
+    Relative,
+}
+
+impl ParameterMode {
+    pub fn from_value(value: isize) -> Option<Self> {
+        match value {
+            2 => Some(Self::Relative),
+            1 => Some(Self::Immediate),
+            0 => Some(Self::Position),
+            _ => None,
+        }
+    }
+
+    pub fn value(&self) -> isize {
+        match self {
+            ParameterMode::Position => 0,
+            ParameterMode::Immediate => 1,
+            ParameterMode::Relative => 2,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Parameter {
+    pub mode: ParameterMode,
+    // address of the parameter itself, not of the value it points to
+    pub index: usize,
+}
+
+// no instruction reads more than two parameters
+const MAX_PARAMETERS: usize = 2;
+
+// what an opcode word says, which is all of decoding that does not depend on the address, so
+// it is kept per address and reused for as long as the word there stays the same
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Decoded {
+    word: isize,
+    opcode: OpCode,
+    // of the parameters read, then of the store target
+    modes: [ParameterMode; MAX_PARAMETERS + 1],
+    size: usize,
+}
+
+impl Decoded {
+    fn new(address: usize, word: isize) -> Result<Self, VmError> {
+        let opcode = OpCode::from_value(word % 100).ok_or(VmError::UnknownOpcode {
+            address,
+            opcode: word,
+        })?;
+        let (param_count, has_store) = opcode.parameter_count();
+        let count = param_count + has_store as usize;
+
+        let mut modes = [ParameterMode::Position; MAX_PARAMETERS + 1];
+        let mut digits = word / 100;
+        for mode in modes[..count].iter_mut() {
+            *mode = ParameterMode::from_value(digits % 10).ok_or(VmError::BadMode {
+                address,
+                mode: digits % 10,
+            })?;
+            digits /= 10;
+        }
+        if has_store && modes[count - 1] == ParameterMode::Immediate {
+            return Err(VmError::BadMode { address, mode: 1 });
+        }
+
+        Ok(Decoded {
+            word,
+            opcode,
+            modes,
+            size: count + 1,
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Instruction {
+    pub address: usize,
+    pub opcode: OpCode,
+    // fixed size so decoding never allocates, only the first `parameter_count` are used
+    parameters: [Parameter; MAX_PARAMETERS],
+    parameter_count: usize,
+    pub store: Option<Parameter>,
+}
+
+impl Instruction {
+    // decodes the instruction at `address`, reading memory through `at`
+    pub fn decode(address: usize, at: impl Fn(usize) -> isize) -> Result<Self, VmError> {
+        Decoded::new(address, at(address)).map(|decoded| Self::at(address, &decoded))
+    }
+
+    fn at(address: usize, decoded: &Decoded) -> Self {
+        let (parameter_count, has_store) = decoded.opcode.parameter_count();
+        let parameter = |i: usize| Parameter {
+            mode: decoded.modes[i],
+            index: address + i + 1,
+        };
+
+        let mut parameters = [parameter(MAX_PARAMETERS); MAX_PARAMETERS];
+        for (i, slot) in parameters[..parameter_count].iter_mut().enumerate() {
+            *slot = parameter(i);
+        }
+        Self {
+            address,
+            opcode: decoded.opcode,
+            parameters,
+            parameter_count,
+            store: if has_store {
+                Some(parameter(parameter_count))
+            } else {
+                None
+            },
+        }
+    }
+
+    // the parameters read, the store target is kept apart
+    pub fn parameters(&self) -> &[Parameter] {
+        &self.parameters[..self.parameter_count]
+    }
+
+    // the opcode word without mode digits beyond the instruction's own operands
+    pub fn word(&self) -> isize {
+        let modes = self
+            .parameters()
+            .iter()
+            .chain(self.store.iter())
+            .rev()
+            .fold(0, |modes, parameter| modes * 10 + parameter.mode.value());
+        modes * 100 + self.opcode.value()
+    }
+
+    pub fn size(&self) -> usize {
+        let store_len = if self.store.is_some() { 1 } else { 0 };
+        // opcode + parameter num + store location if it exists
+        self.parameter_count + store_len + 1
+    }
+}
+
+pub enum ProgramResult {
+    InputPause,
+    OutputPause(isize),
+    Halt,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Step {
+    Executed,
+    Output(isize),
+    NeedsInput,
+    Halted,
+}
+
+pub fn parse_program(text: &str) -> Result<Vec<isize>, ParseIntError> {
+    text.trim().split(',').map(|x| x.trim().parse()).collect()
+}
+
+// the comma-separated text `parse_program` reads back
+pub fn format_program(program: &[isize]) -> String {
+    let words = program.iter().map(isize::to_string).collect::<Vec<_>>();
+    words.join(",")
+}
+
+#[derive(Clone)]
+pub struct Intcode {
+    memory: Memory,
+    // filled in as addresses in the dense region are executed, see `Decoded`
+    decoded: Vec<Option<Decoded>>,
+    instruction_pointer: usize,
+    inputs: VecDeque<isize>,
+    pub outputs: Vec<isize>,
+    diagnostic_code: Option<isize>,
+    pause_on_output: bool,
+    halted: bool,
+    relative_base: isize,
+    trace: Option<Vec<TraceEvent>>,
+}
+
+impl Intcode {
+    pub fn new(instructions: &[isize], inputs: &[isize], pause_on_output: bool) -> Self {
+        Intcode {
+            memory: Memory::new(instructions, MemoryLimits::default()),
+            decoded: vec![None; instructions.len()],
+            instruction_pointer: 0,
+            inputs: inputs.iter().copied().collect(),
+            outputs: vec![],
+            diagnostic_code: None,
+            pause_on_output,
+            halted: false,
+            relative_base: 0,
+            trace: None,
+        }
+    }
+
+    pub fn parsed(text: &str, inputs: &[isize], pause_on_output: bool) -> Self {
+        let program = parse_program(text).expect("Not valid intcode");
+        Self::new(&program, inputs, pause_on_output)
+    }
+
+    pub fn current_instruction(&self) -> Result<Instruction, VmError> {
+        Instruction::decode(self.instruction_pointer, |index| self.at(index))
+    }
+
+    fn address(&self, target: isize) -> Result<usize, VmError> {
+        if target < 0 {
+            Err(VmError::NegativeAddress {
+                address: self.instruction_pointer,
+                target,
+            })
+        } else {
+            Ok(target as usize)
+        }
+    }
+
+    fn relative(&self, offset: isize) -> Result<isize, VmError> {
+        self.relative_base
+            .checked_add(offset)
+            .ok_or(VmError::RelativeOverflow {
+                address: self.instruction_pointer,
+                base: self.relative_base,
+                offset,
+            })
+    }
+
+    fn parameter_value(&self, param: &Parameter) -> Result<isize, VmError> {
+        self.operand(param.mode, param.index)
+    }
+
+    fn operand(&self, mode: ParameterMode, index: usize) -> Result<isize, VmError> {
+        let value = match mode {
+            ParameterMode::Immediate => self.at(index),
+            ParameterMode::Position => self.at(self.address(self.at(index))?),
+            ParameterMode::Relative => self.at(self.address(self.relative(self.at(index))?)?),
+        };
+        Ok(value)
+    }
+
+    fn target(&self, mode: ParameterMode, index: usize) -> Result<usize, VmError> {
+        match mode {
+            ParameterMode::Position => self.address(self.at(index)),
+            ParameterMode::Relative => self.address(self.relative(self.at(index))?),
+            ParameterMode::Immediate => unreachable!("Decoding rejects immediate stores"),
+        }
+    }
+
+    pub fn with_memory_limits(mut self, limits: MemoryLimits) -> Self {
+        self.memory.set_limits(limits);
+        self
+    }
+
+    fn at(&self, index: usize) -> isize {
+        self.memory.read(index)
+    }
+
+    pub fn read(&self, address: usize) -> isize {
+        self.at(address)
+    }
+
+    // fails like a store done by the program would, past the memory limits
+    pub fn write(&mut self, address: usize, value: isize) -> Result<(), VmError> {
+        self.memory
+            .write(address, value)
+            .map_err(|error| VmError::MemoryLimit {
+                address: self.instruction_pointer,
+                target: address,
+                error,
+            })
+    }
+
+    // only addresses in the dense region are cached, the sparse ones are rarely code
+    fn decoded(&mut self, address: usize) -> Result<Decoded, VmError> {
+        let word = self.at(address);
+        if address >= self.decoded.len() && address < self.memory.dense().len() {
+            self.decoded.resize(self.memory.dense().len(), None);
+        }
+        match self.decoded.get(address) {
+            Some(Some(decoded)) if decoded.word == word => return Ok(*decoded),
+            Some(_) => {}
+            None => return Decoded::new(address, word),
+        }
+        let decoded = Decoded::new(address, word)?;
+        self.decoded[address] = Some(decoded);
+        Ok(decoded)
+    }
+
+    // runs the instruction at the pointer, which only moves on once it is done
+    fn execute(&mut self) -> Result<Step, VmError> {
+        let ip = self.instruction_pointer;
+        let decoded = self.decoded(ip)?;
+        let [first, second, third] = decoded.modes;
+        let mut next = ip + decoded.size;
+
+        let step = match decoded.opcode {
+            OpCode::Halt => {
+                self.halted = true;
+                return Ok(Step::Halted);
+            }
+            OpCode::Output => {
+                let value = self.operand(first, ip + 1)?;
+                self.outputs.push(value);
+                self.diagnostic_code = Some(value);
+                Step::Output(value)
+            }
+            OpCode::Input => {
+                self.diagnostic_code = None;
+                // the value is only taken once it is stored, a faulting store leaves it queued
+                let target = self.target(first, ip + 1)?;
+                let value = match self.inputs.front() {
+                    Some(&value) => value,
+                    None => return Ok(Step::NeedsInput),
+                };
+                self.write(target, value)?;
+                self.inputs.pop_front();
+                Step::Executed
+            }
+            OpCode::Add | OpCode::Multiply | OpCode::LessThan | OpCode::Equals => {
+                let (a, b) = (self.operand(first, ip + 1)?, self.operand(second, ip + 2)?);
+                let value = match decoded.opcode {
+                    OpCode::Add => a.checked_add(b),
+                    OpCode::Multiply => a.checked_mul(b),
+                    OpCode::LessThan => Some((a < b) as isize),
+                    _ => Some((a == b) as isize),
+                }
+                .ok_or(VmError::ArithmeticOverflow { address: ip })?;
+                self.diagnostic_code = None;
+                let target = self.target(third, ip + 3)?;
+                self.write(target, value)?;
+                Step::Executed
+            }
+            OpCode::JumpIfTrue | OpCode::JumpIfFalse => {
+                let condition = self.operand(first, ip + 1)?;
+                let target = self.operand(second, ip + 2)?;
+                self.diagnostic_code = None;
+                if (condition != 0) == (decoded.opcode == OpCode::JumpIfTrue) {
+                    next = self.address(target)?;
+                }
+                Step::Executed
+            }
+            OpCode::RelativeBaseOffset => {
+                self.relative_base = self.relative(self.operand(first, ip + 1)?)?;
+                self.diagnostic_code = None;
+                Step::Executed
+            }
+        };
+
+        self.instruction_pointer = next;
+        Ok(step)
+    }
+
+    // executes a single instruction, one waiting for input is left to run again
+    pub fn step(&mut self) -> Result<Step, VmError> {
+        if self.halted {
+            return Ok(Step::Halted);
+        }
+        if self.trace.is_some() {
+            return self.traced_step();
+        }
+        self.execute()
+    }
+
+    fn traced_step(&mut self) -> Result<Step, VmError> {
+        let instruction = self.current_instruction()?;
+        // read before executing, as the instruction may overwrite its own operands
+        let (operands, target) = self.operands(&instruction);
+        let step = self.execute()?;
+        if step == Step::NeedsInput {
+            return Ok(step);
+        }
+
+        let jumped = match instruction.opcode {
+            OpCode::JumpIfTrue => operands[0] != 0,
+            OpCode::JumpIfFalse => operands[0] == 0,
+            _ => false,
+        };
+        let event = TraceEvent {
+            ip: instruction.address,
+            opcode: instruction.opcode,
+            modes: (instruction.word() / 100) as u8,
+            operands,
+            write: target.map(|address| (address, self.read(address))),
+            jumped,
+        };
+        self.trace.as_mut().expect("Tracing is on").push(event);
+        Ok(step)
+    }
+
+    // the values an instruction reads and the address it stores to, only used once it ran fine
+    fn operands(&self, instruction: &Instruction) -> (Vec<isize>, Option<usize>) {
+        let operands = instruction
+            .parameters()
+            .iter()
+            .map(|param| self.parameter_value(param).unwrap_or_default())
+            .collect();
+        let target = instruction
+            .store
+            .as_ref()
+            .map(|param| self.target(param.mode, param.index).unwrap_or_default());
+        (operands, target)
+    }
+
+    // every instruction executed from now on is kept until taken with `take_trace`
+    pub fn record_trace(&mut self) {
+        self.trace.get_or_insert_with(Vec::new);
+    }
+
+    pub fn take_trace(&mut self) -> Vec<TraceEvent> {
+        self.trace.take().unwrap_or_default()
+    }
+
+    // runs until the program halts, outputs while pausing on output, or waits for input
+    pub fn run_program(&mut self) -> Result<ProgramResult, VmError> {
+        loop {
+            match self.step()? {
+                Step::Halted => break Ok(ProgramResult::Halt),
+                Step::NeedsInput => break Ok(ProgramResult::InputPause),
+                Step::Output(output) if self.pause_on_output => {
+                    break Ok(ProgramResult::OutputPause(output))
+                }
+                Step::Output(_) | Step::Executed => {}
+            }
+        }
+    }
+
+    // for programs that get all of their input up front, running dry is a fault
+    pub fn run_to_halt(&mut self) -> Result<(), VmError> {
+        loop {
+            match self.run_program()? {
+                ProgramResult::Halt => break Ok(()),
+                ProgramResult::OutputPause(_) => continue,
+                ProgramResult::InputPause => {
+                    break Err(VmError::InputExhausted {
+                        address: self.instruction_pointer,
+                    })
+                }
+            }
+        }
+    }
+
+    pub fn add_inputs(&mut self, inputs: &[isize]) {
+        self.inputs.extend(inputs);
+    }
+
+    pub fn set_inputs(&mut self, inputs: &[isize]) {
+        self.inputs = inputs.iter().copied().collect();
+    }
+
+    pub fn is_halted(&self) -> bool {
+        self.halted
+    }
+
+    pub fn instruction_pointer(&self) -> usize {
+        self.instruction_pointer
+    }
+
+    pub fn relative_base(&self) -> isize {
+        self.relative_base
+    }
+
+    pub fn pending_inputs(&self) -> &VecDeque<isize> {
+        &self.inputs
+    }
+
+    pub fn memory(&self) -> &Memory {
+        &self.memory
+    }
+
+    pub fn snapshot(&self) -> Snapshot {
+        Snapshot {
+            memory: self.memory.clone(),
+            instruction_pointer: self.instruction_pointer,
+            relative_base: self.relative_base,
+            inputs: self.inputs.clone(),
+            outputs: self.outputs.clone(),
+            diagnostic_code: self.diagnostic_code,
+            pause_on_output: self.pause_on_output,
+            halted: self.halted,
+        }
+    }
+
+    // decoded opcodes are kept, they are checked against memory before being reused anyway
+    pub fn restore(&mut self, snapshot: &Snapshot) {
+        self.memory.clone_from(&snapshot.memory);
+        self.instruction_pointer = snapshot.instruction_pointer;
+        self.relative_base = snapshot.relative_base;
+        self.inputs.clone_from(&snapshot.inputs);
+        self.outputs.clone_from(&snapshot.outputs);
+        self.diagnostic_code = snapshot.diagnostic_code;
+        self.pause_on_output = snapshot.pause_on_output;
+        self.halted = snapshot.halted;
+    }
+
+    // the last output, as long as nothing but a halt came after it
+    pub fn diagnostic_code(&self) -> Option<isize> {
+        self.diagnostic_code
+    }
+
+    pub fn last_output(&self) -> Option<isize> {
+        self.outputs.last().copied()
+    }
+}
+
+impl From<Snapshot> for Intcode {
+    fn from(snapshot: Snapshot) -> Self {
+        let mut intcode = Intcode::new(&[], &[], false);
+        intcode.restore(&snapshot);
+        intcode
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn run(program: &[isize], inputs: &[isize]) -> Result<Intcode, VmError> {
+        let mut intcode = Intcode::new(program, inputs, false);
+        intcode.run_to_halt().map(|_| intcode)
+    }
+
+    #[test]
+    fn compares_input_with_eight() {
+        let program = parse_program("3,9,8,9,10,9,4,9,99,-1,8\n").unwrap();
+        assert_eq!(Some(1), run(&program, &[8]).unwrap().last_output());
+        assert_eq!(Some(0), run(&program, &[7]).unwrap().last_output());
+    }
+
+    #[test]
+    fn relative_mode_reaches_past_the_program() {
+        let quine = [
+            109, 1, 204, -1, 1001, 100, 1, 100, 1008, 100, 16, 101, 1006, 101, 0, 99,
+        ];
+        assert_eq!(quine.to_vec(), run(&quine, &[]).unwrap().outputs);
+    }
+
+    #[test]
+    fn faults_are_reported_instead_of_panicking() {
+        assert_eq!(
+            Err(VmError::UnknownOpcode {
+                address: 4,
+                opcode: 42
+            }),
+            run(&[1, 0, 0, 0, 42], &[]).map(drop)
+        );
+        assert_eq!(
+            Err(VmError::BadMode {
+                address: 0,
+                mode: 3
+            }),
+            run(&[301, 0, 0, 0, 99], &[]).map(drop)
+        );
+        assert_eq!(
+            Err(VmError::BadMode {
+                address: 0,
+                mode: 1
+            }),
+            run(&[10001, 0, 0, 0, 99], &[]).map(drop)
+        );
+        assert_eq!(
+            Err(VmError::NegativeAddress {
+                address: 0,
+                target: -1
+            }),
+            run(&[1, -1, 0, 0, 99], &[]).map(drop)
+        );
+        assert_eq!(
+            Err(VmError::InputExhausted { address: 2 }),
+            run(&[3, 0, 3, 0, 99], &[5]).map(drop)
+        );
+    }
+
+    #[test]
+    fn faulting_input_keeps_its_value_queued() {
+        let mut intcode = Intcode::new(&[3, -1, 99], &[7], false);
+        assert_eq!(
+            Err(VmError::NegativeAddress {
+                address: 0,
+                target: -1
+            }),
+            intcode.step()
+        );
+        assert_eq!(&VecDeque::from([7]), intcode.pending_inputs());
+
+        let limits = MemoryLimits {
+            max_address: 10,
+            ..MemoryLimits::default()
+        };
+        let mut intcode = Intcode::new(&[3, 100, 99], &[7], false).with_memory_limits(limits);
+        assert!(matches!(
+            intcode.step(),
+            Err(VmError::MemoryLimit { target: 100, .. })
+        ));
+        assert_eq!(&VecDeque::from([7]), intcode.pending_inputs());
+    }
+
+    #[test]
+    fn relative_base_overflow_is_a_fault() {
+        let program = [109, isize::MAX, 109, 1, 99];
+        assert_eq!(
+            Err(VmError::RelativeOverflow {
+                address: 2,
+                base: isize::MAX,
+                offset: 1
+            }),
+            run(&program, &[]).map(drop)
+        );
+
+        let program = [109, isize::MAX, 204, 1, 99];
+        assert_eq!(
+            Err(VmError::RelativeOverflow {
+                address: 2,
+                base: isize::MAX,
+                offset: 1
+            }),
+            run(&program, &[]).map(drop)
+        );
+    }
+
+    #[test]
+    fn arithmetic_overflow_is_a_fault() {
+        assert_eq!(
+            Err(VmError::ArithmeticOverflow { address: 4 }),
+            run(&[1101, 1, 2, 0, 1101, isize::MAX, 1, 0, 99], &[]).map(drop)
+        );
+        assert_eq!(
+            Err(VmError::ArithmeticOverflow { address: 0 }),
+            run(&[1102, isize::MIN, -1, 0, 99], &[]).map(drop)
+        );
+    }
+
+    #[test]
+    fn rewritten_opcodes_are_decoded_again() {
+        let mut intcode = Intcode::new(&[99, 40, 2, 5, 99, 0], &[], false);
+        let start = intcode.snapshot();
+        intcode.run_to_halt().unwrap();
+        assert_eq!(0, intcode.instruction_pointer());
+
+        // the halt at 0 is cached now, turning it into an add has to be noticed
+        intcode.restore(&start);
+        intcode.write(0, 1101).unwrap();
+        intcode.run_to_halt().unwrap();
+        assert_eq!(42, intcode.read(5));
+        assert_eq!(4, intcode.instruction_pointer());
+    }
+
+    #[test]
+    fn far_addresses_do_not_allocate_everything_below_them() {
+        // stores the input a trillion cells out and prints it back
+        let program = [3, 1_000_000_000_000, 4, 1_000_000_000_000, 99];
+        let mut intcode = Intcode::new(&program, &[42], false);
+        intcode.run_to_halt().unwrap();
+        assert_eq!(vec![42], intcode.outputs);
+        assert_eq!(program.len(), intcode.memory().dense().len());
+
+        let limits = MemoryLimits {
+            max_address: 1000,
+            ..MemoryLimits::default()
+        };
+        let mut intcode = Intcode::new(&program, &[42], false).with_memory_limits(limits);
+        assert_eq!(
+            Err(VmError::MemoryLimit {
+                address: 0,
+                target: 1_000_000_000_000,
+                error: MemoryError::PastMaxAddress { max_address: 1000 }
+            }),
+            intcode.run_to_halt()
+        );
+    }
+}
@@ -0,0 +1,7 @@
+#![feature(drain_filter)]
+#![feature(assoc_int_consts)]
+
+pub mod days;
+mod input_reader;
+pub mod intcode;
+pub mod registry;
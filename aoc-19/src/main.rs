@@ -1,9 +1,4 @@
-#![feature(drain_filter)]
-#![feature(assoc_int_consts)]
-
-mod days;
-mod input_reader;
-use days::{runner_factory, Days, Parts};
+use aoc_19::days::{runner_factory, Days, Parts};
 use std::env;
 
 fn main() {
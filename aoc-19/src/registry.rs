@@ -0,0 +1,60 @@
+use aoc_core::{FnSolution, Registry};
+use std::path::Path;
+
+use crate::days::*;
+
+pub const YEAR: u16 = 2019;
+
+macro_rules! register_runner {
+    ($registry:expr, $day:expr, $runner:ty) => {
+        $registry.register(
+            YEAR,
+            $day,
+            FnSolution::from_path(location)
+                .part1(|path| <$runner>::with_input_path(path, Parts::Part1).part1())
+                .part2(|path| <$runner>::with_input_path(path, Parts::Part2).part2()),
+        )
+    };
+    // for runners with only a first part worth running
+    ($registry:expr, $day:expr, $runner:ty, part1) => {
+        $registry.register(
+            YEAR,
+            $day,
+            FnSolution::from_path(location)
+                .part1(|path| <$runner>::with_input_path(path, Parts::Part1).part1()),
+        )
+    };
+    // for runners whose parts return a `Result`
+    ($registry:expr, $day:expr, $runner:ty, try) => {
+        $registry.register(
+            YEAR,
+            $day,
+            FnSolution::from_path(location)
+                .try_part1(|path| <$runner>::with_input_path(path, Parts::Part1).part1())
+                .try_part2(|path| <$runner>::with_input_path(path, Parts::Part2).part2()),
+        )
+    };
+}
+
+pub fn register(registry: &mut Registry) {
+    register_runner!(registry, 1, day1::Day1Runner);
+    register_runner!(registry, 2, day2::Day2Runner, try);
+    register_runner!(registry, 3, day3::Day3Runner);
+    // part 2 was never written
+    register_runner!(registry, 4, day4::Day4Runner, part1);
+    register_runner!(registry, 5, day5::Day5Runner, try);
+    register_runner!(registry, 6, day6::Day6Runner);
+    register_runner!(registry, 7, day7::Day7Runner, try);
+    register_runner!(registry, 8, day8::Day8Runner);
+    register_runner!(registry, 9, day9::Day9Runner, try);
+    register_runner!(registry, 10, day10::Day10Runner);
+    register_runner!(registry, 11, day11::Day11Runner, try);
+    // part 2 waits for every moon to come back on its own, which does not end on the real input
+    register_runner!(registry, 12, day12::Day12Runner, part1);
+}
+
+fn location(path: &Path) -> Result<String, String> {
+    path.to_str()
+        .map(str::to_owned)
+        .ok_or_else(|| format!("Input path '{}' is not valid UTF-8", path.display()))
+}
@@ -1,24 +1,32 @@
 use std::{fmt::Debug, fs, str::FromStr};
 
-pub fn parse_input<TResult: FromStr>(filename: &str, delimiter: &str) -> Vec<TResult>
+pub fn parse_input<TResult: FromStr>(
+  filename: &str,
+  delimiter: &str,
+) -> Result<Vec<TResult>, String>
 where
   <TResult as FromStr>::Err: Debug,
 {
-  fs::read_to_string(filename)
-    .unwrap()
+  read_file(filename)?
     .split(delimiter)
     .map(|elem| {
       elem
         .parse::<TResult>()
-        .expect(format!("Could not parse elem: '{}'", elem).as_ref())
+        .map_err(|e| format!("Could not parse elem: '{}' ({:?})", elem, e))
     })
-    .collect::<Vec<TResult>>()
+    .collect::<Result<Vec<TResult>, String>>()
+}
+
+pub fn read_unparsed(filename: &str, delimiter: &str) -> Result<Vec<String>, String> {
+  Ok(
+    read_file(filename)?
+      .split(delimiter)
+      .map(|s| s.to_string())
+      .collect::<Vec<_>>(),
+  )
 }
 
-pub fn read_unparsed(filename: &str, delimiter: &str) -> Vec<String> {
+fn read_file(filename: &str) -> Result<String, String> {
   fs::read_to_string(filename)
-    .unwrap()
-    .split(delimiter)
-    .map(|s| s.to_string())
-    .collect::<Vec<_>>()
+    .map_err(|e| format!("Could not read input file '{}': {}", filename, e))
 }
@@ -0,0 +1,7 @@
+#![feature(iterator_fold_self)]
+#![feature(str_split_once)]
+
+mod common;
+pub mod registry;
+pub mod runner;
+pub mod tasks;
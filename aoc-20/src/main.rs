@@ -1,13 +1,168 @@
-#![feature(iterator_fold_self)]
-#![feature(str_split_once)]
+use std::panic;
 
-mod common;
-mod runner;
-mod tasks;
+use aoc_20::registry::{self, YEAR};
+use aoc_20::runner::{
+  argument_parser::CliOption,
+  cli_runner::{CliBuilder, CliRunner},
+};
+use aoc_20::tasks::{self, Day, Part};
+use aoc_core::{
+  verify, ExpectedAnswers, Part as CorePart, PuzzleId, PuzzleInput, Registry, Verdict,
+};
+
+const DEFAULT_INPUT_ROOT: &str = "./input";
 
-use tasks::{Day, Part};
 fn main() {
-  let input_root = "E:/Programming Projects/advent-of-code/aoc-20/input";
-  tasks::dispatch(input_root, &Day::Day19, &Part::First);
-  tasks::dispatch(input_root, &Day::Day19, &Part::Second);
+  let runner = cli_builder()
+    .with_argument_list(std::env::args().skip(1))
+    .make_runner();
+
+  let runner = match runner {
+    Ok(runner) => runner,
+    Err(e) => {
+      eprintln!("{}\n\n{}", e, usage(&cli_builder().help()));
+      std::process::exit(2);
+    }
+  };
+
+  if runner.help_requested() {
+    println!("{}", usage(&runner.help()));
+    return;
+  }
+
+  let days = match runner.value_of("day") {
+    Some("all") => Day::all().to_vec(),
+    day => match day.unwrap_or_default().parse::<Day>() {
+      Ok(day) => vec![day],
+      Err(e) => {
+        eprintln!("{}", e);
+        std::process::exit(2);
+      }
+    },
+  };
+
+  let parts = match runner.value_of("part") {
+    None | Some("both") => vec![Part::First, Part::Second],
+    Some(part) => match part.parse::<Part>() {
+      Ok(part) => vec![part],
+      Err(e) => {
+        eprintln!("{}", e);
+        std::process::exit(2);
+      }
+    },
+  };
+
+  let input_root = runner.value_of("input").unwrap_or(DEFAULT_INPUT_ROOT);
+  let all_ok = match runner.value_of("verify") {
+    Some(answer_file) => verify_days(input_root, answer_file, &days, &parts),
+    None => solve_days(input_root, &days, &parts),
+  };
+
+  if !all_ok {
+    std::process::exit(1);
+  }
+}
+
+fn solve_days(input_root: &str, days: &[Day], parts: &[Part]) -> bool {
+  let mut all_ok = true;
+  for day in days.iter() {
+    for part in parts.iter() {
+      match tasks::dispatch(input_root, day, part) {
+        Ok(solution) => println!("{}", solution.rendered),
+        Err(e) => {
+          all_ok = false;
+          eprintln!("(Day {}, Part {}) {}", day.number(), part.number(), e);
+        }
+      }
+    }
+  }
+  all_ok
+}
+
+fn verify_days(input_root: &str, answer_file: &str, days: &[Day], parts: &[Part]) -> bool {
+  let expected = match ExpectedAnswers::from_file(answer_file) {
+    Ok(expected) => expected,
+    Err(e) => {
+      eprintln!("{}", e);
+      return false;
+    }
+  };
+  let mut registry = Registry::new();
+  registry::register(&mut registry);
+
+  // panics are reported as verdicts, the default hook would only scramble them
+  let previous_hook = panic::take_hook();
+  panic::set_hook(Box::new(|_| {}));
+
+  let mut failures = 0;
+  for day in days.iter() {
+    let id = PuzzleId::new(YEAR, day.number());
+    let solution = registry.get(id).expect("Every day is registered");
+    let verdicts = match PuzzleInput::from_file(day.input_file(input_root)) {
+      Ok(input) => verify(id, solution, &input, &expected),
+      Err(e) => CorePart::ALL
+        .iter()
+        .map(|&part| (part, Verdict::MissingInput(e.to_string())))
+        .collect(),
+    };
+
+    for (part, verdict) in verdicts {
+      if !parts.iter().any(|p| p.number() == part.number()) {
+        continue;
+      }
+      if verdict.is_regression() {
+        failures += 1;
+      }
+      println!(
+        "Day {:02}, Part {} | {}",
+        day.number(),
+        part.number(),
+        verdict
+      );
+    }
+  }
+
+  panic::set_hook(previous_hook);
+
+  println!("{} failed", failures);
+  failures == 0
+}
+
+fn cli_builder() -> CliBuilder {
+  CliRunner::from_builder()
+    .register_option(CliOption::new(
+      "d",
+      "day",
+      true,
+      false,
+      "Day to solve (1-19) or all",
+    ))
+    .register_option(CliOption::new(
+      "p",
+      "part",
+      false,
+      false,
+      "Part to solve: 1, 2 or both (default: both)",
+    ))
+    .register_option(CliOption::new(
+      "i",
+      "input",
+      false,
+      false,
+      "Directory containing day_XX.input.txt files (default: ./input)",
+    ))
+    .register_option(CliOption::new(
+      "v",
+      "verify",
+      false,
+      false,
+      "Compare answers against the [2020.<day>] tables of the given answers.toml",
+    ))
+}
+
+fn usage(options_help: &str) -> String {
+  format!(
+    "Usage: aoc-20 --day N|all [--part 1|2|both] [--input DIR] [--verify FILE]\n\n{}",
+    options_help
+  )
 }
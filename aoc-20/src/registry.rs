@@ -0,0 +1,52 @@
+use aoc_core::{Answer, Part as CorePart, PuzzleInput, Registry, Solution, SolutionError};
+
+use crate::tasks::{self, Day, Part, TaskError};
+
+pub const YEAR: u16 = 2020;
+
+pub fn register(registry: &mut Registry) {
+  for day in Day::all().iter() {
+    registry.register(YEAR, day.number(), DaySolution(*day));
+  }
+}
+
+// days read their input file themselves, so the path is all there is to parse
+struct DaySolution(Day);
+
+impl DaySolution {
+  fn solve(&self, input_path: &str, part: Part) -> Result<Answer, SolutionError> {
+    match tasks::solve(input_path, &self.0, &part) {
+      Ok(solution) => Ok(Answer::new(solution.answer)),
+      Err(TaskError::NotImplemented) => Err(SolutionError::NotImplemented),
+      Err(TaskError::Input(msg)) => Err(SolutionError::Parse(msg)),
+    }
+  }
+}
+
+impl Solution for DaySolution {
+  type Parsed = String;
+
+  fn parse(&self, input: &PuzzleInput) -> Result<String, SolutionError> {
+    let path = input.path().ok_or(SolutionError::MissingInputPath)?;
+    path
+      .to_str()
+      .map(str::to_owned)
+      .ok_or_else(|| SolutionError::parse(format!("'{}' is not valid UTF-8", path.display())))
+  }
+
+  fn part1(&self, input_path: &String) -> Result<Answer, SolutionError> {
+    self.solve(input_path, Part::First)
+  }
+
+  fn part2(&self, input_path: &String) -> Result<Answer, SolutionError> {
+    self.solve(input_path, Part::Second)
+  }
+
+  fn implements(&self, part: CorePart) -> bool {
+    !(self.0 == Day::Day19 && part == CorePart::Two)
+  }
+
+  fn parses_input(&self) -> bool {
+    false
+  }
+}
@@ -1,3 +1,4 @@
+#[derive(Clone)]
 pub struct CliOption {
   short_name: String,
   long_name: String,
@@ -23,6 +24,22 @@ impl CliOption {
     }
   }
 
+  pub fn short_name(&self) -> &str {
+    &self.short_name
+  }
+
+  pub fn long_name(&self) -> &str {
+    &self.long_name
+  }
+
+  pub fn is_mandatory(&self) -> bool {
+    self.mandatory
+  }
+
+  pub fn is_flag(&self) -> bool {
+    self.is_flag
+  }
+
   pub fn help(&self, short_switch: &str, long_switch: &str) -> String {
     format!(
       "{s_switch}{s_name}, {l_switch}{l_name} | {desc}",
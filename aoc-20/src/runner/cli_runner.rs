@@ -2,10 +2,13 @@ use super::argument_parser::{CliArgument, CliOption};
 
 const SHORT_SWITCH: &str = "-";
 const LONG_SWITCH: &str = "--";
+const HELP_SHORT_NAME: &str = "h";
+const HELP_LONG_NAME: &str = "help";
 
 pub struct CliRunner {
+  options: Vec<CliOption>,
   args: Vec<CliArgument>,
-  original_argument_line: String,
+  help_requested: bool,
 }
 
 impl CliRunner {
@@ -14,34 +17,185 @@ impl CliRunner {
   }
 
   pub fn help(&self) -> String {
-    let mut string_builder = String::new();
+    options_help(&self.options)
+  }
+
+  pub fn help_requested(&self) -> bool {
+    self.help_requested
+  }
+
+  pub fn is_set(&self, long_name: &str) -> bool {
+    self.find_argument(long_name).is_some()
+  }
+
+  pub fn value_of(&self, long_name: &str) -> Option<&str> {
+    self
+      .find_argument(long_name)
+      .and_then(|arg| arg.value.as_deref())
+  }
+
+  fn find_argument(&self, long_name: &str) -> Option<&CliArgument> {
+    self
+      .args
+      .iter()
+      .find(|arg| arg.option.long_name() == long_name)
+  }
+
+  fn new(tokens: Vec<String>, options: Vec<CliOption>) -> Result<CliRunner, String> {
+    let mut args: Vec<CliArgument> = Vec::new();
+    let mut help_requested = false;
+    let mut tokens = tokens.into_iter();
+
+    while let Some(token) = tokens.next() {
+      let (switch, inline_value) = split_switch(&token)?;
+
+      if switch.names(HELP_SHORT_NAME, HELP_LONG_NAME) {
+        help_requested = true;
+        continue;
+      }
 
-    for arg in self.args.iter() {
-      string_builder.push_str(arg.option.help(SHORT_SWITCH, LONG_SWITCH).as_ref());
-      string_builder.push('\n');
+      let option = options
+        .iter()
+        .find(|opt| switch.names(opt.short_name(), opt.long_name()))
+        .ok_or_else(|| format!("Unknown option '{}'", token))?;
+
+      if args
+        .iter()
+        .any(|arg| arg.option.long_name() == option.long_name())
+      {
+        return Err(format!("Option '{}' given more than once", token));
+      }
+
+      let value = if option.is_flag() {
+        if inline_value.is_some() {
+          return Err(format!("Flag '{}' does not take a value", token));
+        }
+        String::new()
+      } else {
+        match inline_value.or_else(|| tokens.next()) {
+          Some(value) if !value.is_empty() => value,
+          _ => return Err(format!("Missing value for option '{}'", token)),
+        }
+      };
+
+      args.push(CliArgument::new(option.clone(), &value));
     }
 
-    string_builder
+    // when help is requested nothing else will run so missing options are not an error
+    if !help_requested {
+      if let Some(missing) = options.iter().find(|opt| {
+        opt.is_mandatory()
+          && !args
+            .iter()
+            .any(|arg| arg.option.long_name() == opt.long_name())
+      }) {
+        return Err(format!(
+          "Missing mandatory option '{}{}'",
+          LONG_SWITCH,
+          missing.long_name()
+        ));
+      }
+    }
+
+    Ok(CliRunner {
+      options,
+      args,
+      help_requested,
+    })
+  }
+}
+
+fn options_help(options: &[CliOption]) -> String {
+  let mut string_builder = String::new();
+
+  for option in options.iter() {
+    string_builder.push_str(option.help(SHORT_SWITCH, LONG_SWITCH).as_ref());
+    if option.is_mandatory() {
+      string_builder.push_str(" (mandatory)");
+    }
+    string_builder.push('\n');
   }
+  string_builder.push_str(
+    CliOption::new(
+      HELP_SHORT_NAME,
+      HELP_LONG_NAME,
+      false,
+      true,
+      "Print this help",
+    )
+    .help(SHORT_SWITCH, LONG_SWITCH)
+    .as_ref(),
+  );
+  string_builder.push('\n');
+
+  string_builder
+}
+
+// short names only follow a single dash and long names only a double one
+enum Switch<'a> {
+  Short(&'a str),
+  Long(&'a str),
+}
 
-  fn new(args: String, options: Vec<CliOption>) -> CliRunner {
-    CliRunner {
-      args: Vec::new(),
-      original_argument_line: args,
+impl Switch<'_> {
+  fn names(&self, short_name: &str, long_name: &str) -> bool {
+    match self {
+      Switch::Short(name) => *name == short_name,
+      Switch::Long(name) => *name == long_name,
     }
   }
 }
 
+fn split_switch(token: &str) -> Result<(Switch<'_>, Option<String>), String> {
+  let (name, inline_value) = match token.split_once('=') {
+    Some((name, value)) => (name, Some(value.to_string())),
+    None => (token, None),
+  };
+
+  let switch = if let Some(long) = name.strip_prefix(LONG_SWITCH) {
+    Switch::Long(long)
+  } else if let Some(short) = name.strip_prefix(SHORT_SWITCH) {
+    Switch::Short(short)
+  } else {
+    return Err(format!("Expected option but found '{}'", token));
+  };
+
+  Ok((switch, inline_value))
+}
+
+fn tokenize(arg_line: &str) -> Vec<String> {
+  let mut tokens = Vec::new();
+  let mut current = String::new();
+  let mut in_quotes = false;
+
+  for c in arg_line.chars() {
+    match c {
+      '"' => in_quotes = !in_quotes,
+      c if c.is_whitespace() && !in_quotes => {
+        if !current.is_empty() {
+          tokens.push(std::mem::take(&mut current));
+        }
+      }
+      c => current.push(c),
+    }
+  }
+  if !current.is_empty() {
+    tokens.push(current);
+  }
+
+  tokens
+}
+
 pub struct CliBuilder {
   options: Vec<CliOption>,
-  arg_line: String,
+  arg_tokens: Vec<String>,
 }
 
 impl CliBuilder {
   fn new() -> Self {
     Self {
       options: Vec::new(),
-      arg_line: String::new(),
+      arg_tokens: Vec::new(),
     }
   }
 
@@ -51,11 +205,65 @@ impl CliBuilder {
   }
 
   pub fn with_arguments(mut self, args: &str) -> CliBuilder {
-    self.arg_line = args.to_string();
+    self.arg_tokens = tokenize(args);
+    self
+  }
+
+  pub fn with_argument_list<I: IntoIterator<Item = String>>(mut self, args: I) -> CliBuilder {
+    self.arg_tokens = args.into_iter().collect();
     self
   }
 
-  pub fn make_runner(self) -> CliRunner {
-    CliRunner::new(self.arg_line, self.options)
+  pub fn help(&self) -> String {
+    options_help(&self.options)
+  }
+
+  pub fn make_runner(self) -> Result<CliRunner, String> {
+    CliRunner::new(self.arg_tokens, self.options)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn runner_for(args: &str) -> Result<CliRunner, String> {
+    CliRunner::from_builder()
+      .register_option(CliOption::new("d", "day", true, false, "Day"))
+      .register_option(CliOption::new("v", "verbose", false, true, "Verbose"))
+      .with_arguments(args)
+      .make_runner()
+  }
+
+  #[test]
+  fn parses_short_and_long_switches() {
+    let runner = runner_for("-d 5 --verbose").unwrap();
+    assert_eq!(Some("5"), runner.value_of("day"));
+    assert!(runner.is_set("verbose"));
+
+    let runner = runner_for("--day=\"1 2\"").unwrap();
+    assert_eq!(Some("1 2"), runner.value_of("day"));
+    assert!(!runner.is_set("verbose"));
+  }
+
+  #[test]
+  fn rejects_invalid_argument_lines() {
+    assert!(runner_for("--verbose").is_err());
+    assert!(runner_for("--day").is_err());
+    assert!(runner_for("--day 1 --unknown").is_err());
+    assert!(runner_for("--day 1 --verbose=yes").is_err());
+    assert!(runner_for("--help").unwrap().help_requested());
+  }
+
+  #[test]
+  fn names_must_match_their_switch() {
+    assert!(runner_for("-d=5").is_ok());
+    assert!(runner_for("--d 5").is_err());
+    assert!(runner_for("-day 5").is_err());
+    assert!(runner_for("--day 5 -verbose").is_err());
+    assert!(runner_for("--day 5 --v").is_err());
+    assert!(runner_for("-help").is_err());
+    assert!(runner_for("--h").is_err());
+    assert!(runner_for("-h").unwrap().help_requested());
   }
 }
@@ -1,6 +1,5 @@
-pub fn solve(expenses: &[u64]) -> u64 {
-  let (num1, num2) = find_sum(2020u64, expenses).unwrap();
-  num1 * num2
+pub fn solve(expenses: &[u64]) -> Option<u64> {
+  find_sum(2020u64, expenses).map(|(num1, num2)| num1 * num2)
 }
 
 fn find_sum(target: u64, numbers: &[u64]) -> Option<(u64, u64)> {
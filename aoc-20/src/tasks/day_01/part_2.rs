@@ -1,6 +1,5 @@
-pub(super) fn solve(expenses: &[u64]) -> u64 {
-  let (num1, num2, num3) = find_sum(2020u64, expenses).unwrap();
-  num1 * num2 * num3
+pub(super) fn solve(expenses: &[u64]) -> Option<u64> {
+  find_sum(2020u64, expenses).map(|(num1, num2, num3)| num1 * num2 * num3)
 }
 
 fn find_sum(target: u64, numbers: &[u64]) -> Option<(u64, u64, u64)> {
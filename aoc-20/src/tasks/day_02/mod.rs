@@ -1,5 +1,7 @@
 use std::str::FromStr;
 
+use super::{Solution, TaskResult};
+
 mod part_1;
 mod part_2;
 
@@ -63,23 +65,23 @@ impl FromStr for DatabaseRecord {
   }
 }
 
-pub fn solve_part_1(input_root: &str) {
-  let input_path = format!("{}/day_02.input.txt", input_root);
-  println!("Reading data from input path: {}", &input_path);
-
-  let solution = part_1::solve_part(&input_path);
-  println!("Day 2, Part 1 || Found {} valid passwords", solution);
+pub fn solve_part_1(input_path: &str) -> TaskResult {
+  let solution = part_1::solve_part(&read_data(input_path)?);
+  Ok(Solution::new(
+    solution,
+    format!("Day 2, Part 1 || Found {} valid passwords", solution),
+  ))
 }
 
-pub fn solve_part_2(input_root: &str) {
-  let input_path = format!("{}/day_02.input.txt", input_root);
-  println!("Reading data from input path: {}", &input_path);
-
-  let solution = part_2::solve_part(&input_path);
-  println!("Day 2, Part 2 || Found {} valid passwords", solution);
+pub fn solve_part_2(input_path: &str) -> TaskResult {
+  let solution = part_2::solve_part(&read_data(input_path)?);
+  Ok(Solution::new(
+    solution,
+    format!("Day 2, Part 2 || Found {} valid passwords", solution),
+  ))
 }
 
-fn read_data(path: &str) -> Vec<DatabaseRecord> {
+fn read_data(path: &str) -> Result<Vec<DatabaseRecord>, String> {
   use crate::common::file_reader as fr;
   fr::parse_input::<DatabaseRecord>(path, "\r\n")
 }
@@ -1,7 +1,7 @@
 use super::DatabaseRecord;
 
-pub fn solve_part(input_path: &str) -> usize {
-  super::count_valid_passwords(super::read_data(input_path).as_slice(), is_valid_password)
+pub fn solve_part(data: &[DatabaseRecord]) -> usize {
+  super::count_valid_passwords(data, is_valid_password)
 }
 
 fn is_valid_password(record: &DatabaseRecord) -> bool {
@@ -1,24 +1,22 @@
 use super::DatabaseRecord;
 
-pub fn solve_part(input_path: &str) -> usize {
-  super::count_valid_passwords(super::read_data(input_path).as_slice(), is_valid_password)
+pub fn solve_part(data: &[DatabaseRecord]) -> usize {
+  super::count_valid_passwords(data, is_valid_password)
 }
 
 fn is_valid_password(record: &DatabaseRecord) -> bool {
-  let letter_at_first_pos = record
-    .password
-    .0
-    .chars()
-    .nth(record.policy.first_num - 1)
-    .unwrap();
-  let letter_at_second_pos = record
-    .password
-    .0
-    .chars()
-    .nth(record.policy.second_num - 1)
-    .unwrap();
-
-  (letter_at_first_pos == record.policy.letter) ^ (letter_at_second_pos == record.policy.letter)
+  let letter_at_first_pos = letter_at(record, record.policy.first_num);
+  let letter_at_second_pos = letter_at(record, record.policy.second_num);
+
+  (letter_at_first_pos == Some(record.policy.letter))
+    ^ (letter_at_second_pos == Some(record.policy.letter))
+}
+
+// positions are 1-based, one outside of the password can't hold the letter
+fn letter_at(record: &DatabaseRecord, position: usize) -> Option<char> {
+  position
+    .checked_sub(1)
+    .and_then(|idx| record.password.0.chars().nth(idx))
 }
 
 #[cfg(test)]
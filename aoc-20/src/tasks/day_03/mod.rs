@@ -1,5 +1,7 @@
 use std::str::FromStr;
 
+use super::{Solution, TaskResult};
+
 mod part_1;
 mod part_2;
 
@@ -88,27 +90,24 @@ fn advance_position(pos: &Position, step: &Step) -> Position {
   Position::new(pos.x + step.delta_x, pos.y + step.delta_y)
 }
 
-fn read_data(path: &str) -> Map {
+fn read_data(path: &str) -> Result<Map, String> {
   use crate::common::file_reader as fr;
-  Map::new(fr::parse_input::<MapRow>(path, "\r\n"))
+  Ok(Map::new(fr::parse_input::<MapRow>(path, "\r\n")?))
 }
 
-pub fn solve_part_1(input_root: &str) {
-  let path = format!("{}/day_03.input.txt", input_root);
-  println!("Reading input from {}", path);
-
-  let map = read_data(path.as_str());
+pub fn solve_part_1(path: &str) -> TaskResult {
+  let map = read_data(path)?;
   let starting_position = Position::new(0, 0);
   let step = Step::new(3, 1);
 
   let tree_count = part_1::number_of_trees_on_slope(&map, &step, &starting_position);
-  println!("Counted {} trees for part 1 solution!", tree_count);
+  Ok(Solution::new(
+    tree_count,
+    format!("Counted {} trees for part 1 solution!", tree_count),
+  ))
 }
 
-pub fn solve_part_2(input_root: &str) {
-  let path = format!("{}/day_03.input.txt", input_root);
-  println!("Reading input from {}", path);
-
+pub fn solve_part_2(path: &str) -> TaskResult {
   let slopes = [
     Step::new(1, 1),
     Step::new(3, 1),
@@ -117,12 +116,15 @@ pub fn solve_part_2(input_root: &str) {
     Step::new(1, 2),
   ];
 
-  let map = read_data(path.as_str());
+  let map = read_data(path)?;
   let starting_position = Position::new(0, 0);
   let result = part_2::mul_tree_counts_on_slopes(&map, &starting_position, &slopes);
 
-  println!(
-    "Multiplication result (part 2 solution) of all slopes is {}",
-    result
-  );
+  Ok(Solution::new(
+    result,
+    format!(
+      "Multiplication result (part 2 solution) of all slopes is {}",
+      result
+    ),
+  ))
 }
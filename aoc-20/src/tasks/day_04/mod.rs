@@ -3,6 +3,8 @@ mod part_2;
 
 use std::{collections::HashMap, str::FromStr};
 
+use super::{Solution, TaskResult};
+
 struct Field {
   pub short_name: String,
   pub optional: bool,
@@ -63,7 +65,7 @@ impl Passport {
   }
 }
 
-fn read_data(path: &str) -> Vec<Passport> {
+fn read_data(path: &str) -> Result<Vec<Passport>, String> {
   use crate::common::file_reader as fr;
 
   fr::parse_input::<Passport>(path, "\r\n\r\n")
@@ -91,24 +93,24 @@ fn is_passport_valid(schema: &PassportSchema, passport: &Passport) -> bool {
     })
 }
 
-pub fn solve_part_1(input_root: &str) {
-  let path = format!("{}/day_04.input.txt", input_root);
-  println!("Reading input for day 4 part 1 fron '{}'", path);
-
-  let passports = read_data(&path);
+pub fn solve_part_1(path: &str) -> TaskResult {
+  let passports = read_data(path)?;
   let schema = part_1::get_schema();
   let result = count_valid_passports(&schema, &passports);
 
-  println!("(Day 4, Part 1) Found {} valid passports", result);
+  Ok(Solution::new(
+    result,
+    format!("(Day 4, Part 1) Found {} valid passports", result),
+  ))
 }
 
-pub fn solve_part_2(input_root: &str) {
-  let path = format!("{}/day_04.input.txt", input_root);
-  println!("Reading input for day 4 part 2 fron '{}'", path);
-
-  let passports = read_data(&path);
+pub fn solve_part_2(path: &str) -> TaskResult {
+  let passports = read_data(path)?;
   let schema = part_2::get_schema();
   let result = count_valid_passports(&schema, &passports);
 
-  println!("(Day 4, Part 2) Counted {} valid passports", result);
+  Ok(Solution::new(
+    result,
+    format!("(Day 4, Part 2) Counted {} valid passports", result),
+  ))
 }
@@ -1,5 +1,7 @@
 use std::str::FromStr;
 
+use super::{Solution, TaskResult};
+
 mod part_1;
 mod part_2;
 
@@ -66,14 +68,14 @@ impl FromStr for BoardingPass {
       .chars()
       .filter(|c| !row_chars.contains(c))
       .map(|c| column_to_instr(c))
-      .collect::<Vec<Instruction>>();
+      .collect::<Result<Vec<Instruction>, String>>()?;
 
     let rows = line
       .trim()
       .chars()
       .filter(|c| row_chars.contains(c))
       .map(|c| row_to_instr(c))
-      .collect::<Vec<Instruction>>();
+      .collect::<Result<Vec<Instruction>, String>>()?;
 
     Ok(Self {
       row_instructions: rows,
@@ -82,19 +84,19 @@ impl FromStr for BoardingPass {
   }
 }
 
-fn row_to_instr(symbol: char) -> Instruction {
+fn row_to_instr(symbol: char) -> Result<Instruction, String> {
   match symbol {
-    'F' => Instruction::Lower,
-    'B' => Instruction::Upper,
-    _ => panic!(),
+    'F' => Ok(Instruction::Lower),
+    'B' => Ok(Instruction::Upper),
+    _ => Err(format!("Unknown row instruction '{}'", symbol)),
   }
 }
 
-fn column_to_instr(symbol: char) -> Instruction {
+fn column_to_instr(symbol: char) -> Result<Instruction, String> {
   match symbol {
-    'L' => Instruction::Lower,
-    'R' => Instruction::Upper,
-    _ => panic!(),
+    'L' => Ok(Instruction::Lower),
+    'R' => Ok(Instruction::Upper),
+    _ => Err(format!("Unknown column instruction '{}'", symbol)),
   }
 }
 
@@ -144,26 +146,27 @@ impl Range {
   }
 }
 
-pub fn solve_part_1(input_root: &str) {
-  let path = format!("{}/day_05.input.txt", input_root);
-  println!("(Day 5, Part 1) Reading input from '{}'", &path);
-
-  let data = get_data(&path);
-  let solution = part_1::highest_seat_id(&data);
+pub fn solve_part_1(path: &str) -> TaskResult {
+  let data = get_data(path)?;
+  let solution = part_1::highest_seat_id(&data).ok_or("No boarding passes given".to_string())?;
 
-  println!("(Day 5, Part 1) Highest seat id is '{}'", solution);
+  Ok(Solution::new(
+    solution,
+    format!("(Day 5, Part 1) Highest seat id is '{}'", solution),
+  ))
 }
-pub fn solve_part_2(input_root: &str) {
-  let path = format!("{}/day_05.input.txt", input_root);
-  println!("(Day 5, Part 2) Reading input from '{}'", &path);
-
-  let data = get_data(&path);
-  let missing_seat_id = part_2::get_missing_seat_id(&data);
-
-  println!("(Day 5, Part 2) Missing seat id {}", missing_seat_id);
+pub fn solve_part_2(path: &str) -> TaskResult {
+  let data = get_data(path)?;
+  let missing_seat_id = part_2::get_missing_seat_id(&data)
+    .ok_or("No seat is missing between the taken ones".to_string())?;
+
+  Ok(Solution::new(
+    missing_seat_id,
+    format!("(Day 5, Part 2) Missing seat id {}", missing_seat_id),
+  ))
 }
 
-fn get_data(path: &str) -> Vec<BoardingPass> {
+fn get_data(path: &str) -> Result<Vec<BoardingPass>, String> {
   use crate::common::file_reader as fr;
   fr::parse_input::<BoardingPass>(path, "\r\n")
 }
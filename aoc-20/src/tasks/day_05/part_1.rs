@@ -1,10 +1,9 @@
 use super::BoardingPass;
 
-pub(super) fn highest_seat_id(data: &[BoardingPass]) -> usize {
+pub(super) fn highest_seat_id(data: &[BoardingPass]) -> Option<usize> {
   data
     .iter()
     .map(|pass| pass.seat())
     .map(|seat| seat.id())
     .max()
-    .unwrap()
 }
@@ -1,6 +1,6 @@
 use super::BoardingPass;
 
-pub(super) fn get_missing_seat_id(passes: &[BoardingPass]) -> usize {
+pub(super) fn get_missing_seat_id(passes: &[BoardingPass]) -> Option<usize> {
   let mut taken_seat_ids = passes
     .iter()
     .map(|pass| pass.seat().id())
@@ -8,14 +8,14 @@ pub(super) fn get_missing_seat_id(passes: &[BoardingPass]) -> usize {
 
   taken_seat_ids.sort();
 
-  let mut current_seat_id = taken_seat_ids.first().unwrap();
+  let mut current_seat_id = taken_seat_ids.first()?;
   for seat_id in taken_seat_ids.iter().skip(1) {
     if seat_id - current_seat_id > 1 {
-      return seat_id - 1;
+      return Some(seat_id - 1);
     } else {
       current_seat_id = seat_id;
     }
   }
 
-  panic!("(Day 5, Part 2) Could not calculate missing seat id");
+  None
 }
@@ -1,5 +1,7 @@
 use std::str::FromStr;
 
+use super::{Solution, TaskResult};
+
 mod part_1;
 mod part_2;
 
@@ -28,8 +30,8 @@ impl FromStr for Group {
     Ok(Self {
       answer_sheets: group_str
         .lines()
-        .map(|l| l.parse::<AnswerSheet>().unwrap())
-        .collect::<Vec<AnswerSheet>>(),
+        .map(|l| l.parse::<AnswerSheet>())
+        .collect::<Result<Vec<AnswerSheet>, String>>()?,
     })
   }
 }
@@ -56,27 +58,30 @@ impl Group {
       .iter()
       .map(|sheet| sheet.yes_answers.iter().copied().collect::<HashSet<char>>())
       .fold_first(|acc, elem| acc.intersection(&elem).copied().collect())
-      .unwrap()
+      .unwrap_or_default()
       .iter()
       .copied()
       .collect()
   }
 }
 
-pub fn solve_part_1(input_root: &str) {
-  let result = part_1::sum_of_group_yes_answers(&get_data(input_root));
-  println!("(Day 6, Part 1) Sum of all group yes answers is {}", result);
+pub fn solve_part_1(input_path: &str) -> TaskResult {
+  let result = part_1::sum_of_group_yes_answers(&get_data(input_path)?);
+  Ok(Solution::new(
+    result,
+    format!("(Day 6, Part 1) Sum of all group yes answers is {}", result),
+  ))
 }
 
-pub fn solve_part_2(input_root: &str) {
-  let result = part_2::sum_of_answeres_where_everyone_said_yes(&get_data(input_root));
-  println!("(Day 6, Part 2) Sum of all group yes answers is {}", result);
+pub fn solve_part_2(input_path: &str) -> TaskResult {
+  let result = part_2::sum_of_answeres_where_everyone_said_yes(&get_data(input_path)?);
+  Ok(Solution::new(
+    result,
+    format!("(Day 6, Part 2) Sum of all group yes answers is {}", result),
+  ))
 }
 
-fn get_data(root: &str) -> Vec<Group> {
+fn get_data(path: &str) -> Result<Vec<Group>, String> {
   use crate::common::file_reader as fr;
-  let path = format!("{}/day_06.input.txt", root);
-  println!("Reading input from {}", &path);
-
-  fr::parse_input::<Group>(&path, "\r\n\r\n")
+  fr::parse_input::<Group>(path, "\r\n\r\n")
 }
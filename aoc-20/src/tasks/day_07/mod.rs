@@ -5,6 +5,8 @@ use std::{
   str::FromStr,
 };
 
+use super::{Solution, TaskResult};
+
 mod part_1;
 mod part_2;
 
@@ -30,9 +32,11 @@ impl FromStr for Bag {
   type Err = String;
 
   fn from_str(input: &str) -> Result<Self, Self::Err> {
-    let mut split_iter = input.split("bags contain");
-    let color = split_iter.next().unwrap().trim().to_string();
-    let bag_contents = split_iter.next().unwrap().trim().trim_end_matches('.');
+    let (color, bag_contents) = input
+      .split_once("bags contain")
+      .ok_or_else(|| format!("Expected '<color> bags contain ...' but got '{}'", input))?;
+    let color = color.trim().to_string();
+    let bag_contents = bag_contents.trim().trim_end_matches('.');
 
     let contents = if bag_contents == "no other bags" {
       HashMap::new()
@@ -40,52 +44,62 @@ impl FromStr for Bag {
       bag_contents
         .split(", ")
         .map(|val| val.trim())
-        .map(|trimmed| trimmed.split_once(' ').unwrap())
-        .map(|(count_str, inside_bag_name)| {
-          (
+        .map(|trimmed| {
+          let (count_str, inside_bag_name) = trimmed
+            .split_once(' ')
+            .ok_or_else(|| format!("Expected '<count> <color> bags' but got '{}'", trimmed))?;
+          let count = count_str
+            .trim()
+            .parse::<usize>()
+            .map_err(|_| format!("Bag count not parsable in '{}'", trimmed))?;
+          Ok((
             inside_bag_name
               .trim()
               .trim_end_matches("bags")
               .trim_end_matches("bag")
               .trim_end()
               .to_string(),
-            count_str.trim().parse::<usize>().unwrap(),
-          )
+            count,
+          ))
         })
-        .collect::<HashMap<String, usize>>()
+        .collect::<Result<HashMap<String, usize>, String>>()?
     };
     Ok(Self { color, contents })
   }
 }
 
-pub fn solve_part_1(input_root: &str) {
+pub fn solve_part_1(input_path: &str) -> TaskResult {
   let target_bag = "shiny gold";
-  let bags = get_data(input_root);
+  let bags = get_data(input_path)?;
 
   let result = part_1::how_many_outermost_bags_contain_target(target_bag, &bags);
-  println!(
-    "(Day 7, Part 1) {} outermost bags can contain {} bag",
-    result, target_bag
-  );
+  Ok(Solution::new(
+    result,
+    format!(
+      "(Day 7, Part 1) {} outermost bags can contain {} bag",
+      result, target_bag
+    ),
+  ))
 }
-pub fn solve_part_2(input_root: &str) {
+pub fn solve_part_2(input_path: &str) -> TaskResult {
   let target_bag = "shiny gold";
-  let bags = get_data(input_root);
-
-  let result = part_2::how_many_bags_must_target_contain(target_bag, &bags);
-  println!(
-    "(Day 7, Part 2) {} must contain {} other bags",
-    target_bag, result
-  );
+  let bags = get_data(input_path)?;
+
+  let result = part_2::how_many_bags_must_target_contain(target_bag, &bags)
+    .ok_or("Some bag has no rule of its own".to_string())?;
+  Ok(Solution::new(
+    result,
+    format!(
+      "(Day 7, Part 2) {} must contain {} other bags",
+      target_bag, result
+    ),
+  ))
 }
 
-fn get_data(root: &str) -> Vec<Bag> {
+fn get_data(path: &str) -> Result<Vec<Bag>, String> {
   use crate::common::file_reader as fr;
 
-  let path = format!("{}/day_07.input.txt", root);
-  println!("Reading input from '{}'", &path);
-
-  fr::parse_input::<Bag>(&path, "\r\n")
+  fr::parse_input::<Bag>(path, "\r\n")
 }
 
 #[cfg(test)]
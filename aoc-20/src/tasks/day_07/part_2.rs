@@ -1,20 +1,19 @@
 use super::Bag;
 
-pub(super) fn how_many_bags_must_target_contain(target: &str, bag_roots: &[Bag]) -> usize {
-  let target_bag = bag_roots
-    .iter()
-    .skip_while(|bag| bag.color != target)
-    .next()
-    .unwrap();
+// None when the target, or any bag inside it, has no rule
+pub(super) fn how_many_bags_must_target_contain(target: &str, bag_roots: &[Bag]) -> Option<usize> {
+  let target_bag = bag_roots.iter().find(|bag| bag.color == target)?;
 
   let count = direct_descendants_count(target_bag);
 
-  return count
-    + target_bag
-      .contents
-      .iter()
-      .map(|(child, num_of_kids)| num_of_kids * how_many_bags_must_target_contain(child, bag_roots))
-      .sum::<usize>();
+  let nested = target_bag
+    .contents
+    .iter()
+    .map(|(child, num_of_kids)| {
+      how_many_bags_must_target_contain(child, bag_roots).map(|kids| num_of_kids * kids)
+    })
+    .sum::<Option<usize>>()?;
+  Some(count + nested)
 }
 
 fn direct_descendants_count(bag: &Bag) -> usize {
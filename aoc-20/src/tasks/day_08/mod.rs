@@ -1,5 +1,7 @@
 use std::str::FromStr;
 
+use super::{Solution, TaskResult};
+
 mod part_1;
 mod part_2;
 
@@ -14,7 +16,10 @@ impl FromStr for Instr {
   type Err = String;
 
   fn from_str(input: &str) -> Result<Self, Self::Err> {
-    let (instr_str, num_str) = input.trim().split_once(' ').unwrap();
+    let (instr_str, num_str) = input
+      .trim()
+      .split_once(' ')
+      .ok_or_else(|| format!("Expected '<instruction> <offset>' but got '{}'", input))?;
     let num = num_str
       .parse::<i32>()
       .map_err(|_| "Instruction offset not parsable".to_string())?;
@@ -66,27 +71,33 @@ fn usize_i32_addition(num: usize, sub: i32) -> usize {
   }
 }
 
-fn get_data(root: &str) -> Vec<Instr> {
+fn get_data(path: &str) -> Result<Vec<Instr>, String> {
   use crate::common::file_reader as fr;
-  let path = format!("{}/day_08.input.txt", root);
-  println!("Reading input from '{}'", &path);
-  fr::parse_input::<Instr>(&path, "\r\n")
+  fr::parse_input::<Instr>(path, "\r\n")
 }
 
-pub fn solve_part_1(input_root: &str) {
-  let data = get_data(input_root);
-  let result = part_1::get_acc_state_before_loop_starts(data);
-  println!(
-    "(Day 8, Part 1) Accumulator state before infinite loop start was '{}'",
-    result
-  );
+pub fn solve_part_1(input_path: &str) -> TaskResult {
+  let data = get_data(input_path)?;
+  let result = part_1::get_acc_state_before_loop_starts(data)
+    .ok_or("Program terminated instead of looping".to_string())?;
+  Ok(Solution::new(
+    result,
+    format!(
+      "(Day 8, Part 1) Accumulator state before infinite loop start was '{}'",
+      result
+    ),
+  ))
 }
 
-pub fn solve_part_2(input_root: &str) {
-  let data = get_data(input_root);
-  let result = part_2::accumulator_of_terminating_version(data);
-  println!(
-    "(Day 8, Part 2) Accumulator after termination has value of '{}'",
-    result
-  );
+pub fn solve_part_2(input_path: &str) -> TaskResult {
+  let data = get_data(input_path)?;
+  let result = part_2::accumulator_of_terminating_version(data)
+    .ok_or("Terminating version of program not found".to_string())?;
+  Ok(Solution::new(
+    result,
+    format!(
+      "(Day 8, Part 2) Accumulator after termination has value of '{}'",
+      result
+    ),
+  ))
 }
@@ -2,7 +2,7 @@ use std::collections::HashSet;
 
 use super::{Executor, Instr};
 
-pub(super) fn accumulator_of_terminating_version(instructions: Vec<Instr>) -> i32 {
+pub(super) fn accumulator_of_terminating_version(instructions: Vec<Instr>) -> Option<i32> {
   let indexes = instructions
     .iter()
     .enumerate()
@@ -17,11 +17,11 @@ pub(super) fn accumulator_of_terminating_version(instructions: Vec<Instr>) -> i3
     version[index] = flip_jmp_and_nop(&version[index]);
 
     if let Some(term_acc) = execute_until_termination(version) {
-      return term_acc;
+      return Some(term_acc);
     }
   }
 
-  panic!("Terminating version of program not found!");
+  None
 }
 
 fn flip_jmp_and_nop(instr: &Instr) -> Instr {
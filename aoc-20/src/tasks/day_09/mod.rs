@@ -1,5 +1,7 @@
 use std::{collections::VecDeque, iter::FromIterator};
 
+use super::{Solution, TaskResult};
+
 mod part_1;
 mod part_2;
 
@@ -32,29 +34,33 @@ impl Encoder {
   }
 }
 
-pub fn solve_part_1(input_root: &str) {
-  let data = get_data(input_root);
+pub fn solve_part_1(input_path: &str) -> TaskResult {
+  let data = get_data(input_path)?;
   let preamble_size = 25usize;
   let first_invalid =
     part_1::find_first_invalid_number(&data[..preamble_size], &data[preamble_size..]);
-  println!(
-    "(Day 9, Part 1) First found invalid encoder number is {}",
-    first_invalid
-  );
+  Ok(Solution::new(
+    first_invalid,
+    format!(
+      "(Day 9, Part 1) First found invalid encoder number is {}",
+      first_invalid
+    ),
+  ))
 }
 
-pub fn solve_part_2(input_root: &str) {
-  let data = get_data(input_root);
+pub fn solve_part_2(input_path: &str) -> TaskResult {
+  let data = get_data(input_path)?;
   let preamble_size = 25usize;
   let first_invalid =
     part_1::find_first_invalid_number(&data[..preamble_size], &data[preamble_size..]);
   let weakness = part_2::get_encription_weakness(first_invalid, data.as_slice());
-  println!("(Day 9, Part 2) Encoder weakness is {}", weakness);
+  Ok(Solution::new(
+    weakness,
+    format!("(Day 9, Part 2) Encoder weakness is {}", weakness),
+  ))
 }
 
-fn get_data(root: &str) -> Vec<usize> {
+fn get_data(path: &str) -> Result<Vec<usize>, String> {
   use crate::common::file_reader as fr;
-  let path = format!("{}/day_09.input.txt", root);
-  println!("Reading input from '{}'", &path);
-  fr::parse_input::<usize>(&path, "\r\n")
+  fr::parse_input::<usize>(path, "\r\n")
 }
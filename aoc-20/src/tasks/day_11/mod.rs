@@ -1,5 +1,7 @@
 use std::{fmt, fmt::Display, str::FromStr};
 
+use super::{Solution, TaskResult};
+
 mod part_1;
 mod part_2;
 
@@ -19,7 +21,11 @@ impl FromStr for Position {
     if trimmed.len() > 1 {
       Err("Invalid input, can't parse position from more than 1 character".to_string())
     } else {
-      match trimmed.chars().next().unwrap() {
+      let symbol = trimmed
+        .chars()
+        .next()
+        .ok_or("Invalid input, can't parse position from empty string".to_string())?;
+      match symbol {
         'L' => Ok(Position::Empty),
         '#' => Ok(Position::Occupied),
         '.' => Ok(Position::Floor),
@@ -79,7 +85,7 @@ impl Display for WaitingArea {
 
 fn area_to_str(area: &WaitingArea) -> String {
   let mut result =
-    String::with_capacity(area.rows.len() * area.rows.first().unwrap().positions.len());
+    String::with_capacity(area.rows.len() * area.rows.first().map_or(0, |row| row.positions.len()));
   result.extend(
     area
       .rows
@@ -158,25 +164,29 @@ fn product(xs: &[isize], ys: &[isize]) -> Vec<(isize, isize)> {
     .flat_map(|&x| ys.iter().clone().map(move |&y| (x, y)))
     .collect()
 }
-pub fn solve_part_1(input_root: &str) {
-  let result = part_1::occupied_seats_after_changes_stop(get_data(input_root));
-  println!(
-    "(Day 11, Part 1) Number of occupied seats after changes stop {}",
-    result
-  );
-}
-
-pub fn solve_part_2(input_root: &str) {
-  let result = part_2::count_occupied_seats_after_changes_stop(get_data(input_root));
-  println!(
-    "(Day 11, Part 2) Number of occupied seats after changes stop {}",
-    result
-  );
-}
-
-fn get_data(root: &str) -> Vec<Row> {
+pub fn solve_part_1(input_path: &str) -> TaskResult {
+  let result = part_1::occupied_seats_after_changes_stop(get_data(input_path)?);
+  Ok(Solution::new(
+    result,
+    format!(
+      "(Day 11, Part 1) Number of occupied seats after changes stop {}",
+      result
+    ),
+  ))
+}
+
+pub fn solve_part_2(input_path: &str) -> TaskResult {
+  let result = part_2::count_occupied_seats_after_changes_stop(get_data(input_path)?);
+  Ok(Solution::new(
+    result,
+    format!(
+      "(Day 11, Part 2) Number of occupied seats after changes stop {}",
+      result
+    ),
+  ))
+}
+
+fn get_data(path: &str) -> Result<Vec<Row>, String> {
   use crate::common::file_reader as fr;
-  let path = format!("{}/day_11.input.txt", root);
-  println!("Reading input from {}", &path);
-  fr::parse_input::<Row>(&path, "\r\n")
+  fr::parse_input::<Row>(path, "\r\n")
 }
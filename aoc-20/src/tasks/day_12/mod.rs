@@ -1,5 +1,7 @@
 use std::str::FromStr;
 
+use super::{Solution, TaskResult};
+
 mod part_1;
 mod part_2;
 
@@ -28,23 +30,27 @@ impl FromStr for Action {
 
   fn from_str(input: &str) -> Result<Self, Self::Err> {
     let mut char_iter = input.trim().chars();
-    let action_str = char_iter.next().unwrap();
+    let action_str = char_iter
+      .next()
+      .ok_or("Could not parse action from empty line".to_string())?;
     let value_str = char_iter.collect::<String>();
     let value = value_str
       .parse::<usize>()
       .map_err(|_| "Could not parse action value".to_string())?;
 
+    // both parts only know how to turn by quarter circles
+    let rotation = |value: usize| match value {
+      90 | 180 | 270 => Ok(RotationDistance(value)),
+      _ => Err(format!("Can't rotate by {} degrees", value)),
+    };
+
     match action_str {
       'N' => Ok(Action::Move(CardinalDirection::North(MoveDistance(value)))),
       'S' => Ok(Action::Move(CardinalDirection::South(MoveDistance(value)))),
       'E' => Ok(Action::Move(CardinalDirection::East(MoveDistance(value)))),
       'W' => Ok(Action::Move(CardinalDirection::West(MoveDistance(value)))),
-      'L' => Ok(Action::Rotate(RotateDirection::Left(RotationDistance(
-        value,
-      )))),
-      'R' => Ok(Action::Rotate(RotateDirection::Right(RotationDistance(
-        value,
-      )))),
+      'L' => Ok(Action::Rotate(RotateDirection::Left(rotation(value)?))),
+      'R' => Ok(Action::Rotate(RotateDirection::Right(rotation(value)?))),
       'F' => Ok(Action::Forward(MoveDistance(value))),
       _ => Err("Could not parse action type".to_string()),
     }
@@ -56,26 +62,29 @@ fn manhattan_distance(start: &PositionT, end: &PositionT) -> usize {
   ((start.0 - end.0).abs() + (start.1 - end.1).abs()) as usize
 }
 
-fn get_data(root: &str) -> Vec<Action> {
+fn get_data(path: &str) -> Result<Vec<Action>, String> {
   use crate::common::file_reader as fr;
-  let path = format!("{}/day_12.input.txt", root);
-  println!("Reading input from '{}'", path);
-
-  fr::parse_input::<Action>(&path, "\r\n")
+  fr::parse_input::<Action>(path, "\r\n")
 }
-pub fn solve_part_1(input_root: &str) {
-  let result = part_1::manhattan_distance_after_navigation(get_data(input_root), (0, 0));
-  println!(
-    "(Day 12, Part 1) Manhattan distance from start after following navigation course is {}",
-    result
-  );
+pub fn solve_part_1(input_path: &str) -> TaskResult {
+  let result = part_1::manhattan_distance_after_navigation(get_data(input_path)?, (0, 0));
+  Ok(Solution::new(
+    result,
+    format!(
+      "(Day 12, Part 1) Manhattan distance from start after following navigation course is {}",
+      result
+    ),
+  ))
 }
 
-pub fn solve_part_2(input_root: &str) {
+pub fn solve_part_2(input_path: &str) -> TaskResult {
   let result =
-    part_2::manhattan_distance_after_waypoint_navigation(get_data(input_root), (0, 0), (10, 1));
-  println!(
-    "(Day 12, Part 2) Manhattan distance from start after following navigation course is {}",
-    result
-  );
+    part_2::manhattan_distance_after_waypoint_navigation(get_data(input_path)?, (0, 0), (10, 1));
+  Ok(Solution::new(
+    result,
+    format!(
+      "(Day 12, Part 2) Manhattan distance from start after following navigation course is {}",
+      result
+    ),
+  ))
 }
@@ -1,5 +1,7 @@
 use std::str::FromStr;
 
+use super::{Solution, TaskResult};
+
 mod part_1;
 mod part_2;
 
@@ -12,6 +14,9 @@ impl FromStr for Bus {
 
   fn from_str(s: &str) -> Result<Self, Self::Err> {
     let parse_result = s.trim().parse::<usize>().ok();
+    if parse_result == Some(0) {
+      return Err("Bus id must not be 0".to_string());
+    }
     Ok(Self { id: parse_result })
   }
 }
@@ -26,13 +31,17 @@ impl FromStr for Schedule {
 
   fn from_str(s: &str) -> Result<Self, Self::Err> {
     let mut split_iter = s.trim().lines();
-    let earliest_departure = split_iter.next().unwrap().parse::<usize>().unwrap();
+    let earliest_departure = split_iter
+      .next()
+      .ok_or("Schedule is missing the earliest departure".to_string())?
+      .parse::<usize>()
+      .map_err(|e| format!("Could not parse earliest departure: {}", e))?;
     let bus_lines = split_iter
       .next()
-      .unwrap()
+      .ok_or("Schedule is missing the bus lines".to_string())?
       .split(",")
-      .map(|id| id.parse::<Bus>().unwrap())
-      .collect::<Vec<_>>();
+      .map(|id| id.parse::<Bus>())
+      .collect::<Result<Vec<_>, _>>()?;
 
     Ok(Self {
       earliest_departure,
@@ -41,22 +50,27 @@ impl FromStr for Schedule {
   }
 }
 
-pub fn solve_part_1(input_root: &str) {
-  let result = part_1::mul_bus_id_and_wait_time(get_data(input_root));
-  println!("(Day 13, Part 1) Result = {}", result);
+pub fn solve_part_1(input_path: &str) -> TaskResult {
+  let result = part_1::mul_bus_id_and_wait_time(get_data(input_path)?)
+    .ok_or("Schedule has no bus in service".to_string())?;
+  Ok(Solution::new(
+    result,
+    format!("(Day 13, Part 1) Result = {}", result),
+  ))
 }
 
-pub fn solve_part_2(input_root: &str) {
-  let result = part_2::earliest_timestamp(get_data(input_root));
-  println!("(Day 13, Part 2) Result = {}", result);
+pub fn solve_part_2(input_path: &str) -> TaskResult {
+  let result = part_2::earliest_timestamp(get_data(input_path)?)
+    .ok_or("Schedule has no bus in service".to_string())?;
+  Ok(Solution::new(
+    result,
+    format!("(Day 13, Part 2) Result = {}", result),
+  ))
 }
 
-fn get_data(root: &str) -> Schedule {
+fn get_data(path: &str) -> Result<Schedule, String> {
   use crate::common::file_reader as fr;
-  let path = format!("{}/day_13.input.txt", root);
-  println!("Reading input from '{}'", path);
-
-  fr::parse_input::<Schedule>(&path, "\r\n\r\n")
+  fr::parse_input::<Schedule>(path, "\r\n\r\n")?
     .pop()
-    .unwrap()
+    .ok_or_else(|| format!("No schedule found in '{}'", path))
 }
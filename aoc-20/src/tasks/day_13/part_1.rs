@@ -1,6 +1,6 @@
 use super::Schedule;
 
-pub(super) fn mul_bus_id_and_wait_time(schedule: Schedule) -> usize {
+pub(super) fn mul_bus_id_and_wait_time(schedule: Schedule) -> Option<usize> {
   let (id, time) = schedule
     .bus_lines
     .iter()
@@ -12,9 +12,8 @@ pub(super) fn mul_bus_id_and_wait_time(schedule: Schedule) -> usize {
         )
       })
     })
-    .min_by_key(|elem| elem.1)
-    .unwrap();
-  id * (time - schedule.earliest_departure)
+    .min_by_key(|elem| elem.1)?;
+  Some(id * (time - schedule.earliest_departure))
 }
 
 fn first_multiplicant_over_target(start: usize, target: usize) -> usize {
@@ -1,6 +1,6 @@
 use super::Schedule;
 
-pub(super) fn earliest_timestamp(schedule: Schedule) -> u64 {
+pub(super) fn earliest_timestamp(schedule: Schedule) -> Option<u64> {
   let ids_offsets = schedule // (bus_id, time_offset)
     .bus_lines
     .iter()
@@ -8,7 +8,7 @@ pub(super) fn earliest_timestamp(schedule: Schedule) -> u64 {
     .filter_map(|(i, bus)| bus.id.map(|id| (id as u64, i as u64)))
     .collect::<Vec<_>>();
 
-  let &(first_id, _) = ids_offsets.first().unwrap();
+  let &(first_id, _) = ids_offsets.first()?;
   let mut timestamp = 0;
   let mut inc = first_id;
 
@@ -19,7 +19,7 @@ pub(super) fn earliest_timestamp(schedule: Schedule) -> u64 {
     inc *= id;
   }
 
-  timestamp
+  Some(timestamp)
 }
 
 fn is_solution(schedule: &Vec<(u64, u64)>, candidate: &u64) -> bool {
@@ -52,7 +52,7 @@ mod tests {
       .collect::<Vec<_>>();
     assert!(is_solution(&id_offsets, &3417));
     let result = super::earliest_timestamp(sched);
-    assert_eq!(3417, result);
+    assert_eq!(Some(3417), result);
   }
   #[test]
   fn test_2() {
@@ -75,7 +75,7 @@ mod tests {
       .collect::<Vec<_>>();
     assert!(is_solution(&id_offsets, &754018));
     let result = super::earliest_timestamp(sched);
-    assert_eq!(754018, result);
+    assert_eq!(Some(754018), result);
   }
   #[test]
   fn test_3() {
@@ -99,7 +99,7 @@ mod tests {
       .collect::<Vec<_>>();
     assert!(is_solution(&id_offsets, &779210));
     let result = super::earliest_timestamp(sched);
-    assert_eq!(779210, result);
+    assert_eq!(Some(779210), result);
   }
   #[test]
   fn test_4() {
@@ -122,7 +122,7 @@ mod tests {
       .collect::<Vec<_>>();
     assert!(is_solution(&id_offsets, &1261476));
     let result = super::earliest_timestamp(sched);
-    assert_eq!(1261476, result);
+    assert_eq!(Some(1261476), result);
   }
   #[test]
   fn test_5() {
@@ -144,6 +144,6 @@ mod tests {
       .collect::<Vec<_>>();
     assert!(is_solution(&id_offsets, &1202161486));
     let result = super::earliest_timestamp(sched);
-    assert_eq!(1202161486, result);
+    assert_eq!(Some(1202161486), result);
   }
 }
@@ -1,5 +1,7 @@
 use std::{collections::HashMap, str::FromStr};
 
+use super::{Solution, TaskResult};
+
 mod part_1;
 mod part_2;
 
@@ -12,10 +14,12 @@ impl FromStr for MemStore {
   type Err = String;
 
   fn from_str(s: &str) -> Result<Self, Self::Err> {
-    let (instr_str, val_str) = s.split_once('=').unwrap();
+    let (instr_str, val_str) = s
+      .split_once('=')
+      .ok_or_else(|| format!("Expected 'mem[<address>] = <value>' but got '{}'", s))?;
     let address = instr_str
       .split_once('[')
-      .unwrap()
+      .ok_or_else(|| format!("Expected 'mem[<address>] = <value>' but got '{}'", s))?
       .1
       .trim()
       .trim_end_matches(']')
@@ -40,7 +44,16 @@ impl FromStr for Mask {
   type Err = String;
 
   fn from_str(s: &str) -> Result<Self, Self::Err> {
-    let (_, val) = s.split_once('=').unwrap();
+    let (_, val) = s
+      .split_once('=')
+      .ok_or_else(|| format!("Expected 'mask = <mask>' but got '{}'", s))?;
+    let val = val.trim();
+    if val.len() != 36 || !val.chars().all(|c| matches!(c, '0' | '1' | 'X')) {
+      return Err(format!(
+        "Mask must be 36 of '0', '1' or 'X' but got '{}'",
+        val
+      ));
+    }
     Ok(Self::new(val))
   }
 }
@@ -131,20 +144,27 @@ fn type_size_in_bits<T>() -> usize {
   std::mem::size_of::<T>() * 8
 }
 
-pub fn solve_part_1(input_root: &str) {
-  let result = part_1::memory_sum_after_execution(get_data(input_root));
-  println!("(Day 14, Part 1) Memory sum = {}", result);
+pub fn solve_part_1(input_path: &str) -> TaskResult {
+  let result = part_1::memory_sum_after_execution(get_data(input_path)?);
+  Ok(Solution::new(
+    result,
+    format!("(Day 14, Part 1) Memory sum = {}", result),
+  ))
 }
 
-pub fn solve_part_2(input_root: &str) {
-  let result = part_2::memory_sum_after_execution(get_data(input_root));
-  println!("(Day 14, Part 2) Memory sum = {}", result);
+pub fn solve_part_2(input_path: &str) -> TaskResult {
+  let result = part_2::memory_sum_after_execution(get_data(input_path)?);
+  Ok(Solution::new(
+    result,
+    format!("(Day 14, Part 2) Memory sum = {}", result),
+  ))
 }
 
-fn get_data(root: &str) -> Vec<Instr> {
+fn get_data(path: &str) -> Result<Vec<Instr>, String> {
   use crate::common::file_reader as fr;
-  let path = format!("{}/day_14.input.txt", root);
-  println!("Reading input from '{}'", path);
-
-  fr::parse_input::<Instr>(&path, "\r\n")
+  let instructions = fr::parse_input::<Instr>(path, "\r\n")?;
+  match instructions.first() {
+    Some(Instr::Mask(_)) => Ok(instructions),
+    _ => Err("Program has to start by setting a mask".to_string()),
+  }
 }
@@ -1,6 +1,8 @@
 use std::collections::HashMap;
 use std::time::Instant;
 
+use super::{Solution, TaskResult};
+
 mod part_1;
 mod part_2;
 
@@ -44,32 +46,39 @@ impl Game {
   }
 }
 
-pub fn solve_part_1(input_root: &str) {
+pub fn solve_part_1(input_path: &str) -> TaskResult {
   let start = Instant::now();
   let result =
-    part_1::number_played_at_turn(Game::from_seed(get_data(input_root).as_slice()), &2020);
+    part_1::number_played_at_turn(Game::from_seed(get_data(input_path)?.as_slice()), &2020);
   let duration = start.elapsed();
-  println!(
-    "(Day 15, Part 1, {:?}) Number on turn 2020 was {}",
-    duration, result
-  );
+  Ok(Solution::new(
+    result,
+    format!(
+      "(Day 15, Part 1, {:?}) Number on turn 2020 was {}",
+      duration, result
+    ),
+  ))
 }
 
-pub fn solve_part_2(input_root: &str) {
+pub fn solve_part_2(input_path: &str) -> TaskResult {
   let start = Instant::now();
   let result =
-    part_1::number_played_at_turn(Game::from_seed(get_data(input_root).as_slice()), &30000000);
+    part_1::number_played_at_turn(Game::from_seed(get_data(input_path)?.as_slice()), &30000000);
   let duration = start.elapsed();
-  println!(
-    "(Day 15, Part 2, {:?}) Number on turn 30000000 was {}",
-    duration, result
-  );
+  Ok(Solution::new(
+    result,
+    format!(
+      "(Day 15, Part 2, {:?}) Number on turn 30000000 was {}",
+      duration, result
+    ),
+  ))
 }
 
-fn get_data(root: &str) -> Vec<usize> {
+fn get_data(path: &str) -> Result<Vec<usize>, String> {
   use crate::common::file_reader as fr;
-  let path = format!("{}/day_15.input.txt", root);
-  println!("Reading input from '{}'", path);
-
-  fr::parse_input::<usize>(&path, ",")
+  let starting_numbers = fr::parse_input::<usize>(path, ",")?;
+  if starting_numbers.is_empty() {
+    return Err("No starting numbers given".to_string());
+  }
+  Ok(starting_numbers)
 }
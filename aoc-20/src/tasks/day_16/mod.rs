@@ -1,5 +1,7 @@
 use std::{collections::HashMap, str::FromStr};
 
+use super::{Solution, TaskResult};
+
 mod part_1;
 mod part_2;
 
@@ -52,15 +54,17 @@ impl FromStr for TicketSchema {
     let fields = s
       .lines()
       .map(|l| {
-        let (name, ranges_str) = l.split_once(':').unwrap();
+        let (name, ranges_str) = l
+          .split_once(':')
+          .ok_or_else(|| format!("Expected '<field>: <ranges>' but got '{}'", l))?;
         let field_name = name.trim().to_string();
         let ranges = ranges_str
           .split("or")
-          .map(|r_str| r_str.trim().parse::<Range>().unwrap())
-          .collect::<Vec<_>>();
-        (field_name, ranges)
+          .map(|r_str| r_str.trim().parse::<Range>())
+          .collect::<Result<Vec<_>, _>>()?;
+        Ok((field_name, ranges))
       })
-      .collect::<HashMap<String, Vec<Range>>>();
+      .collect::<Result<HashMap<String, Vec<Range>>, String>>()?;
 
     Ok(Self { fields })
   }
@@ -77,8 +81,8 @@ impl FromStr for Ticket {
     let field_values = s
       .split(',')
       .map(<usize as FromStr>::from_str)
-      .map(Result::unwrap)
-      .collect::<Vec<_>>();
+      .collect::<Result<Vec<_>, _>>()
+      .map_err(|e| format!("Could not parse ticket '{}': {}", s, e))?;
 
     Ok(Self { field_values })
   }
@@ -95,22 +99,35 @@ impl FromStr for TicketSystem {
 
   fn from_str(s: &str) -> Result<Self, Self::Err> {
     let mut split_iter = s.trim().split("\r\n\r\n");
-    let schema_str = split_iter.next().unwrap();
-    let your_ticket_str = split_iter.next().unwrap();
-    let nearby_tickets_str = split_iter.next().unwrap();
+    let mut section = |name: &str| {
+      split_iter
+        .next()
+        .ok_or_else(|| format!("Ticket notes are missing the {}", name))
+    };
+    let schema_str = section("field rules")?;
+    let your_ticket_str = section("your ticket")?;
+    let nearby_tickets_str = section("nearby tickets")?;
 
     let schema = schema_str.parse::<TicketSchema>()?;
     let your_ticket = your_ticket_str
       .lines()
       .skip(1)
       .next()
-      .unwrap()
+      .ok_or("Ticket notes are missing your ticket".to_string())?
       .parse::<Ticket>()?;
     let nearby_tickets = nearby_tickets_str
       .lines()
       .skip(1)
-      .map(|l| l.parse::<Ticket>().unwrap())
-      .collect::<Vec<_>>();
+      .map(|l| l.parse::<Ticket>())
+      .collect::<Result<Vec<_>, _>>()?;
+
+    let field_count = schema.fields.len();
+    if std::iter::once(&your_ticket)
+      .chain(nearby_tickets.iter())
+      .any(|ticket| ticket.field_values.len() != field_count)
+    {
+      return Err(format!("Every ticket must have {} values", field_count));
+    }
     Ok(Self {
       schema,
       your_ticket,
@@ -119,22 +136,29 @@ impl FromStr for TicketSystem {
   }
 }
 
-pub fn solve_part_1(input_root: &str) {
-  let system = get_data(input_root);
+pub fn solve_part_1(input_path: &str) -> TaskResult {
+  let system = get_data(input_path)?;
   let result = part_1::ticket_scanning_error_rate(system);
-  println!("(Day 16, Part 1) Ticket scanning error rate = {}", result);
+  Ok(Solution::new(
+    result,
+    format!("(Day 16, Part 1) Ticket scanning error rate = {}", result),
+  ))
 }
 
-pub fn solve_part_2(input_root: &str) {
-  let system = get_data(input_root);
-  let result = part_2::departure_validation(system);
-  println!("(Day 16, Part 2) Departure validation: {}", result);
+pub fn solve_part_2(input_path: &str) -> TaskResult {
+  let system = get_data(input_path)?;
+  let result = part_2::departure_validation(system)
+    .ok_or("Fields can't be matched to ticket columns".to_string())?;
+  Ok(Solution::new(
+    result,
+    format!("(Day 16, Part 2) Departure validation: {}", result),
+  ))
 }
 
-fn get_data(root: &str) -> TicketSystem {
+fn get_data(path: &str) -> Result<TicketSystem, String> {
   use crate::common::file_reader as fr;
-  let path = format!("{}/day_16.input.txt", root);
-  println!("Reading input from '{}'", path);
-
-  fr::parse_input::<TicketSystem>(&path, "||||").remove(0)
+  fr::parse_input::<TicketSystem>(path, "||||")?
+    .into_iter()
+    .next()
+    .ok_or_else(|| format!("No ticket system found in '{}'", path))
 }
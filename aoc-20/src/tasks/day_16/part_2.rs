@@ -2,7 +2,7 @@ use std::collections::{HashMap, HashSet};
 
 use super::{Range, Ticket, TicketSchema, TicketSystem};
 
-pub(super) fn departure_validation(system: TicketSystem) -> usize {
+pub(super) fn departure_validation(system: TicketSystem) -> Option<usize> {
   let valid_nearby_tickets = system
     .nearby_tickets
     .iter()
@@ -11,7 +11,7 @@ pub(super) fn departure_validation(system: TicketSystem) -> usize {
 
   let mut column_order: Vec<Vec<usize>> = Vec::new();
 
-  for index in 0..valid_nearby_tickets[0].field_values.len() {
+  for index in 0..valid_nearby_tickets.first()?.field_values.len() {
     let mut column: Vec<usize> = Vec::new();
 
     for ticket in valid_nearby_tickets.iter() {
@@ -24,6 +24,7 @@ pub(super) fn departure_validation(system: TicketSystem) -> usize {
   let mut used_columns: HashSet<usize> = HashSet::new();
 
   while field_positions.len() < system.schema.fields.len() {
+    let known_fields = field_positions.len();
     for (field, constraints) in system.schema.fields.iter() {
       if field_positions.contains_key(field) {
         continue;
@@ -47,8 +48,11 @@ pub(super) fn departure_validation(system: TicketSystem) -> usize {
         used_columns.insert(candidate_positions[0]);
       }
     }
+    if field_positions.len() == known_fields {
+      return None;
+    }
   }
-  field_positions
+  let product = field_positions
     .iter()
     .filter_map(|(k, v)| {
       if k.starts_with("departure") {
@@ -59,7 +63,8 @@ pub(super) fn departure_validation(system: TicketSystem) -> usize {
     })
     .fold(1usize, |acc, elem| {
       acc * system.your_ticket.field_values[*elem]
-    })
+    });
+  Some(product)
 }
 
 fn is_value_valid(value: &usize, ranges: &[Range]) -> bool {
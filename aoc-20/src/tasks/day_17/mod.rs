@@ -3,6 +3,8 @@ use std::{
   str::FromStr,
 };
 
+use super::{Solution, TaskResult};
+
 mod part_1;
 mod part_2;
 
@@ -215,7 +217,10 @@ impl FromStr for ConwayCube {
     if trimmed.len() > 1 {
       Err(format!("Cube must be 1 char: '{}'", trimmed))
     } else {
-      let symbol = trimmed.chars().next().unwrap();
+      let symbol = trimmed
+        .chars()
+        .next()
+        .ok_or("Cube can't be parsed from an empty string".to_string())?;
       match symbol {
         '#' => Ok(Self { active: true }),
         '.' => Ok(Self { active: false }),
@@ -225,22 +230,25 @@ impl FromStr for ConwayCube {
   }
 }
 
-pub fn solve_part_1(input_root: &str) {
-  let result = part_1::boot_cycle(get_data(input_root), 6);
-  println!("(Day 17, Part 1) Active cubes after boot cycle: {}", result);
+pub fn solve_part_1(input_path: &str) -> TaskResult {
+  let result = part_1::boot_cycle(get_data(input_path)?, 6);
+  Ok(Solution::new(
+    result,
+    format!("(Day 17, Part 1) Active cubes after boot cycle: {}", result),
+  ))
 }
 
-pub fn solve_part_2(input_root: &str) {
-  let result = part_2::boot_cycle(get_data(input_root), 6);
-  println!("(Day 17, Part 2) Active cubes after boot cycle: {}", result);
+pub fn solve_part_2(input_path: &str) -> TaskResult {
+  let result = part_2::boot_cycle(get_data(input_path)?, 6);
+  Ok(Solution::new(
+    result,
+    format!("(Day 17, Part 2) Active cubes after boot cycle: {}", result),
+  ))
 }
 
-fn get_data(root: &str) -> Vec<Row> {
+fn get_data(path: &str) -> Result<Vec<Row>, String> {
   use crate::common::file_reader as fr;
-  let path = format!("{}/day_17.input.txt", root);
-  println!("Reading input from '{}'", path);
-
-  fr::parse_input::<Row>(&path, "\r\n")
+  fr::parse_input::<Row>(path, "\r\n")
 }
 
 struct Row {
@@ -255,8 +263,8 @@ impl FromStr for Row {
       cubes: s
         .trim()
         .chars()
-        .map(|c| c.to_string().parse::<ConwayCube>().unwrap())
-        .collect::<Vec<_>>(),
+        .map(|c| c.to_string().parse::<ConwayCube>())
+        .collect::<Result<Vec<_>, _>>()?,
     })
   }
 }
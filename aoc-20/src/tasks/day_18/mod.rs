@@ -1,5 +1,7 @@
 use std::{collections::HashMap, str::FromStr};
 
+use super::{Solution, TaskResult};
+
 mod part_1;
 mod part_2;
 
@@ -51,7 +53,7 @@ struct Equation {
 }
 
 impl Equation {
-  fn solve(&self) -> isize {
+  fn solve(&self) -> Result<isize, String> {
     let mut output: Vec<&Symbol> = Vec::new();
     let mut operator_stack: Vec<&Symbol> = Vec::new();
 
@@ -82,11 +84,13 @@ impl Equation {
         }
         Symbol::Num(_) => output.push(sym),
         Symbol::OpenParen => operator_stack.push(sym),
-        Symbol::CloseParen => {
-          while let s @ Symbol::Op(_) = operator_stack.pop().unwrap() {
-            output.push(s)
+        Symbol::CloseParen => loop {
+          match operator_stack.pop() {
+            Some(s @ Symbol::Op(_)) => output.push(s),
+            Some(_) => break,
+            None => return Err("Closing parenthesis was never opened".to_string()),
           }
-        }
+        },
       }
     }
 
@@ -98,16 +102,21 @@ impl Equation {
     for sym in output {
       match sym {
         Symbol::Op(op) => {
-          let rhs = variable_stack.pop().unwrap();
-          let lhs = variable_stack.pop().unwrap();
+          let missing_operand = || format!("Operator '{}' is missing an operand", op.name);
+          let rhs = variable_stack.pop().ok_or_else(missing_operand)?;
+          let lhs = variable_stack.pop().ok_or_else(missing_operand)?;
           let op_res = (op.operation_fn)(&lhs, &rhs);
           variable_stack.push(op_res);
         }
         Symbol::Num(var) => variable_stack.push(*var),
-        _ => panic!("Output can only containt operators and variables"),
+        _ => return Err("Opening parenthesis is never closed".to_string()),
       }
     }
-    variable_stack.last().unwrap().value
+
+    match variable_stack.as_slice() {
+      [result] => Ok(result.value),
+      _ => Err("Equation must reduce to a single value".to_string()),
+    }
   }
 }
 
@@ -118,7 +127,7 @@ struct EquationParser {
 }
 
 impl EquationParser {
-  fn parse(&self, raw_eq: &RawEquation) -> Equation {
+  fn parse(&self, raw_eq: &RawEquation) -> Result<Equation, String> {
     let mut num_temp = String::new();
     let mut input: Vec<Symbol> = Vec::new();
 
@@ -126,7 +135,7 @@ impl EquationParser {
       if sym.is_whitespace() {
         if !num_temp.is_empty() {
           input.push(Symbol::Num(Variable {
-            value: num_temp.parse().unwrap(),
+            value: parse_number(&num_temp)?,
           }));
           num_temp.clear();
         }
@@ -135,13 +144,13 @@ impl EquationParser {
       } else if sym == self.closing_paren {
         if !num_temp.is_empty() {
           input.push(Symbol::Num(Variable {
-            value: num_temp.parse().unwrap(),
+            value: parse_number(&num_temp)?,
           }));
           num_temp.clear();
         }
         input.push(Symbol::CloseParen);
-      } else if self.operator_map.contains_key(&sym) {
-        input.push(Symbol::Op(self.operator_map.get(&sym).unwrap().clone()));
+      } else if let Some(op) = self.operator_map.get(&sym) {
+        input.push(Symbol::Op(op.clone()));
       } else {
         num_temp.push(sym);
       }
@@ -149,14 +158,20 @@ impl EquationParser {
 
     if !num_temp.is_empty() {
       input.push(Symbol::Num(Variable {
-        value: num_temp.parse().unwrap(),
+        value: parse_number(&num_temp)?,
       }));
       num_temp.clear();
     }
-    Equation { input }
+    Ok(Equation { input })
   }
 }
 
+fn parse_number(num: &str) -> Result<isize, String> {
+  num
+    .parse()
+    .map_err(|_| format!("Could not parse number '{}'", num))
+}
+
 struct RawEquation {
   input: String,
 }
@@ -219,20 +234,23 @@ impl EquationParserBuilder {
   }
 }
 
-pub fn solve_part_1(input_root: &str) {
-  let result = part_1::sum_equation_solutions(get_data(input_root));
-  println!("(Day 18, Part 1) Sum of equation results = {}", result);
+pub fn solve_part_1(input_path: &str) -> TaskResult {
+  let result = part_1::sum_equation_solutions(get_data(input_path)?)?;
+  Ok(Solution::new(
+    result,
+    format!("(Day 18, Part 1) Sum of equation results = {}", result),
+  ))
 }
 
-pub fn solve_part_2(input_root: &str) {
-  let result = part_2::sum_equation_solutions(get_data(input_root));
-  println!("(Day 18, Part 2) Sum of equation results = {}", result);
+pub fn solve_part_2(input_path: &str) -> TaskResult {
+  let result = part_2::sum_equation_solutions(get_data(input_path)?)?;
+  Ok(Solution::new(
+    result,
+    format!("(Day 18, Part 2) Sum of equation results = {}", result),
+  ))
 }
 
-fn get_data(root: &str) -> Vec<RawEquation> {
+fn get_data(path: &str) -> Result<Vec<RawEquation>, String> {
   use crate::common::file_reader as fr;
-  let path = format!("{}/day_18.input.txt", root);
-  println!("Reading input from '{}'", path);
-
-  fr::parse_input::<RawEquation>(&path, "\r\n")
+  fr::parse_input::<RawEquation>(path, "\r\n")
 }
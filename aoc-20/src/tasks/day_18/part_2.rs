@@ -1,6 +1,6 @@
 use super::{Associativity, EquationParserBuilder, Operator, RawEquation, Variable};
 
-pub(super) fn sum_equation_solutions(inputs: Vec<RawEquation>) -> isize {
+pub(super) fn sum_equation_solutions(inputs: Vec<RawEquation>) -> Result<isize, String> {
   let eq_parser = EquationParserBuilder::new()
     .with_open_paren('(')
     .with_closing_paren(')')
@@ -10,8 +10,7 @@ pub(super) fn sum_equation_solutions(inputs: Vec<RawEquation>) -> isize {
 
   inputs
     .into_iter()
-    .map(|raw_eq| eq_parser.parse(&raw_eq))
-    .map(|eq| eq.solve())
+    .map(|raw_eq| eq_parser.parse(&raw_eq)?.solve())
     .sum()
 }
 
@@ -63,7 +62,7 @@ mod tests {
 
     get_test_data()
       .iter()
-      .map(|(r_eq, expected)| (parser.parse(&r_eq).solve(), expected))
-      .for_each(|(result, expected)| assert_eq!(*expected, result));
+      .map(|(r_eq, expected)| (parser.parse(&r_eq).unwrap().solve(), expected))
+      .for_each(|(result, expected)| assert_eq!(Ok(*expected), result));
   }
 }
@@ -1,10 +1,15 @@
-use std::{collections::HashMap, str::FromStr};
+use std::{
+  collections::{HashMap, HashSet},
+  str::FromStr,
+};
+
+use super::{Solution, TaskError, TaskResult};
 
 mod part_1;
 mod part_2;
 
 type RuleId = usize;
-type Subrule = [RuleId; 2];
+type Subrule = Vec<RuleId>;
 
 #[derive(Clone)]
 struct Rule {
@@ -17,7 +22,10 @@ impl FromStr for Rule {
   type Err = String;
 
   fn from_str(s: &str) -> Result<Self, Self::Err> {
-    let (id_str, rule) = s.trim().split_once(':').unwrap();
+    let (id_str, rule) = s
+      .trim()
+      .split_once(':')
+      .ok_or_else(|| format!("Expected '<id>: <rule>' but got '{}'", s))?;
     let id = id_str
       .parse::<RuleId>()
       .map_err(|_| format!("Could not parse rule id '{}'", id_str))?;
@@ -35,14 +43,16 @@ impl FromStr for Rule {
         .map(|s| s.trim())
         .filter(|s| !s.is_empty())
         .map(|sub_rule_variant| {
-          println!("{}", sub_rule_variant);
-          let subrule = sub_rule_variant
-            .split(' ')
-            .map(|rule_id_ref| rule_id_ref.parse::<RuleId>().unwrap())
-            .collect::<Vec<_>>();
-          [subrule[0], subrule[1]]
+          sub_rule_variant
+            .split_whitespace()
+            .map(|rule_id_ref| {
+              rule_id_ref
+                .parse::<RuleId>()
+                .map_err(|_| format!("Could not parse rule reference '{}'", rule_id_ref))
+            })
+            .collect::<Result<Subrule, _>>()
         })
-        .collect::<Vec<_>>();
+        .collect::<Result<Vec<_>, _>>()?;
       Ok(Self {
         id,
         matcher: None,
@@ -112,12 +122,15 @@ fn resolve_rule(
   } else {
     let mut result = Vec::new();
     for subrule in rule.subrules.iter() {
-      let lhs = resolve_rule(rules.get(&subrule[0]).unwrap(), rules, cache);
-      let rhs = resolve_rule(rules.get(&subrule[1]).unwrap(), rules, cache);
-      let mut resolved = Vec::with_capacity(lhs.len() * rhs.len());
-      for i in lhs {
-        for j in &rhs {
-          resolved.push(format!("{}{}", i, j));
+      let mut resolved = vec![String::new()];
+      for rule_id in subrule.iter() {
+        let rhs = resolve_rule(rules.get(rule_id).unwrap(), rules, cache);
+        let lhs = std::mem::replace(&mut resolved, Vec::with_capacity(0));
+        resolved.reserve(lhs.len() * rhs.len());
+        for i in lhs {
+          for j in &rhs {
+            resolved.push(format!("{}{}", i, j));
+          }
         }
       }
       result.extend(resolved);
@@ -133,36 +146,46 @@ struct InputReader {
 }
 
 impl InputReader {
-  fn from_str(rules_str: &str, messages_str: &str) -> Self {
-    Self {
-      rules: rules_str
-        .lines()
-        .map(|l| l.parse::<Rule>().unwrap())
-        .collect::<Vec<_>>(),
-      messages: messages_str
-        .lines()
-        .map(|l| l.parse::<Message>().unwrap())
-        .collect::<Vec<_>>(),
+  fn from_str(rules_str: &str, messages_str: &str) -> Result<Self, String> {
+    let rules = rules_str
+      .lines()
+      .map(|l| l.parse::<Rule>())
+      .collect::<Result<Vec<_>, _>>()?;
+    let messages = messages_str
+      .lines()
+      .map(|l| l.parse::<Message>())
+      .collect::<Result<Vec<_>, _>>()?;
+
+    let known = rules.iter().map(|r| r.id).collect::<HashSet<_>>();
+    if !known.contains(&0) {
+      return Err("There is no rule 0 to match messages against".to_string());
+    }
+    let mut referenced = rules.iter().flat_map(|r| r.subrules.iter().flatten());
+    if let Some(missing) = referenced.find(|id| !known.contains(id)) {
+      return Err(format!("Rule {} is referenced but never defined", missing));
     }
+    Ok(Self { rules, messages })
   }
 }
 
-pub fn solve_part_1(input_root: &str) {
-  let result = part_1::count_matches_for_zero_rule(get_data(input_root));
-  println!("(Day 19, Part 1) Messages matching rule 0 = {}", result);
+pub fn solve_part_1(input_path: &str) -> TaskResult {
+  let result = part_1::count_matches_for_zero_rule(get_data(input_path)?);
+  Ok(Solution::new(
+    result,
+    format!("(Day 19, Part 1) Messages matching rule 0 = {}", result),
+  ))
 }
 
-pub fn solve_part_2(input_root: &str) {
-  println!("(Day 19, Part 2) Not Implemented");
+pub fn solve_part_2(_input_path: &str) -> TaskResult {
+  Err(TaskError::NotImplemented)
 }
 
-fn get_data(root: &str) -> InputReader {
+fn get_data(path: &str) -> Result<InputReader, String> {
   use crate::common::file_reader as fr;
-  let path = format!("{}/day_19.input.txt", root);
-  println!("Reading input from '{}'", path);
 
-  let mut split_iter: Vec<String> = fr::read_unparsed(&path, "\r\n\r\n");
-  let messages = split_iter.pop().unwrap();
-  let rules = split_iter.pop().unwrap();
+  let mut split_iter: Vec<String> = fr::read_unparsed(path, "\r\n\r\n")?;
+  let missing_section = || format!("Expected rules and messages sections in '{}'", path);
+  let messages = split_iter.pop().ok_or_else(missing_section)?;
+  let rules = split_iter.pop().ok_or_else(missing_section)?;
   InputReader::from_str(&rules, &messages)
 }
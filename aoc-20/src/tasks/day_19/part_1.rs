@@ -9,3 +9,33 @@ pub(super) fn count_matches_for_zero_rule(input: InputReader) -> usize {
     .filter(|msg| engine.matches(0, &msg))
     .count()
 }
+
+#[cfg(test)]
+mod tests {
+  use super::super::InputReader;
+
+  #[test]
+  fn rules_may_reference_any_number_of_rules() {
+    let rules = "0: 4 1 5
+1: 2 3 | 3 2
+2: 4 4 | 5 5
+3: 4 5 | 5 4
+4: \"a\"
+5: \"b\"";
+    let messages = "ababbb
+bababa
+abbbab
+aaabbb
+aaaabbb";
+
+    let input = InputReader::from_str(rules, messages).unwrap();
+    assert_eq!(2, super::count_matches_for_zero_rule(input));
+  }
+
+  #[test]
+  fn undefined_rules_are_rejected() {
+    assert!(InputReader::from_str("0: 1 2\n1: \"a\"", "a").is_err());
+    assert!(InputReader::from_str("1: \"a\"", "a").is_err());
+    assert!(InputReader::from_str("0: 1 x", "a").is_err());
+  }
+}
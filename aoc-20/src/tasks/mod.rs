@@ -1,3 +1,5 @@
+use std::{fmt, str::FromStr};
+
 mod day_01;
 mod day_02;
 mod day_03;
@@ -18,87 +20,168 @@ mod day_17;
 mod day_18;
 mod day_19;
 
-pub fn dispatch(input_root: &str, day: &Day, part: &Part) {
+pub type TaskResult = Result<Solution, TaskError>;
+
+pub struct Solution {
+  pub answer: Answer,
+  pub rendered: String,
+}
+
+impl Solution {
+  pub fn new<T: Into<Answer>>(answer: T, rendered: String) -> Self {
+    Self {
+      answer: answer.into(),
+      rendered,
+    }
+  }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Answer {
+  Unsigned(u64),
+  Signed(i64),
+}
+
+impl From<u64> for Answer {
+  fn from(val: u64) -> Self {
+    Answer::Unsigned(val)
+  }
+}
+
+impl From<usize> for Answer {
+  fn from(val: usize) -> Self {
+    Answer::Unsigned(val as u64)
+  }
+}
+
+impl From<i32> for Answer {
+  fn from(val: i32) -> Self {
+    Answer::Signed(val as i64)
+  }
+}
+
+impl From<isize> for Answer {
+  fn from(val: isize) -> Self {
+    Answer::Signed(val as i64)
+  }
+}
+
+impl fmt::Display for Answer {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      Answer::Unsigned(val) => write!(f, "{}", val),
+      Answer::Signed(val) => write!(f, "{}", val),
+    }
+  }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TaskError {
+  Input(String),
+  NotImplemented,
+}
+
+impl From<String> for TaskError {
+  fn from(msg: String) -> Self {
+    TaskError::Input(msg)
+  }
+}
+
+impl fmt::Display for TaskError {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      TaskError::Input(msg) => write!(f, "Invalid input: {}", msg),
+      TaskError::NotImplemented => f.write_str("Not implemented"),
+    }
+  }
+}
+
+pub fn dispatch(input_root: &str, day: &Day, part: &Part) -> TaskResult {
+  solve(&day.input_file(input_root), day, part)
+}
+
+pub fn solve(input_path: &str, day: &Day, part: &Part) -> TaskResult {
   match day {
     Day::Day01 => match part {
-      Part::First => day_01::solve_part_1(input_root),
-      Part::Second => day_01::solve_part_2(input_root),
+      Part::First => day_01::solve_part_1(input_path),
+      Part::Second => day_01::solve_part_2(input_path),
     },
     Day::Day02 => match part {
-      Part::First => day_02::solve_part_1(input_root),
-      Part::Second => day_02::solve_part_2(input_root),
+      Part::First => day_02::solve_part_1(input_path),
+      Part::Second => day_02::solve_part_2(input_path),
     },
     Day::Day03 => match part {
-      Part::First => day_03::solve_part_1(input_root),
-      Part::Second => day_03::solve_part_2(input_root),
+      Part::First => day_03::solve_part_1(input_path),
+      Part::Second => day_03::solve_part_2(input_path),
     },
     Day::Day04 => match part {
-      Part::First => day_04::solve_part_1(input_root),
-      Part::Second => day_04::solve_part_2(input_root),
+      Part::First => day_04::solve_part_1(input_path),
+      Part::Second => day_04::solve_part_2(input_path),
     },
     Day::Day05 => match part {
-      Part::First => day_05::solve_part_1(input_root),
-      Part::Second => day_05::solve_part_2(input_root),
+      Part::First => day_05::solve_part_1(input_path),
+      Part::Second => day_05::solve_part_2(input_path),
     },
     Day::Day06 => match part {
-      Part::First => day_06::solve_part_1(input_root),
-      Part::Second => day_06::solve_part_2(input_root),
+      Part::First => day_06::solve_part_1(input_path),
+      Part::Second => day_06::solve_part_2(input_path),
     },
     Day::Day07 => match part {
-      Part::First => day_07::solve_part_1(input_root),
-      Part::Second => day_07::solve_part_2(input_root),
+      Part::First => day_07::solve_part_1(input_path),
+      Part::Second => day_07::solve_part_2(input_path),
     },
     Day::Day08 => match part {
-      Part::First => day_08::solve_part_1(input_root),
-      Part::Second => day_08::solve_part_2(input_root),
+      Part::First => day_08::solve_part_1(input_path),
+      Part::Second => day_08::solve_part_2(input_path),
     },
     Day::Day09 => match part {
-      Part::First => day_09::solve_part_1(input_root),
-      Part::Second => day_09::solve_part_2(input_root),
+      Part::First => day_09::solve_part_1(input_path),
+      Part::Second => day_09::solve_part_2(input_path),
     },
     Day::Day10 => match part {
-      Part::First => day_10::solve_part_1(input_root),
-      Part::Second => day_10::solve_part_2(input_root),
+      Part::First => day_10::solve_part_1(input_path),
+      Part::Second => day_10::solve_part_2(input_path),
     },
     Day::Day11 => match part {
-      Part::First => day_11::solve_part_1(input_root),
-      Part::Second => day_11::solve_part_2(input_root),
+      Part::First => day_11::solve_part_1(input_path),
+      Part::Second => day_11::solve_part_2(input_path),
     },
     Day::Day12 => match part {
-      Part::First => day_12::solve_part_1(input_root),
-      Part::Second => day_12::solve_part_2(input_root),
+      Part::First => day_12::solve_part_1(input_path),
+      Part::Second => day_12::solve_part_2(input_path),
     },
     Day::Day13 => match part {
-      Part::First => day_13::solve_part_1(input_root),
-      Part::Second => day_13::solve_part_2(input_root),
+      Part::First => day_13::solve_part_1(input_path),
+      Part::Second => day_13::solve_part_2(input_path),
     },
     Day::Day14 => match part {
-      Part::First => day_14::solve_part_1(input_root),
-      Part::Second => day_14::solve_part_2(input_root),
+      Part::First => day_14::solve_part_1(input_path),
+      Part::Second => day_14::solve_part_2(input_path),
     },
     Day::Day15 => match part {
-      Part::First => day_15::solve_part_1(input_root),
-      Part::Second => day_15::solve_part_2(input_root),
+      Part::First => day_15::solve_part_1(input_path),
+      Part::Second => day_15::solve_part_2(input_path),
     },
     Day::Day16 => match part {
-      Part::First => day_16::solve_part_1(input_root),
-      Part::Second => day_16::solve_part_2(input_root),
+      Part::First => day_16::solve_part_1(input_path),
+      Part::Second => day_16::solve_part_2(input_path),
     },
     Day::Day17 => match part {
-      Part::First => day_17::solve_part_1(input_root),
-      Part::Second => day_17::solve_part_2(input_root),
+      Part::First => day_17::solve_part_1(input_path),
+      Part::Second => day_17::solve_part_2(input_path),
     },
     Day::Day18 => match part {
-      Part::First => day_18::solve_part_1(input_root),
-      Part::Second => day_18::solve_part_2(input_root),
+      Part::First => day_18::solve_part_1(input_path),
+      Part::Second => day_18::solve_part_2(input_path),
     },
     Day::Day19 => match part {
-      Part::First => day_19::solve_part_1(input_root),
-      Part::Second => day_19::solve_part_2(input_root),
+      Part::First => day_19::solve_part_1(input_path),
+      Part::Second => day_19::solve_part_2(input_path),
     },
   }
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Day {
   Day01,
   Day02,
@@ -121,7 +204,98 @@ pub enum Day {
   Day19,
 }
 
+impl Day {
+  pub fn all() -> [Day; 19] {
+    [
+      Day::Day01,
+      Day::Day02,
+      Day::Day03,
+      Day::Day04,
+      Day::Day05,
+      Day::Day06,
+      Day::Day07,
+      Day::Day08,
+      Day::Day09,
+      Day::Day10,
+      Day::Day11,
+      Day::Day12,
+      Day::Day13,
+      Day::Day14,
+      Day::Day15,
+      Day::Day16,
+      Day::Day17,
+      Day::Day18,
+      Day::Day19,
+    ]
+  }
+
+  pub fn number(&self) -> u8 {
+    *self as u8 + 1
+  }
+
+  pub fn input_file(&self, input_root: &str) -> String {
+    format!("{}/day_{:02}.input.txt", input_root, self.number())
+  }
+}
+
+impl FromStr for Day {
+  type Err = String;
+
+  fn from_str(s: &str) -> Result<Self, Self::Err> {
+    let number = s
+      .trim()
+      .trim_start_matches(|c: char| !c.is_ascii_digit())
+      .parse::<u8>()
+      .map_err(|_| format!("Could not parse day '{}'", s))?;
+
+    match number {
+      1 => Ok(Day::Day01),
+      2 => Ok(Day::Day02),
+      3 => Ok(Day::Day03),
+      4 => Ok(Day::Day04),
+      5 => Ok(Day::Day05),
+      6 => Ok(Day::Day06),
+      7 => Ok(Day::Day07),
+      8 => Ok(Day::Day08),
+      9 => Ok(Day::Day09),
+      10 => Ok(Day::Day10),
+      11 => Ok(Day::Day11),
+      12 => Ok(Day::Day12),
+      13 => Ok(Day::Day13),
+      14 => Ok(Day::Day14),
+      15 => Ok(Day::Day15),
+      16 => Ok(Day::Day16),
+      17 => Ok(Day::Day17),
+      18 => Ok(Day::Day18),
+      19 => Ok(Day::Day19),
+      _ => Err(format!("Day {} is not solved", number)),
+    }
+  }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Part {
   First,
   Second,
 }
+
+impl Part {
+  pub fn number(&self) -> u8 {
+    match self {
+      Part::First => 1,
+      Part::Second => 2,
+    }
+  }
+}
+
+impl FromStr for Part {
+  type Err = String;
+
+  fn from_str(s: &str) -> Result<Self, Self::Err> {
+    match s.trim().to_lowercase().as_str() {
+      "1" | "first" => Ok(Part::First),
+      "2" | "second" => Ok(Part::Second),
+      _ => Err(format!("Could not parse part '{}', expected 1 or 2", s)),
+    }
+  }
+}
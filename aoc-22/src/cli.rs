@@ -0,0 +1,172 @@
+use std::{fmt::Display, io::Read, path::PathBuf};
+
+use crate::day_exec::{DayArgError, Part};
+
+pub const USAGE: &str = "Usage: aoc-22 --day N [--part 1|2|both] [--input PATH]
+
+Options:
+    --day N          day to execute (1-13)
+    --part P         part to execute: 1, 2 or both (default: both)
+    --input PATH     file with puzzle input, reads stdin when omitted or '-'
+    --help           print this message";
+
+#[derive(Debug)]
+pub struct CliArgs {
+    pub day: String,
+    pub part: Part,
+    pub input: InputSource,
+}
+
+#[derive(Debug)]
+pub enum InputSource {
+    File(PathBuf),
+    Stdin,
+}
+
+impl InputSource {
+    pub fn read(&self) -> std::io::Result<String> {
+        match self {
+            InputSource::File(path) => std::fs::read_to_string(path),
+            InputSource::Stdin => {
+                let mut buf = String::new();
+                std::io::stdin().read_to_string(&mut buf)?;
+                Ok(buf)
+            }
+        }
+    }
+}
+
+impl Display for InputSource {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            InputSource::File(path) => write!(f, "{}", path.display()),
+            InputSource::Stdin => f.write_str("<stdin>"),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum CliError {
+    HelpRequested,
+    MissingDay,
+    MissingValue(String),
+    UnknownArgument(String),
+    InvalidArgument(DayArgError),
+}
+
+impl Display for CliError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CliError::HelpRequested => f.write_str(USAGE),
+            CliError::MissingDay => f.write_str("Missing mandatory argument --day"),
+            CliError::MissingValue(opt) => write!(f, "Missing value for argument {}", opt),
+            CliError::UnknownArgument(arg) => write!(f, "Unknown argument '{}'", arg),
+            CliError::InvalidArgument(err) => err.fmt(f),
+        }
+    }
+}
+
+impl From<DayArgError> for CliError {
+    fn from(err: DayArgError) -> Self {
+        CliError::InvalidArgument(err)
+    }
+}
+
+const KNOWN_OPTIONS: [&str; 6] = ["--day", "-d", "--part", "-p", "--input", "-i"];
+
+pub fn parse_args(args: impl IntoIterator<Item = String>) -> Result<CliArgs, CliError> {
+    let mut day = None;
+    let mut part = Part::Both;
+    let mut input = InputSource::Stdin;
+
+    let mut args = args.into_iter();
+    while let Some(arg) = args.next() {
+        let (name, inline_value) = match arg.split_once('=') {
+            Some((name, value)) => (name.to_owned(), Some(value.to_owned())),
+            None => (arg.clone(), None),
+        };
+
+        if name == "--help" || name == "-h" {
+            return Err(CliError::HelpRequested);
+        }
+
+        if !KNOWN_OPTIONS.contains(&name.as_str()) {
+            return Err(CliError::UnknownArgument(arg));
+        }
+
+        let value = match inline_value.or_else(|| args.next()) {
+            Some(value) => value,
+            None => return Err(CliError::MissingValue(name)),
+        };
+
+        match name.as_str() {
+            "--day" | "-d" => day = Some(value),
+            "--part" | "-p" => part = value.parse()?,
+            "--input" | "-i" => {
+                input = if value == "-" {
+                    InputSource::Stdin
+                } else {
+                    InputSource::File(PathBuf::from(value))
+                }
+            }
+            _ => unreachable!("Option {} is listed as known but not handled", name),
+        }
+    }
+
+    Ok(CliArgs {
+        day: day.ok_or(CliError::MissingDay)?,
+        part,
+        input,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(args: &[&str]) -> Result<CliArgs, CliError> {
+        parse_args(args.iter().map(|arg| arg.to_string()))
+    }
+
+    #[test]
+    fn long_short_and_inline_options() {
+        let args = parse(&["--day", "7", "--part", "2", "--input", "day7.txt"]).unwrap();
+        assert_eq!("7", args.day);
+        assert_eq!(Part::Two, args.part);
+        assert!(matches!(args.input, InputSource::File(path) if path.as_os_str() == "day7.txt"));
+
+        let args = parse(&["-d=day3", "-p", "both", "-i=-"]).unwrap();
+        assert_eq!("day3", args.day);
+        assert_eq!(Part::Both, args.part);
+        assert!(matches!(args.input, InputSource::Stdin));
+    }
+
+    #[test]
+    fn part_and_input_have_defaults() {
+        let args = parse(&["--day", "1"]).unwrap();
+        assert_eq!(Part::Both, args.part);
+        assert!(matches!(args.input, InputSource::Stdin));
+    }
+
+    #[test]
+    fn bad_arguments_are_rejected() {
+        assert!(matches!(parse(&[]), Err(CliError::MissingDay)));
+        assert!(matches!(parse(&["--part", "1"]), Err(CliError::MissingDay)));
+        assert!(matches!(
+            parse(&["--day"]),
+            Err(CliError::MissingValue(opt)) if opt == "--day"
+        ));
+        assert!(matches!(
+            parse(&["--day", "1", "--verbose"]),
+            Err(CliError::UnknownArgument(arg)) if arg == "--verbose"
+        ));
+        assert!(matches!(
+            parse(&["--day", "1", "--part", "3"]),
+            Err(CliError::InvalidArgument(DayArgError::InvalidPart(_)))
+        ));
+        assert!(matches!(
+            parse(&["--day", "1", "--help"]),
+            Err(CliError::HelpRequested)
+        ));
+    }
+}
@@ -1,64 +1,122 @@
 use std::{fmt::Display, str::FromStr};
 
+use crate::days::{
+    day1::Day1, day10::Day10, day11::Day11, day12::Day12, day13::Day13, day2::Day2, day3::Day3,
+    day4::Day4, day5::Day5, day6::Day6, day7::Day7, day8::Day8, day9::Day9,
+};
+
 pub trait DayExecutor {
     fn exec_part1(&self, input: String) -> Box<dyn Display>;
     fn exec_part2(&self, input: String) -> Box<dyn Display>;
 }
 
-trait DayExecutorFactory {
-    fn make_day_executor(&self, day_arg: &str, part_arg: &str) -> Box<dyn DayExecutor>;
+pub trait DayExecutorFactory {
+    fn make_day_executor(&self, day_arg: &str) -> Result<Box<dyn DayExecutor>, DayArgError>;
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Part {
+    One,
+    Two,
+    Both,
 }
 
-struct DayFactory {}
+impl Part {
+    pub fn includes_part1(&self) -> bool {
+        matches!(self, Part::One | Part::Both)
+    }
 
-#[derive(Clone)]
-struct UnknownDayExecutor {
-    day_arg: String,
-    part_arg: String,
+    pub fn includes_part2(&self) -> bool {
+        matches!(self, Part::Two | Part::Both)
+    }
 }
 
-impl UnknownDayExecutor {
-    fn new(day_arg: String, part_arg: String) -> Self {
-        Self { day_arg, part_arg }
+impl FromStr for Part {
+    type Err = DayArgError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let trimmed = s.trim();
+        if trimmed.eq_ignore_ascii_case("both") {
+            return Ok(Part::Both);
+        }
+
+        match parse_number_arg(trimmed) {
+            Some(1) => Ok(Part::One),
+            Some(2) => Ok(Part::Two),
+            _ => Err(DayArgError::InvalidPart(s.to_owned())),
+        }
     }
 }
 
-impl Display for UnknownDayExecutor {
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DayArgError {
+    InvalidDay(String),
+    InvalidPart(String),
+    UnknownDay(usize),
+}
+
+impl Display for DayArgError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.write_str(&format!(
-            "Can't provide executor for day {} part {}",
-            self.day_arg, self.part_arg
-        ))
+        match self {
+            DayArgError::InvalidDay(arg) => write!(f, "Wrong argument for selecting day: '{}'", arg),
+            DayArgError::InvalidPart(arg) => write!(
+                f,
+                "Wrong argument for selecting part: '{}', expected 1, 2 or both",
+                arg
+            ),
+            DayArgError::UnknownDay(day) => write!(
+                f,
+                "Day {} is not implemented, available days are {}..={}",
+                day,
+                DayFactory::FIRST_DAY,
+                DayFactory::LAST_DAY
+            ),
+        }
     }
 }
 
-impl DayExecutor for UnknownDayExecutor {
-    fn exec_part1(&self, input: String) -> Box<dyn Display> {
-        Box::new(self.clone())
-    }
+impl std::error::Error for DayArgError {}
+
+pub struct DayFactory {}
+
+impl DayFactory {
+    pub const FIRST_DAY: usize = 1;
+    pub const LAST_DAY: usize = 13;
 
-    fn exec_part2(&self, input: String) -> Box<dyn Display> {
-        Box::new(self.clone())
+    pub(crate) fn executor_for(day: usize) -> Option<Box<dyn DayExecutor>> {
+        let exec: Box<dyn DayExecutor> = match day {
+            1 => Box::new(Day1),
+            2 => Box::new(Day2),
+            3 => Box::new(Day3),
+            4 => Box::new(Day4),
+            5 => Box::new(Day5),
+            6 => Box::new(Day6),
+            7 => Box::new(Day7),
+            8 => Box::new(Day8),
+            9 => Box::new(Day9),
+            10 => Box::new(Day10),
+            11 => Box::new(Day11),
+            12 => Box::new(Day12),
+            13 => Box::new(Day13),
+            _ => return None,
+        };
+        Some(exec)
     }
 }
 
 impl DayExecutorFactory for DayFactory {
-    fn make_day_executor(&self, day_arg: &str, part_arg: &str) -> Box<dyn DayExecutor> {
-        let parsed_day: usize = day_arg
-            .trim_start_matches(|c: char| !c.is_digit(10))
-            .trim()
-            .parse()
-            .expect("Wrong argument for selecting day to execute");
-
-        let parsed_part: usize = part_arg
-            .trim_start_matches(|c: char| !c.is_digit(10))
-            .trim()
-            .parse()
-            .expect("Wrong argument for selecting which part of day to execute");
-
-        Box::new(UnknownDayExecutor::new(
-            day_arg.to_owned(),
-            part_arg.to_owned(),
-        ))
+    fn make_day_executor(&self, day_arg: &str) -> Result<Box<dyn DayExecutor>, DayArgError> {
+        let parsed_day =
+            parse_number_arg(day_arg).ok_or_else(|| DayArgError::InvalidDay(day_arg.to_owned()))?;
+
+        Self::executor_for(parsed_day).ok_or(DayArgError::UnknownDay(parsed_day))
     }
 }
+
+// accepts both plain numbers and prefixed forms such as "day13" or "part2"
+fn parse_number_arg(arg: &str) -> Option<usize> {
+    arg.trim_start_matches(|c: char| !c.is_ascii_digit())
+        .trim()
+        .parse()
+        .ok()
+}
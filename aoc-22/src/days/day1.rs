@@ -17,11 +17,11 @@ impl DayExecutor for Day1 {
     }
 }
 
-fn part1_solution(input: &str) -> u32 {
+pub(crate) fn part1_solution(input: &str) -> u32 {
     *make_total_cal_vec_sorted(&input).last().unwrap()
 }
 
-fn part2_solution(input: &str) -> u32 {
+pub(crate) fn part2_solution(input: &str) -> u32 {
     make_total_cal_vec_sorted(&input)
         .into_iter()
         .rev()
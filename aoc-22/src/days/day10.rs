@@ -21,7 +21,7 @@ impl DayExecutor for Day10 {
     }
 }
 
-fn solve_part1(input: &str) -> isize {
+pub(crate) fn solve_part1(input: &str) -> isize {
     let sample_frequencies = [20, 60, 100, 140, 180, 220];
     let mut sampler = Sampler::new(sample_frequencies);
     let mut cpu = CPU::new(get_instr_set(input));
@@ -18,7 +18,7 @@ impl DayExecutor for Day11 {
     }
 }
 
-fn solve_part1(input: &str) -> u128 {
+pub(crate) fn solve_part1(input: &str) -> u128 {
     let mut group = get_monkey_group_from_input(input);
     group.relief_adujstment = Box::new(|i| i / 3);
 
@@ -34,7 +34,7 @@ fn solve_part1(input: &str) -> u128 {
     last * second_to_last
 }
 
-fn solve_part2(input: &str) -> u128 {
+pub(crate) fn solve_part2(input: &str) -> u128 {
     let mut group = get_monkey_group_from_input(input);
     let monkey_divisors = group
         .monkey_items
@@ -23,7 +23,7 @@ impl DayExecutor for Day12 {
     }
 }
 
-fn solve_part1(input: &str) -> u32 {
+pub(crate) fn solve_part1(input: &str) -> u32 {
     let map = get_map_from_input(input);
     get_shortest_path_for_map(&map)
 }
@@ -45,7 +45,7 @@ fn get_shortest_path_for_map(map: &Map) -> u32 {
     res.unwrap_or(u32::MAX)
 }
 
-fn solve_part2(input: &str) -> u32 {
+pub(crate) fn solve_part2(input: &str) -> u32 {
     let multi_map = get_multi_map(input);
     let map_count = multi_map.len();
 
@@ -1,18 +1,99 @@
-use std::str::FromStr;
+use std::{cmp::Ordering, str::FromStr};
 
 use crate::day_exec::DayExecutor;
 
+use self::details::Element;
+
 pub struct Day13;
 impl DayExecutor for Day13 {
     fn exec_part1(&self, input: String) -> Box<dyn std::fmt::Display> {
-        Box::new(format!("Part 1 TODO"))
+        Box::new(format!(
+            "Sum of indices of pairs in the right order: {}",
+            solve_part1(&input)
+        ))
     }
 
     fn exec_part2(&self, input: String) -> Box<dyn std::fmt::Display> {
-        Box::new(format!("Part 2 TODO"))
+        Box::new(format!(
+            "Decoder key for the distress signal: {}",
+            solve_part2(&input)
+        ))
+    }
+}
+
+pub(crate) fn solve_part1(input: &str) -> usize {
+    get_packets(input)
+        .chunks(2)
+        .enumerate()
+        .filter(|(_, pair)| pair[0] < pair[1])
+        .map(|(idx, _)| idx + 1)
+        .sum()
+}
+
+pub(crate) fn solve_part2(input: &str) -> usize {
+    let dividers =
+        ["[[2]]", "[[6]]"].map(|d| d.parse::<Element>().expect("Divider is a valid packet"));
+
+    let mut packets = get_packets(input);
+    packets.extend(dividers.iter().cloned());
+    packets.sort();
+
+    dividers
+        .iter()
+        .map(|divider| packets.iter().position(|p| p == divider).unwrap() + 1)
+        .product()
+}
+
+fn get_packets(input: &str) -> Vec<Element> {
+    input
+        .lines()
+        .map(|l| l.trim())
+        .filter(|l| !l.is_empty())
+        .map(|l| l.parse::<Element>())
+        .collect::<Result<Vec<_>, _>>()
+        .unwrap_or_else(|e| panic!("Could not parse list of packets: {}", e))
+}
+
+impl FromStr for Element {
+    type Err = details::ParserError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let tokens = details::tokenize(s);
+        details::Parser::new(&mut tokens.iter().peekable()).parse()
+    }
+}
+
+// lists compare element by element, a number against a list is wrapped in a list first
+impl Ord for Element {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (self, other) {
+            (Element::Number(left), Element::Number(right)) => left.cmp(right),
+            (Element::List(left), Element::List(right)) => left.cmp(right),
+            (Element::Number(left), Element::List(_)) => {
+                Element::List(vec![Element::Number(*left)]).cmp(other)
+            }
+            (Element::List(_), Element::Number(right)) => {
+                self.cmp(&Element::List(vec![Element::Number(*right)]))
+            }
+        }
     }
 }
 
+impl PartialOrd for Element {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+// `[1]` and `1` are equal packets, so equality has to follow the ordering
+impl PartialEq for Element {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Element {}
+
 mod details {
     use std::{iter::Peekable, slice::Iter};
 
@@ -24,6 +105,7 @@ mod details {
         Number(u32),
     }
 
+    #[derive(Debug, Clone)]
     pub enum Element {
         List(Vec<Element>),
         Number(u32),
@@ -54,7 +136,7 @@ mod details {
             }
 
             let mut num_str = String::new();
-            while let Some(digit) = stream.next_if(|c| c.is_digit(10)) {
+            while let Some(digit) = stream.next_if(|c| c.is_ascii_digit()) {
                 num_str.push(digit);
             }
 
@@ -64,23 +146,45 @@ mod details {
         tokens
     }
 
-    enum ParserError {
+    #[derive(Debug)]
+    pub enum ParserError {
         UnexpectedToken(Token),
         EndOfStream,
     }
 
+    impl std::fmt::Display for ParserError {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            match self {
+                ParserError::UnexpectedToken(tok) => write!(f, "unexpected token {:?}", tok),
+                ParserError::EndOfStream => f.write_str("packet ended early"),
+            }
+        }
+    }
+
     type ParserResult = Result<Element, ParserError>;
-    struct Parser<'a> {
+    pub struct Parser<'a> {
         token_stream: &'a mut Peekable<Iter<'a, Token>>,
     }
 
     impl<'a> Parser<'a> {
-        fn new(token_stream: &'a mut Peekable<Iter<'a, Token>>) -> Self {
+        pub fn new(token_stream: &'a mut Peekable<Iter<'a, Token>>) -> Self {
             Self { token_stream }
         }
 
-        fn parse(&mut self) -> Element {
-            todo!()
+        // a packet has to use up every token
+        pub fn parse(&mut self) -> ParserResult {
+            let packet = self.packet()?;
+            match self.token_stream.peek() {
+                Some(_) => Err(self.unexpected()),
+                None => Ok(packet),
+            }
+        }
+
+        fn unexpected(&mut self) -> ParserError {
+            match self.token_stream.peek() {
+                Some(&tok) => ParserError::UnexpectedToken(tok.clone()),
+                None => ParserError::EndOfStream,
+            }
         }
 
         fn list_begin(&mut self) -> bool {
@@ -103,42 +207,42 @@ mod details {
                 .next_if(|&tok| matches!(tok, Token::Number(_)))
             {
                 Some(Token::Number(n)) => Ok(Element::Number(*n)),
-                None => {
-                    if let Some(&tok) = self.token_stream.peek() {
-                        Err(ParserError::UnexpectedToken(tok.clone()))
-                    } else {
-                        Err(ParserError::EndOfStream)
-                    }
-                }
+                None => Err(self.unexpected()),
                 _ => unreachable!(),
             }
         }
 
         fn element(&mut self) -> ParserResult {
-            todo!()
+            match self.token_stream.peek() {
+                Some(Token::OpenBracket) => self.list(),
+                _ => self.num(),
+            }
         }
 
-        fn n_elements(&mut self) -> Vec<ParserResult> {
-            todo!()
+        // a single element counts too, it is just a list of elements without any commas
+        fn n_elements(&mut self) -> Result<Vec<Element>, ParserError> {
+            let mut elements = vec![self.element()?];
+            while self.comma() {
+                elements.push(self.element()?);
+            }
+            Ok(elements)
         }
 
         fn list(&mut self) -> ParserResult {
             if !self.list_begin() {
-                return match self.token_stream.peek() {
-                    Some(&tok) => Err(ParserError::UnexpectedToken(tok.clone())),
-                    None => Err(ParserError::EndOfStream),
-                };
+                return Err(self.unexpected());
             }
 
             // we hit empty list
             if self.list_end() {
-                Ok(Element::List(Vec::new()))
+                return Ok(Element::List(Vec::new()));
+            }
+
+            let elements = self.n_elements()?;
+            if self.list_end() {
+                Ok(Element::List(elements))
             } else {
-                // try to match n_elements, if that fails try to match single element
-                self.n_elements()
-				.map(|v| )
-                    .or_else(|_| self.element())
-                    .map(|e| Element::List(()))
+                Err(self.unexpected())
             }
         }
 
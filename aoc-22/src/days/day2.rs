@@ -19,7 +19,7 @@ impl DayExecutor for Day2 {
     }
 }
 
-fn solve_part1(input: &str) -> u32 {
+pub(crate) fn solve_part1(input: &str) -> u32 {
     input
         .lines()
         .map(|l| {
@@ -31,7 +31,7 @@ fn solve_part1(input: &str) -> u32 {
         .sum()
 }
 
-fn solve_part2(input: &str) -> u32 {
+pub(crate) fn solve_part2(input: &str) -> u32 {
     input
         .lines()
         .map(|l| {
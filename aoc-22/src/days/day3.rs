@@ -29,7 +29,7 @@ fn get_rucksacks_from_input(input: &str) -> Vec<Rucksack> {
         .collect::<Vec<_>>()
 }
 
-fn solve_part1(input: &str) -> u32 {
+pub(crate) fn solve_part1(input: &str) -> u32 {
     let rucksacks = get_rucksacks_from_input(input);
 
     rucksacks
@@ -38,7 +38,7 @@ fn solve_part1(input: &str) -> u32 {
         .sum()
 }
 
-fn solve_part2(input: &str) -> u32 {
+pub(crate) fn solve_part2(input: &str) -> u32 {
     let rucksacks = get_rucksacks_from_input(input);
 
     rucksacks[..]
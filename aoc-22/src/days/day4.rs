@@ -19,7 +19,7 @@ impl DayExecutor for Day4 {
     }
 }
 
-fn solve_part1(input: &str) -> u32 {
+pub(crate) fn solve_part1(input: &str) -> u32 {
     get_elf_pairs_from_input(input)
         .into_iter()
         .fold(0u32, |acc, elem| {
@@ -31,7 +31,7 @@ fn solve_part1(input: &str) -> u32 {
         })
 }
 
-fn solve_part2(input: &str) -> u32 {
+pub(crate) fn solve_part2(input: &str) -> u32 {
     get_elf_pairs_from_input(input)
         .into_iter()
         .filter(|elf_pair| elf_pair.do_assignments_overlap())
@@ -19,7 +19,7 @@ impl DayExecutor for Day5 {
     }
 }
 
-fn solve_part1(input: &str) -> String {
+pub(crate) fn solve_part1(input: &str) -> String {
     let (mut crane, instruction_set) = get_parsed_input(input);
 
     for instr in instruction_set {
@@ -29,7 +29,7 @@ fn solve_part1(input: &str) -> String {
     crane.top_message()
 }
 
-fn solve_part2(input: &str) -> String {
+pub(crate) fn solve_part2(input: &str) -> String {
     let (mut crane, instruction_set) = get_parsed_input(input);
 
     for instr in instruction_set {
@@ -25,13 +25,13 @@ fn solve_part2(input: &str) -> String {
     )
 }
 
-fn find_start_of_packet_offset(input: &str) -> usize {
+pub(crate) fn find_start_of_packet_offset(input: &str) -> usize {
     let data = input.chars().collect::<Vec<_>>();
     const PACKET_SIZE: usize = 4;
     find_unique_offset(data.as_slice(), PACKET_SIZE)
 }
 
-fn find_start_of_message_offset(input: &str) -> usize {
+pub(crate) fn find_start_of_message_offset(input: &str) -> usize {
     let data = input.chars().collect::<Vec<_>>();
     const MESSAGE_SIZE: usize = 14;
     find_unique_offset(data.as_slice(), MESSAGE_SIZE)
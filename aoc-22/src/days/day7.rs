@@ -48,7 +48,7 @@ fn process_input(fs: &mut Filesystem, input: &str) {
     }
 }
 
-fn solve_part1(input: &str) -> usize {
+pub(crate) fn solve_part1(input: &str) -> usize {
     let mut fs = Filesystem::new();
 
     process_input(&mut fs, input);
@@ -59,7 +59,7 @@ fn solve_part1(input: &str) -> usize {
         .sum()
 }
 
-fn solve_part2(input: &str) -> usize {
+pub(crate) fn solve_part2(input: &str) -> usize {
     let mut fs = Filesystem::new();
 
     process_input(&mut fs, input);
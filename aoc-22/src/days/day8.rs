@@ -14,14 +14,14 @@ impl DayExecutor for Day8 {
     }
 }
 
-fn solve_part1(input: &str) -> usize {
+pub(crate) fn solve_part1(input: &str) -> usize {
     let forest = input
         .parse::<Forest>()
         .expect("Failed to parse input as forest");
     forest.count_visible()
 }
 
-fn solve_part2(input: &str) -> usize {
+pub(crate) fn solve_part2(input: &str) -> usize {
     let forest = input
         .parse::<Forest>()
         .expect("Failed to parse input as forest");
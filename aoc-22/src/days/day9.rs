@@ -20,7 +20,7 @@ impl DayExecutor for Day9 {
     }
 }
 
-fn solve_part1(input: &str) -> usize {
+pub(crate) fn solve_part1(input: &str) -> usize {
     let instr_list = get_instructions(input);
     let mut rope = Rope::new(2);
     let mut unique_positions_visited_by_tail: HashSet<Position> = HashSet::new();
@@ -32,7 +32,7 @@ fn solve_part1(input: &str) -> usize {
     unique_positions_visited_by_tail.len()
 }
 
-fn solve_part2(input: &str) -> usize {
+pub(crate) fn solve_part2(input: &str) -> usize {
     let instr_list = get_instructions(input);
     let mut rope = Rope::new(10);
     let mut unique_positions_visited_by_tail: HashSet<Position> = HashSet::new();
@@ -0,0 +1,4 @@
+pub mod cli;
+pub mod day_exec;
+pub mod days;
+pub mod registry;
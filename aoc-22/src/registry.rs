@@ -0,0 +1,100 @@
+use aoc_core::{FnSolution, Registry};
+
+use crate::days::{
+    day1, day10, day11, day12, day13, day2, day3, day4, day5, day6, day7, day8, day9,
+};
+
+pub const YEAR: u16 = 2022;
+
+// day 10 part 2 draws its answer as letters on a screen, so only part 1 has a value to register
+pub fn register(registry: &mut Registry) {
+    registry.register(
+        YEAR,
+        1,
+        FnSolution::raw()
+            .part1(|input| day1::part1_solution(input))
+            .part2(|input| day1::part2_solution(input)),
+    );
+    registry.register(
+        YEAR,
+        2,
+        FnSolution::raw()
+            .part1(|input| day2::solve_part1(input))
+            .part2(|input| day2::solve_part2(input)),
+    );
+    registry.register(
+        YEAR,
+        3,
+        FnSolution::raw()
+            .part1(|input| day3::solve_part1(input))
+            .part2(|input| day3::solve_part2(input)),
+    );
+    registry.register(
+        YEAR,
+        4,
+        FnSolution::raw()
+            .part1(|input| day4::solve_part1(input))
+            .part2(|input| day4::solve_part2(input)),
+    );
+    registry.register(
+        YEAR,
+        5,
+        FnSolution::raw()
+            .part1(|input| day5::solve_part1(input))
+            .part2(|input| day5::solve_part2(input)),
+    );
+    registry.register(
+        YEAR,
+        6,
+        FnSolution::raw()
+            .part1(|input| day6::find_start_of_packet_offset(input))
+            .part2(|input| day6::find_start_of_message_offset(input)),
+    );
+    registry.register(
+        YEAR,
+        7,
+        FnSolution::raw()
+            .part1(|input| day7::solve_part1(input))
+            .part2(|input| day7::solve_part2(input)),
+    );
+    registry.register(
+        YEAR,
+        8,
+        FnSolution::raw()
+            .part1(|input| day8::solve_part1(input))
+            .part2(|input| day8::solve_part2(input)),
+    );
+    registry.register(
+        YEAR,
+        9,
+        FnSolution::raw()
+            .part1(|input| day9::solve_part1(input))
+            .part2(|input| day9::solve_part2(input)),
+    );
+    registry.register(
+        YEAR,
+        10,
+        FnSolution::raw().part1(|input| day10::solve_part1(input)),
+    );
+    registry.register(
+        YEAR,
+        11,
+        FnSolution::raw()
+            .part1(|input| day11::solve_part1(input))
+            .part2(|input| day11::solve_part2(input)),
+    );
+    registry.register(
+        YEAR,
+        12,
+        FnSolution::raw()
+            .part1(|input| day12::solve_part1(input))
+            .part2(|input| day12::solve_part2(input)),
+    );
+    registry.register(
+        YEAR,
+        13,
+        FnSolution::raw()
+            .part1(|input| day13::solve_part1(input))
+            .part2(|input| day13::solve_part2(input)),
+    );
+}
@@ -0,0 +1,648 @@
+use aoc_23::runner::{run_day, DayReport};
+use aoc_core::{ExpectedAnswers, InputResolver, Part, PuzzleInput, Registry};
+use crossterm::event;
+use crossterm::event::DisableBracketedPaste;
+use crossterm::event::DisableMouseCapture;
+use crossterm::event::EnableBracketedPaste;
+use crossterm::event::EnableMouseCapture;
+use crossterm::event::Event;
+use crossterm::event::KeyCode;
+use crossterm::event::KeyModifiers;
+use crossterm::execute;
+use crossterm::terminal::{disable_raw_mode, LeaveAlternateScreen};
+use crossterm::terminal::{enable_raw_mode, EnterAlternateScreen};
+use ratatui::layout::Constraint;
+use ratatui::layout::Direction;
+use ratatui::layout::Layout;
+use ratatui::style::Color;
+use ratatui::style::Style;
+use ratatui::text::Line;
+use ratatui::text::Span;
+use ratatui::text::Text;
+use ratatui::widgets::Block;
+use ratatui::widgets::Borders;
+use ratatui::widgets::List;
+use ratatui::widgets::ListItem;
+use ratatui::widgets::Paragraph;
+use ratatui::Frame;
+use ratatui::{backend::CrosstermBackend, Terminal};
+use std::collections::HashMap;
+use std::io::{self, IsTerminal, Read};
+use std::panic;
+use std::path::Path;
+use std::sync::Arc;
+use std::thread;
+use std::time::Duration;
+
+use aoc_23::ui::history::History;
+use aoc_23::ui::input_pane::{InputPane, InputSource};
+use aoc_23::ui::tree::{self, Node, Results, Status, Tree};
+use aoc_23::ui::worker::{self, Job};
+
+// how long to wait for a key before redrawing, keeps the spinner and timer moving
+const TICK: Duration = Duration::from_millis(100);
+
+const ANSWERS: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/../answers.toml");
+
+fn registry() -> Registry {
+    let mut registry = Registry::new();
+    #[cfg(feature = "year-2018")]
+    solutions::registry::register(&mut registry);
+    #[cfg(feature = "year-2019")]
+    aoc_19::registry::register(&mut registry);
+    #[cfg(feature = "year-2020")]
+    aoc_20::registry::register(&mut registry);
+    #[cfg(feature = "year-2022")]
+    aoc_22::registry::register(&mut registry);
+    aoc_23::registry::register(&mut registry);
+    registry
+}
+
+struct Run {
+    job: Job<DayReport>,
+    input: PuzzleInput,
+    // where the input came from
+    source: String,
+    // only runs on the day's own input are held against the stored answers
+    default_input: bool,
+}
+
+struct App {
+    registry: Arc<Registry>,
+    resolver: Result<InputResolver, String>,
+    expected: ExpectedAnswers,
+    results: Results,
+    tree: Tree,
+    // empty means the day's default input
+    input_path: String,
+    // restored when editing is cancelled
+    saved_input_path: String,
+    editing_input: bool,
+    input: InputPane,
+    running: Option<Run>,
+    history: History,
+    last_exec_out: String,
+}
+
+impl App {
+    fn new(pasted: String) -> Self {
+        let registry = registry();
+        let resolver = aoc_23::input::resolver().map_err(|e| e.to_string());
+        let (expected, last_exec_out) = match ExpectedAnswers::from_file(ANSWERS) {
+            Ok(expected) => (expected, String::new()),
+            Err(e) => (ExpectedAnswers::default(), e.to_string()),
+        };
+
+        let mut app = Self {
+            tree: Tree::new(&registry),
+            registry: Arc::new(registry),
+            resolver,
+            expected,
+            results: HashMap::new(),
+            input_path: String::new(),
+            saved_input_path: String::new(),
+            editing_input: false,
+            input: InputPane::new(pasted),
+            running: None,
+            history: History::default(),
+            last_exec_out,
+        };
+        app.reload_input();
+        app
+    }
+
+    fn reload_input(&mut self) {
+        let id = self.tree.selected().id();
+        self.input.reload(id, &self.input_path, &self.resolver);
+    }
+
+    fn move_selection_down(&mut self) {
+        let before = self.tree.selected().id();
+        self.tree.move_down(&self.registry);
+        if self.tree.selected().id() != before {
+            self.reload_input();
+        }
+    }
+
+    fn move_selection_up(&mut self) {
+        let before = self.tree.selected().id();
+        self.tree.move_up(&self.registry);
+        if self.tree.selected().id() != before {
+            self.reload_input();
+        }
+    }
+
+    fn collapse_selected(&mut self) {
+        let before = self.tree.selected().id();
+        self.tree.collapse();
+        if self.tree.selected().id() != before {
+            self.reload_input();
+        }
+    }
+
+    fn start_filter(&mut self) {
+        self.tree.filtering = true;
+    }
+
+    fn finish_filter(&mut self) {
+        self.tree.filtering = false;
+        self.keep_selection_visible();
+    }
+
+    fn clear_filter(&mut self) {
+        self.tree.filter.clear();
+        self.tree.filtering = false;
+        self.keep_selection_visible();
+    }
+
+    fn keep_selection_visible(&mut self) {
+        let before = self.tree.selected().id();
+        let rows = self.tree.rows(&self.registry);
+        self.tree.selected_index(&rows);
+        if self.tree.selected().id() != before {
+            self.reload_input();
+        }
+    }
+
+    fn toggle_input_source(&mut self) {
+        self.input.toggle_source();
+        self.reload_input();
+    }
+
+    fn finish_paste(&mut self) {
+        self.input.finish_paste();
+        self.reload_input();
+    }
+
+    fn start_input_edit(&mut self) {
+        self.saved_input_path = self.input_path.clone();
+        self.editing_input = true;
+    }
+
+    fn finish_input_edit(&mut self) {
+        self.editing_input = false;
+        self.input.source = InputSource::File;
+        self.reload_input();
+    }
+
+    fn cancel_input_edit(&mut self) {
+        self.input_path = std::mem::take(&mut self.saved_input_path);
+        self.editing_input = false;
+    }
+
+    // a year only opens or closes, a day runs every part it implements
+    fn exec_selected(&mut self) {
+        if self.running.is_some() {
+            return;
+        }
+
+        let (id, parts) = match self.tree.selected() {
+            Node::Year(_) => {
+                self.tree.toggle();
+                return;
+            }
+            Node::Day(id) => {
+                let Some(solution) = self.registry.get(id) else {
+                    return;
+                };
+                let parts = Part::ALL
+                    .into_iter()
+                    .filter(|&part| solution.implements(part))
+                    .collect::<Vec<_>>();
+                (id, parts)
+            }
+            Node::Part(id, part) => (id, vec![part]),
+        };
+        let input = match &self.input.loaded {
+            Ok(input) => input.clone(),
+            Err(e) => {
+                self.last_exec_out = e.clone();
+                return;
+            }
+        };
+        let source = match input.path() {
+            Some(path) => path.display().to_string(),
+            None => self.input.source.label().to_string(),
+        };
+
+        let registry = Arc::clone(&self.registry);
+        let job_input = input.clone();
+        let job = Job::spawn(id.to_string(), move || {
+            run_day(&registry, id, &parts, &job_input)
+        });
+        self.running = Some(Run {
+            job,
+            input,
+            source,
+            default_input: self.input.source == InputSource::File && self.input_path.is_empty(),
+        });
+    }
+
+    // the thread of an abandoned run keeps going, its result is simply never shown
+    fn abandon_run(&mut self) {
+        if let Some(Run { job, .. }) = self.running.take() {
+            self.last_exec_out = format!("Abandoned {} after {:.1?}", job.label, job.elapsed());
+        }
+    }
+
+    fn collect_finished_run(&mut self) {
+        let Some(out) = self.running.as_ref().and_then(|run| run.job.try_finish()) else {
+            return;
+        };
+        let run = self.running.take().expect("a finished job was running");
+
+        self.last_exec_out = match out {
+            Ok(report) => {
+                if run.default_input {
+                    for part_run in &report.parts {
+                        self.results
+                            .insert((report.id, part_run.part), part_run.result.clone());
+                    }
+                }
+                self.history.record(&report, &run.input, &run.source);
+                format!("{}\nInput: {}\n\n{report}", report.id, run.source)
+            }
+            Err(e) => format!("{}\n\n{e}", run.job.label),
+        };
+    }
+
+    fn status(&self, node: Node) -> Option<Status> {
+        tree::status(node, &self.registry, &self.expected, &self.results)
+    }
+
+    fn output(&self) -> String {
+        match &self.running {
+            Some(Run { job, .. }) => format!(
+                "{} Running {}... {:.1}s\n\nEsc abandons the run",
+                job.spinner(),
+                job.label,
+                job.elapsed().as_secs_f64()
+            ),
+            None => self.last_exec_out.clone(),
+        }
+    }
+}
+
+type MyTerminal = Terminal<CrosstermBackend<io::Stdout>>;
+
+fn setup() -> MyTerminal {
+    enable_raw_mode().expect("raw mode enabled");
+    let mut stdout = io::stdout();
+    execute!(
+        stdout,
+        EnterAlternateScreen,
+        EnableMouseCapture,
+        EnableBracketedPaste
+    )
+    .expect("entered alternate screen");
+
+    let backend = CrosstermBackend::new(stdout);
+    Terminal::new(backend).expect("terminal with crossterm backend created")
+}
+
+fn teardown(mut terminal: MyTerminal) {
+    disable_raw_mode().expect("disable raw mode");
+    execute!(
+        terminal.backend_mut(),
+        LeaveAlternateScreen,
+        DisableMouseCapture,
+        DisableBracketedPaste
+    )
+    .expect("leave alternate screen");
+    terminal.show_cursor().expect("terminal show cursor");
+}
+
+fn run_app(term: &mut MyTerminal, app: &mut App) {
+    loop {
+        term.draw(|f| ui(f, app)).expect("terminal to draw frame");
+        if event_loop(app) {
+            break;
+        }
+    }
+}
+
+fn ui(f: &mut Frame, app: &App) {
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(3), // list of days
+            Constraint::Min(1),    // exec output
+            Constraint::Length(3), // input file
+        ])
+        .split(f.size());
+
+    let title_rect = chunks[0];
+    let content_rect = chunks[1];
+    let input_rect = chunks[2];
+
+    let content_chunks = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([
+            Constraint::Length(40),     // tree of days
+            Constraint::Percentage(40), // exec output
+            Constraint::Min(1),         // input viewer
+        ])
+        .split(content_rect);
+    let list_rect = content_chunks[0];
+    let output_chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Percentage(40), // exec output
+            Constraint::Min(3),         // run history
+        ])
+        .split(content_chunks[1]);
+    let last_exec_rect = output_chunks[0];
+    let history_rect = output_chunks[1];
+    let input_view_rect = content_chunks[2];
+
+    let title_block = Block::default()
+        .borders(Borders::ALL)
+        .style(Style::default());
+
+    let title = Paragraph::new(Text::styled(
+        "Advent of Code Day Execution Picker",
+        Style::default().fg(Color::Green),
+    ))
+    .block(title_block);
+
+    f.render_widget(title, title_rect);
+
+    let list_title = if app.tree.filtering {
+        format!("Filter: {}_ (Enter keeps, Esc clears)", app.tree.filter)
+    } else if !app.tree.filter.is_empty() {
+        format!("Filter: {} (/ edits)", app.tree.filter)
+    } else {
+        String::from("Days (/ filters)")
+    };
+    let list_block = Block::default()
+        .borders(Borders::ALL)
+        .title(list_title)
+        .style(Style::default());
+
+    let rows = app.tree.rows(&app.registry);
+    let selected = rows
+        .iter()
+        .position(|row| row.node == app.tree.selected())
+        .unwrap_or_default();
+    let mut list_items = Vec::<ListItem>::new();
+
+    for (idx, row) in rows.iter().enumerate() {
+        let row_style = if idx == selected {
+            Style::default().fg(Color::Yellow).bg(Color::Magenta)
+        } else {
+            Style::default().fg(Color::Yellow)
+        };
+        let fold = match row.expanded {
+            Some(true) => "▾ ",
+            Some(false) => "▸ ",
+            None => "  ",
+        };
+        let (marker, marker_color) = match app.status(row.node) {
+            Some(status @ Status::Solved) => (status.marker(), Color::Green),
+            Some(status @ Status::Unverified) => (status.marker(), Color::Yellow),
+            Some(status @ Status::Failing) => (status.marker(), Color::Red),
+            None => (' ', Color::Reset),
+        };
+
+        list_items.push(ListItem::new(Line::from(vec![
+            Span::styled(format!("{marker} "), Style::default().fg(marker_color)),
+            Span::styled(
+                format!("{}{fold}{}", "  ".repeat(row.depth), row.label),
+                row_style,
+            ),
+        ])));
+    }
+
+    // keep the selection on screen once the tree is taller than the pane
+    let visible = list_rect.height.saturating_sub(2) as usize;
+    let skip = (selected + 1).saturating_sub(visible);
+    let list = List::new(list_items.split_off(skip)).block(list_block);
+
+    f.render_widget(list, list_rect);
+
+    let last_exec_block = Block::default()
+        .borders(Borders::ALL)
+        .style(Style::default());
+
+    let last_exec_content = Paragraph::new(Text::styled(
+        app.output(),
+        Style::default().fg(Color::Green),
+    ))
+    .block(last_exec_block);
+
+    f.render_widget(last_exec_content, last_exec_rect);
+
+    let history_title = match app.history.scroll {
+        0 => String::from("History ([ older, ] newer)"),
+        scroll => format!("History, {scroll} newer hidden ([ older, ] newer)"),
+    };
+    let history_lines = app
+        .history
+        .visible(history_rect.height.saturating_sub(2) as usize)
+        .iter()
+        .map(|entry| {
+            // the file name is enough to tell inputs apart in the narrow pane
+            let source = Path::new(&entry.source)
+                .file_name()
+                .map_or(entry.source.as_str(), |name| {
+                    name.to_str().unwrap_or_default()
+                });
+            let line = format!(
+                "{} p{}: {} ({:.1?}, {})",
+                entry.id,
+                entry.part.number(),
+                entry.answer.replace('\n', " "),
+                entry.elapsed,
+                source
+            );
+            match &entry.changed_from {
+                Some(before) => Line::from(Span::styled(
+                    format!("{line} (was {})", before.replace('\n', " ")),
+                    Style::default().fg(Color::Black).bg(Color::Red),
+                )),
+                None => Line::from(line),
+            }
+        })
+        .collect::<Vec<_>>();
+    let history = Paragraph::new(history_lines)
+        .block(Block::default().borders(Borders::ALL).title(history_title));
+
+    f.render_widget(history, history_rect);
+
+    let input_view_title = if app.input.pasting {
+        String::from("Pasting input (paste or type, Esc when done)")
+    } else {
+        format!(
+            "{} line {}/{} (t toggles, p pastes, PgUp/PgDn scroll)",
+            app.input.source.label(),
+            app.input.scroll as usize + 1,
+            app.input.line_count()
+        )
+    };
+    let input_view_text = if app.input.pasting {
+        app.input.pasted.clone()
+    } else {
+        app.input.text().to_string()
+    };
+    let input_view_style = if app.input.loaded.is_err() && !app.input.pasting {
+        Style::default().fg(Color::Red)
+    } else {
+        Style::default()
+    };
+    let input_view = Paragraph::new(Text::styled(input_view_text, input_view_style))
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title(input_view_title),
+        )
+        .scroll((
+            if app.input.pasting {
+                0
+            } else {
+                app.input.scroll
+            },
+            0,
+        ));
+
+    f.render_widget(input_view, input_view_rect);
+
+    let (input_title, input_style) = if app.editing_input {
+        (
+            "Input file (Enter to confirm, Esc to cancel)",
+            Style::default().fg(Color::Yellow),
+        )
+    } else {
+        (
+            "Input file (i to edit, Enter runs the selection, arrows move and fold, Esc abandons a run, q quits)",
+            Style::default(),
+        )
+    };
+    let input_text = if app.input_path.is_empty() && !app.editing_input {
+        String::from("<default input of the day>")
+    } else {
+        app.input_path.clone()
+    };
+    let input_block = Block::default()
+        .borders(Borders::ALL)
+        .title(input_title)
+        .style(input_style);
+    let input_line = Paragraph::new(Text::raw(input_text)).block(input_block);
+
+    f.render_widget(input_line, input_rect);
+}
+
+fn event_loop(app: &mut App) -> bool {
+    app.collect_finished_run();
+
+    if !event::poll(TICK).expect("to poll term event") {
+        return false;
+    }
+
+    let key = match event::read().expect("to read term event") {
+        Event::Key(key) => key,
+        Event::Paste(text) if app.input.pasting => {
+            app.input.paste(&text);
+            return false;
+        }
+        _ => return false,
+    };
+
+    if key.kind == event::KeyEventKind::Release {
+        // Skip events that are not KeyEventKind::Press
+        return false;
+    }
+
+    if app.input.pasting {
+        match key.code {
+            KeyCode::Esc => app.finish_paste(),
+            KeyCode::Enter => app.input.paste("\n"),
+            KeyCode::Backspace => {
+                app.input.pasted.pop();
+            }
+            KeyCode::Char('d') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                app.finish_paste()
+            }
+            KeyCode::Char(c) => app.input.pasted.push(c),
+            _ => {}
+        }
+        return false;
+    }
+
+    if app.tree.filtering {
+        match key.code {
+            KeyCode::Enter => app.finish_filter(),
+            KeyCode::Esc => app.clear_filter(),
+            KeyCode::Backspace => {
+                app.tree.filter.pop();
+            }
+            KeyCode::Char(c) => app.tree.filter.push(c),
+            _ => {}
+        }
+        app.keep_selection_visible();
+        return false;
+    }
+
+    if app.editing_input {
+        match key.code {
+            KeyCode::Enter => app.finish_input_edit(),
+            KeyCode::Esc => app.cancel_input_edit(),
+            KeyCode::Backspace => {
+                app.input_path.pop();
+            }
+            KeyCode::Char(c) => app.input_path.push(c),
+            _ => {}
+        }
+        return false;
+    }
+
+    match key.code {
+        KeyCode::Char('i') => app.start_input_edit(),
+        KeyCode::Char('t') => app.toggle_input_source(),
+        KeyCode::Char('p') => app.input.start_paste(),
+        KeyCode::PageDown => app.input.scroll_down(10),
+        KeyCode::PageUp => app.input.scroll_up(10),
+        KeyCode::Char('j') => app.input.scroll_down(1),
+        KeyCode::Char('k') => app.input.scroll_up(1),
+        KeyCode::Char('q') => {
+            return true;
+        }
+        KeyCode::Esc => app.abandon_run(),
+        KeyCode::Char('/') => app.start_filter(),
+        KeyCode::Char('[') => app.history.scroll_back(1),
+        KeyCode::Char(']') => app.history.scroll_forward(1),
+        KeyCode::Down => app.move_selection_down(),
+        KeyCode::Up => app.move_selection_up(),
+        KeyCode::Right => app.tree.expand(),
+        KeyCode::Left => app.collapse_selected(),
+        KeyCode::Char(' ') => app.tree.toggle(),
+        KeyCode::Enter => app.exec_selected(),
+        _ => {}
+    }
+
+    false
+}
+
+fn main() {
+    // a panicking day is reported in the output pane, printing it would garble the screen
+    let default_hook = panic::take_hook();
+    panic::set_hook(Box::new(move |info| {
+        if thread::current().name() != Some(worker::THREAD_NAME) {
+            default_hook(info);
+        }
+    }));
+
+    // input piped into the picker becomes the pasted input, keys still come from the terminal
+    let mut pasted = String::new();
+    if !io::stdin().is_terminal() {
+        io::stdin()
+            .read_to_string(&mut pasted)
+            .expect("to read piped input");
+    }
+
+    let mut term = setup();
+
+    let mut app = App::new(pasted);
+    run_app(&mut term, &mut app);
+
+    teardown(term);
+}
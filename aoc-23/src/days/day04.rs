@@ -0,0 +1,41 @@
+use std::fmt::Display;
+
+pub const EXAMPLE: &str = r#"Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53
+Card 2: 13 32 20 16 61 | 61 30 68 82 17 30 19 70
+Card 3:  1 21 53 59 44 | 69 82 63 72 16 21 14  1
+Card 4: 41 92 73 84 69 | 59 84 76 51 58  5 54 83
+Card 5: 87 83 26 28 32 | 88 30 70 12  3 22 82 36
+Card 6: 31 18 13 56 72 | 74 77 10 23 35 67 36 11"#;
+
+pub fn day4_exec(input: &str) -> Result<String, Day4Err> {
+    let p1_res = part1(input)?;
+    let p2_res = part2(input)?;
+
+    Ok(format!("{p1_res}\n{p2_res}"))
+}
+
+pub fn part1(_input: &str) -> Result<String, Day4Err> {
+    Ok("Part 1 not implemented yet".to_string())
+}
+
+pub fn part2(_input: &str) -> Result<String, Day4Err> {
+    Ok("Part 2 not implemented yet".to_string())
+}
+
+#[derive(Debug, Clone)]
+pub struct Day4Err {
+    msg: String,
+}
+
+impl Display for Day4Err {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "ERROR:Day 04: {msg}", msg = self.msg)
+    }
+}
+
+impl Day4Err {
+    pub fn new(s: impl AsRef<str>) -> Self {
+        let s = s.as_ref();
+        Self { msg: s.to_string() }
+    }
+}
@@ -0,0 +1,129 @@
+use std::{
+    fmt::Display,
+    path::{Path, PathBuf},
+};
+
+use aoc_core::{InputError, InputResolver, PuzzleId, PuzzleInput};
+
+use crate::registry::YEAR;
+
+const MANIFEST_DIR: &str = env!("CARGO_MANIFEST_DIR");
+
+pub const USAGE: &str = "Usage: day-NN [PATH | - | --example]
+
+    PATH             read puzzle input from a file
+    -                read puzzle input from stdin
+    --example        use the example from the puzzle description
+
+Without arguments the input is looked up like `aoc run` does, falling back to src/bin/inputN.txt";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InputArg {
+    Default,
+    Example,
+    Stdin,
+    Path(PathBuf),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InputArgError {
+    UnknownArgument(String),
+    TooManyArguments,
+    Input(String),
+}
+
+impl Display for InputArgError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            InputArgError::UnknownArgument(arg) => write!(f, "Unknown argument '{arg}'\n\n{USAGE}"),
+            InputArgError::TooManyArguments => write!(f, "Only one input can be given\n\n{USAGE}"),
+            InputArgError::Input(msg) => f.write_str(msg),
+        }
+    }
+}
+
+impl std::error::Error for InputArgError {}
+
+pub fn parse_input_arg(args: impl IntoIterator<Item = String>) -> Result<InputArg, InputArgError> {
+    let mut args = args.into_iter();
+    let arg = match args.next() {
+        Some(arg) => arg,
+        None => return Ok(InputArg::Default),
+    };
+    if args.next().is_some() {
+        return Err(InputArgError::TooManyArguments);
+    }
+
+    match arg.as_str() {
+        "--example" | "-e" => Ok(InputArg::Example),
+        "-" => Ok(InputArg::Stdin),
+        _ if arg.starts_with('-') => Err(InputArgError::UnknownArgument(arg)),
+        _ => Ok(InputArg::Path(PathBuf::from(arg))),
+    }
+}
+
+// what every day binary does with its command line, `example` is the day's EXAMPLE
+pub fn input_from_args(day: u8, example: &str) -> Result<String, InputArgError> {
+    match parse_input_arg(std::env::args().skip(1))? {
+        InputArg::Default => default_input(day)
+            .map(|input| input.text().to_string())
+            .map_err(|e| InputArgError::Input(e.to_string())),
+        InputArg::Example => Ok(example.to_string()),
+        InputArg::Stdin => PuzzleInput::from_stdin()
+            .map(|input| input.text().to_string())
+            .map_err(|e| InputArgError::Input(format!("Could not read input from stdin: {e}"))),
+        InputArg::Path(path) => std::fs::read_to_string(&path).map_err(|e| {
+            InputArgError::Input(format!("Could not read input '{}': {e}", path.display()))
+        }),
+    }
+}
+
+pub fn default_input(day: u8) -> Result<PuzzleInput, InputError> {
+    resolver()?.resolve(PuzzleId::new(YEAR, day))
+}
+
+// the same lookup as `aoc run`, for every year the ui can list
+pub fn resolver() -> Result<InputResolver, InputError> {
+    let repo_root = Path::new(MANIFEST_DIR).join("..");
+    Ok(
+        InputResolver::configured(&repo_root.join("aoc.toml"), repo_root.join("input"))?
+            .with_fallback(legacy_input),
+    )
+}
+
+// where every year crate kept its inputs before they had a common root
+fn legacy_input(id: PuzzleId) -> Option<PathBuf> {
+    let repo_root = Path::new(MANIFEST_DIR).join("..");
+    let day = id.day;
+
+    match id.year {
+        2018 => Some(repo_root.join(format!("aoc-18/solutions/input/day{day}"))),
+        2019 => Some(repo_root.join(format!("aoc-19/input/day{day}.txt"))),
+        2020 => Some(repo_root.join(format!("aoc-20/input/day_{day:02}.input.txt"))),
+        2022 => Some(repo_root.join(format!("aoc-22/input/day{day:02}.part1.part2.txt"))),
+        YEAR => Some(Path::new(MANIFEST_DIR).join(format!("src/bin/input{day}.txt"))),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(line: &str) -> Result<InputArg, InputArgError> {
+        parse_input_arg(line.split_whitespace().map(str::to_owned))
+    }
+
+    #[test]
+    fn input_can_come_from_path_stdin_or_example() {
+        assert_eq!(Ok(InputArg::Default), parse(""));
+        assert_eq!(Ok(InputArg::Example), parse("--example"));
+        assert_eq!(Ok(InputArg::Stdin), parse("-"));
+        assert_eq!(Ok(InputArg::Path(PathBuf::from("my.txt"))), parse("my.txt"));
+        assert_eq!(
+            Err(InputArgError::UnknownArgument("--x".into())),
+            parse("--x")
+        );
+        assert_eq!(Err(InputArgError::TooManyArguments), parse("a.txt b.txt"));
+    }
+}
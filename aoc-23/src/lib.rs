@@ -0,0 +1,6 @@
+pub mod days;
+pub mod input;
+pub mod registry;
+pub mod runner;
+pub mod titles;
+pub mod ui;
@@ -0,0 +1,30 @@
+use aoc_core::{FnSolution, Registry};
+
+use crate::days::{day01, day02, day03};
+
+pub const YEAR: u16 = 2023;
+
+// day 04 has no solution yet, so there is nothing to register for it
+pub fn register(registry: &mut Registry) {
+    registry.register(
+        YEAR,
+        1,
+        FnSolution::raw()
+            .part1(|input| day01::part1(input))
+            .part2(|input| day01::part2(input)),
+    );
+    registry.register(
+        YEAR,
+        2,
+        FnSolution::raw()
+            .try_part1(|input| day02::part1(input))
+            .try_part2(|input| day02::part2(input)),
+    );
+    registry.register(
+        YEAR,
+        3,
+        FnSolution::raw()
+            .try_part1(|input| day03::part1(input))
+            .try_part2(|input| day03::part2(input)),
+    );
+}
@@ -0,0 +1,150 @@
+use std::{
+    fmt::Display,
+    time::{Duration, Instant},
+};
+
+use aoc_core::{Answer, Part, PuzzleId, PuzzleInput, Registry, SolutionError};
+
+use crate::days::{day01, day02, day03, day04};
+
+pub struct DayInfo {
+    pub day: u8,
+    pub title: &'static str,
+    pub example: &'static str,
+}
+
+pub const DAYS: [DayInfo; 4] = [
+    DayInfo {
+        day: 1,
+        title: "Trebuchet?!",
+        example: day01::EXAMPLE,
+    },
+    DayInfo {
+        day: 2,
+        title: "Cube Conundrum",
+        example: day02::EXAMPLE,
+    },
+    DayInfo {
+        day: 3,
+        title: "Gear Ratios",
+        example: day03::EXAMPLE,
+    },
+    DayInfo {
+        day: 4,
+        title: "Scratchcards",
+        example: day04::EXAMPLE,
+    },
+];
+
+pub struct PartRun {
+    pub part: Part,
+    pub result: Result<Answer, SolutionError>,
+    // the shared parsing is only counted in the day's elapsed time
+    pub elapsed: Duration,
+}
+
+pub struct DayReport {
+    pub id: PuzzleId,
+    pub parts: Vec<PartRun>,
+    pub elapsed: Duration,
+}
+
+impl Display for DayReport {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for run in &self.parts {
+            match &run.result {
+                Ok(answer) => writeln!(f, "Part {}: {answer}", run.part)?,
+                Err(e) => writeln!(f, "Part {}: {e}", run.part)?,
+            }
+        }
+        write!(f, "Elapsed: {:.1?}", self.elapsed)
+    }
+}
+
+// a day without a registered solution reports the asked parts as not implemented
+pub fn run_day(
+    registry: &Registry,
+    id: PuzzleId,
+    parts: &[Part],
+    input: &PuzzleInput,
+) -> DayReport {
+    let start = Instant::now();
+
+    let parts = match registry.get(id) {
+        None => parts
+            .iter()
+            .map(|&part| PartRun {
+                part,
+                result: Err(SolutionError::NotImplemented),
+                elapsed: Duration::ZERO,
+            })
+            .collect(),
+        Some(solution) => match solution.parse_any(input) {
+            Ok(parsed) => parts
+                .iter()
+                .map(|&part| {
+                    let part_start = Instant::now();
+                    let result = solution.solve_any(part, parsed.as_ref());
+                    PartRun {
+                        part,
+                        result,
+                        elapsed: part_start.elapsed(),
+                    }
+                })
+                .collect(),
+            Err(e) => parts
+                .iter()
+                .map(|&part| PartRun {
+                    part,
+                    result: Err(e.clone()),
+                    elapsed: Duration::ZERO,
+                })
+                .collect(),
+        },
+    };
+
+    DayReport {
+        id,
+        parts,
+        elapsed: start.elapsed(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn report_has_both_parts_and_errors() {
+        let mut registry = Registry::new();
+        crate::registry::register(&mut registry);
+
+        let id = PuzzleId::new(2023, 2);
+        let report = run_day(
+            &registry,
+            id,
+            &Part::ALL,
+            &PuzzleInput::from_text(day02::EXAMPLE),
+        );
+        assert!(report
+            .to_string()
+            .starts_with("Part 1: 8\nPart 2: 2286\nElapsed: "));
+
+        let report = run_day(
+            &registry,
+            id,
+            &[Part::Two],
+            &PuzzleInput::from_text("Game x"),
+        );
+        assert_eq!(1, report.parts.len());
+        assert!(report.parts.iter().all(|run| run.result.is_err()));
+
+        let report = run_day(
+            &registry,
+            PuzzleId::new(2023, 4),
+            &Part::ALL,
+            &PuzzleInput::from_text(day04::EXAMPLE),
+        );
+        assert_eq!(Err(SolutionError::NotImplemented), report.parts[0].result);
+    }
+}
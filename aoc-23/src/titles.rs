@@ -0,0 +1,96 @@
+use aoc_core::PuzzleId;
+
+use crate::runner::DAYS;
+
+const TITLES_2018: [&str; 12] = [
+    "Chronal Calibration",
+    "Inventory Management System",
+    "No Matter How You Slice It",
+    "Repose Record",
+    "Alchemical Reduction",
+    "Chronal Coordinates",
+    "The Sum of Its Parts",
+    "Memory Maneuver",
+    "Marble Mania",
+    "The Stars Align",
+    "Chronal Charge",
+    "Subterranean Sustainability",
+];
+
+const TITLES_2019: [&str; 12] = [
+    "The Tyranny of the Rocket Equation",
+    "1202 Program Alarm",
+    "Crossed Wires",
+    "Secure Container",
+    "Sunny with a Chance of Asteroids",
+    "Universal Orbit Map",
+    "Amplification Circuit",
+    "Space Image Format",
+    "Sensor Boost",
+    "Monitoring Station",
+    "Space Police",
+    "The N-Body Problem",
+];
+
+const TITLES_2020: [&str; 25] = [
+    "Report Repair",
+    "Password Philosophy",
+    "Toboggan Trajectory",
+    "Passport Processing",
+    "Binary Boarding",
+    "Custom Customs",
+    "Handy Haversacks",
+    "Handheld Halting",
+    "Encoding Error",
+    "Adapter Array",
+    "Seating System",
+    "Rain Risk",
+    "Shuttle Search",
+    "Docking Data",
+    "Rambunctious Recitation",
+    "Ticket Translation",
+    "Conway Cubes",
+    "Operation Order",
+    "Monster Messages",
+    "Jurassic Jigsaw",
+    "Allergen Assessment",
+    "Crab Combat",
+    "Crab Cups",
+    "Lobby Layout",
+    "Combo Breaker",
+];
+
+const TITLES_2022: [&str; 13] = [
+    "Calorie Counting",
+    "Rock Paper Scissors",
+    "Rucksack Reorganization",
+    "Camp Cleanup",
+    "Supply Stacks",
+    "Tuning Trouble",
+    "No Space Left On Device",
+    "Treetop Tree House",
+    "Rope Bridge",
+    "Cathode-Ray Tube",
+    "Monkey in the Middle",
+    "Hill Climbing Algorithm",
+    "Distress Signal",
+];
+
+// puzzle names as on the Advent of Code site, only for days solved somewhere in the repository
+pub fn title(id: PuzzleId) -> Option<&'static str> {
+    let titles: &[&str] = match id.year {
+        2018 => &TITLES_2018,
+        2019 => &TITLES_2019,
+        2020 => &TITLES_2020,
+        2022 => &TITLES_2022,
+        2023 => {
+            return DAYS
+                .iter()
+                .find(|info| info.day == id.day)
+                .map(|info| info.title)
+        }
+        _ => return None,
+    };
+
+    titles.get(usize::from(id.day).checked_sub(1)?).copied()
+}
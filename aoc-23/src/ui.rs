@@ -0,0 +1,4 @@
+pub mod history;
+pub mod input_pane;
+pub mod tree;
+pub mod worker;
@@ -0,0 +1,184 @@
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+use std::time::Duration;
+
+use aoc_core::{Part, PuzzleId, PuzzleInput};
+
+use crate::runner::DayReport;
+
+pub struct Entry {
+    pub id: PuzzleId,
+    pub part: Part,
+    // where the input came from, for display only
+    pub source: String,
+    pub answer: String,
+    pub elapsed: Duration,
+    // the differing answer of the previous run on the same input
+    pub changed_from: Option<String>,
+    input_hash: u64,
+}
+
+// every run of the session, oldest first
+#[derive(Default)]
+pub struct History {
+    pub entries: Vec<Entry>,
+    // entries hidden below the view, zero follows the newest run
+    pub scroll: usize,
+}
+
+impl History {
+    // inputs are compared by content, an edited file counts as a new input
+    pub fn record(&mut self, report: &DayReport, input: &PuzzleInput, source: &str) {
+        let mut hasher = DefaultHasher::new();
+        input.text().hash(&mut hasher);
+        let input_hash = hasher.finish();
+
+        for run in &report.parts {
+            let answer = match &run.result {
+                Ok(answer) => answer.to_string(),
+                Err(e) => e.to_string(),
+            };
+            let changed_from = self
+                .entries
+                .iter()
+                .rev()
+                .find(|entry| {
+                    entry.id == report.id
+                        && entry.part == run.part
+                        && entry.input_hash == input_hash
+                })
+                .filter(|entry| entry.answer != answer)
+                .map(|entry| entry.answer.clone());
+
+            self.entries.push(Entry {
+                id: report.id,
+                part: run.part,
+                source: source.to_string(),
+                answer,
+                elapsed: run.elapsed,
+                changed_from,
+                input_hash,
+            });
+        }
+
+        // a new run brings the view back to the newest entry
+        self.scroll = 0;
+    }
+
+    pub fn scroll_back(&mut self, lines: usize) {
+        self.scroll = (self.scroll + lines).min(self.entries.len().saturating_sub(1));
+    }
+
+    pub fn scroll_forward(&mut self, lines: usize) {
+        self.scroll = self.scroll.saturating_sub(lines);
+    }
+
+    // the newest entries that fit in `height` lines, after skipping the scrolled ones
+    pub fn visible(&self, height: usize) -> &[Entry] {
+        let end = self.entries.len() - self.scroll.min(self.entries.len());
+        &self.entries[end.saturating_sub(height)..end]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_core::Answer;
+
+    use super::*;
+    use crate::runner::PartRun;
+
+    fn report(day: u8, answers: &[(Part, &str)]) -> DayReport {
+        DayReport {
+            id: PuzzleId::new(2023, day),
+            parts: answers
+                .iter()
+                .map(|&(part, answer)| PartRun {
+                    part,
+                    result: Ok(Answer::new(answer)),
+                    elapsed: Duration::ZERO,
+                })
+                .collect(),
+            elapsed: Duration::ZERO,
+        }
+    }
+
+    fn changes(history: &History) -> Vec<Option<&str>> {
+        history
+            .entries
+            .iter()
+            .map(|entry| entry.changed_from.as_deref())
+            .collect()
+    }
+
+    #[test]
+    fn changed_answers_on_the_same_input_are_flagged() {
+        let input = PuzzleInput::from_text("1abc2");
+        let mut history = History::default();
+
+        history.record(
+            &report(1, &[(Part::One, "12"), (Part::Two, "3")]),
+            &input,
+            "",
+        );
+        history.record(
+            &report(1, &[(Part::One, "12"), (Part::Two, "4")]),
+            &input,
+            "",
+        );
+        assert_eq!(vec![None, None, None, Some("3")], changes(&history));
+
+        // only the latest answer counts, going back to an earlier one is a change as well
+        history.record(&report(1, &[(Part::Two, "3")]), &input, "");
+        assert_eq!(Some("4"), changes(&history)[4]);
+    }
+
+    #[test]
+    fn other_inputs_and_days_are_not_compared() {
+        let mut history = History::default();
+        history.record(
+            &report(1, &[(Part::One, "12")]),
+            &PuzzleInput::from_text("1abc2"),
+            "",
+        );
+        history.record(
+            &report(1, &[(Part::One, "77")]),
+            &PuzzleInput::from_text("7abc7"),
+            "",
+        );
+        history.record(
+            &report(2, &[(Part::One, "8")]),
+            &PuzzleInput::from_text("1abc2"),
+            "",
+        );
+        assert_eq!(vec![None, None, None], changes(&history));
+    }
+
+    fn answers(history: &History, height: usize) -> Vec<&str> {
+        history
+            .visible(height)
+            .iter()
+            .map(|entry| entry.answer.as_str())
+            .collect()
+    }
+
+    #[test]
+    fn scrolling_stays_within_the_entries() {
+        let input = PuzzleInput::from_text("");
+        let mut history = History::default();
+        for answer in ["1", "2", "3", "4"] {
+            history.record(&report(1, &[(Part::One, answer)]), &input, "");
+        }
+
+        assert_eq!(vec!["3", "4"], answers(&history, 2));
+
+        history.scroll_back(10);
+        assert_eq!(3, history.scroll);
+        assert_eq!(vec!["1"], answers(&history, 2));
+
+        history.scroll_forward(2);
+        assert_eq!(vec!["2", "3"], answers(&history, 2));
+
+        history.record(&report(1, &[(Part::One, "5")]), &input, "");
+        assert_eq!(vec!["4", "5"], answers(&history, 2));
+    }
+}
@@ -0,0 +1,196 @@
+use aoc_core::{InputResolver, PuzzleId, PuzzleInput};
+
+use crate::registry::YEAR;
+use crate::runner::DAYS;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InputSource {
+    File,
+    Example,
+    Pasted,
+}
+
+impl InputSource {
+    pub fn label(&self) -> &'static str {
+        match self {
+            InputSource::File => "input file",
+            InputSource::Example => "example",
+            InputSource::Pasted => "pasted input",
+        }
+    }
+}
+
+// the input the selected day will be run on, loaded ahead so it can be looked at first
+pub struct InputPane {
+    pub source: InputSource,
+    pub pasted: String,
+    pub pasting: bool,
+    pub loaded: Result<PuzzleInput, String>,
+    pub scroll: u16,
+}
+
+impl InputPane {
+    pub fn new(pasted: String) -> Self {
+        let source = if pasted.is_empty() {
+            InputSource::File
+        } else {
+            InputSource::Pasted
+        };
+
+        Self {
+            source,
+            pasted,
+            pasting: false,
+            loaded: Err(String::new()),
+            scroll: 0,
+        }
+    }
+
+    // an empty path means the day's default input
+    pub fn reload(
+        &mut self,
+        id: Option<PuzzleId>,
+        path: &str,
+        resolver: &Result<InputResolver, String>,
+    ) {
+        let Some(id) = id else {
+            self.loaded = Err(String::from("Select a day to see its input"));
+            self.scroll = 0;
+            return;
+        };
+
+        self.loaded = match self.source {
+            InputSource::File if path.is_empty() => resolver
+                .as_ref()
+                .map_err(Clone::clone)
+                .and_then(|resolver| resolver.resolve(id).map_err(|e| e.to_string())),
+            InputSource::File => PuzzleInput::from_file(path)
+                .map_err(|e| format!("Could not read input '{path}': {e}")),
+            InputSource::Example => DAYS
+                .iter()
+                .find(|info| id.year == YEAR && info.day == id.day)
+                .map(|info| PuzzleInput::from_text(info.example.trim_start_matches('\n')))
+                .ok_or_else(|| format!("{id} has no example")),
+            InputSource::Pasted => Ok(PuzzleInput::from_text(self.pasted.clone())),
+        };
+        self.scroll = 0;
+    }
+
+    // pasted input only joins the rotation once there is some
+    pub fn toggle_source(&mut self) {
+        self.source = match self.source {
+            InputSource::File => InputSource::Example,
+            InputSource::Example if !self.pasted.is_empty() => InputSource::Pasted,
+            InputSource::Example | InputSource::Pasted => InputSource::File,
+        };
+    }
+
+    pub fn start_paste(&mut self) {
+        self.pasted.clear();
+        self.pasting = true;
+        self.source = InputSource::Pasted;
+    }
+
+    pub fn finish_paste(&mut self) {
+        self.pasting = false;
+    }
+
+    pub fn paste(&mut self, text: &str) {
+        // terminals send pasted line breaks as \r
+        self.pasted
+            .push_str(&text.replace("\r\n", "\n").replace('\r', "\n"));
+    }
+
+    pub fn text(&self) -> &str {
+        match &self.loaded {
+            Ok(input) => input.text(),
+            Err(e) => e,
+        }
+    }
+
+    pub fn line_count(&self) -> usize {
+        self.text().lines().count()
+    }
+
+    pub fn scroll_down(&mut self, lines: u16) {
+        let last = self.line_count().saturating_sub(1) as u16;
+        self.scroll = self.scroll.saturating_add(lines).min(last);
+    }
+
+    pub fn scroll_up(&mut self, lines: u16) {
+        self.scroll = self.scroll.saturating_sub(lines);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::days::day01;
+
+    fn no_resolver() -> Result<InputResolver, String> {
+        Err(String::from("no input folder"))
+    }
+
+    #[test]
+    fn sources_rotate_through_pasted_input_only_when_there_is_some() {
+        let mut pane = InputPane::new(String::new());
+        assert_eq!(InputSource::File, pane.source);
+
+        pane.toggle_source();
+        assert_eq!(InputSource::Example, pane.source);
+        pane.toggle_source();
+        assert_eq!(InputSource::File, pane.source);
+
+        pane.start_paste();
+        pane.paste("1abc2\r\npqr3stu8vwx\r");
+        pane.finish_paste();
+        assert_eq!("1abc2\npqr3stu8vwx\n", pane.pasted);
+        assert!(!pane.pasting);
+
+        pane.toggle_source();
+        assert_eq!(InputSource::File, pane.source);
+        pane.toggle_source();
+        pane.toggle_source();
+        assert_eq!(InputSource::Pasted, pane.source);
+    }
+
+    #[test]
+    fn examples_are_loaded_for_the_selected_day() {
+        let mut pane = InputPane::new(String::new());
+        pane.toggle_source();
+
+        pane.reload(Some(PuzzleId::new(YEAR, 1)), "", &no_resolver());
+        assert_eq!(day01::EXAMPLE.trim_start_matches('\n'), pane.text());
+
+        pane.reload(Some(PuzzleId::new(2019, 1)), "", &no_resolver());
+        assert_eq!("2019 day 01 has no example", pane.text());
+
+        pane.reload(None, "", &no_resolver());
+        assert_eq!("Select a day to see its input", pane.text());
+
+        pane.toggle_source();
+        pane.reload(Some(PuzzleId::new(YEAR, 1)), "", &no_resolver());
+        assert_eq!("no input folder", pane.text());
+    }
+
+    #[test]
+    fn scrolling_stops_at_the_last_line() {
+        let mut pane = InputPane::new(String::from("a\nb\nc\nd"));
+        pane.reload(Some(PuzzleId::new(YEAR, 1)), "", &no_resolver());
+        assert_eq!(4, pane.line_count());
+
+        pane.scroll_down(2);
+        assert_eq!(2, pane.scroll);
+        pane.scroll_down(10);
+        assert_eq!(3, pane.scroll);
+        pane.scroll_up(1);
+        assert_eq!(2, pane.scroll);
+        pane.scroll_up(10);
+        assert_eq!(0, pane.scroll);
+
+        // a new input starts from the top again
+        pane.scroll_down(2);
+        pane.reload(Some(PuzzleId::new(YEAR, 2)), "", &no_resolver());
+        assert_eq!(0, pane.scroll);
+    }
+}
@@ -0,0 +1,347 @@
+use std::collections::{BTreeSet, HashMap};
+
+use aoc_core::{Answer, ExpectedAnswers, Part, PuzzleId, Registry, SolutionError};
+
+use crate::titles::title;
+
+pub type Results = HashMap<(PuzzleId, Part), Result<Answer, SolutionError>>;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Node {
+    Year(u16),
+    Day(PuzzleId),
+    Part(PuzzleId, Part),
+}
+
+impl Node {
+    pub fn id(&self) -> Option<PuzzleId> {
+        match self {
+            Node::Year(_) => None,
+            Node::Day(id) | Node::Part(id, _) => Some(*id),
+        }
+    }
+}
+
+pub struct Row {
+    pub node: Node,
+    pub depth: usize,
+    pub label: String,
+    // None for leaves
+    pub expanded: Option<bool>,
+}
+
+// ordered so the worst status of the children wins
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Status {
+    Solved,
+    Unverified,
+    Failing,
+}
+
+impl Status {
+    pub fn marker(&self) -> char {
+        match self {
+            Status::Solved => '✓',
+            Status::Unverified => '?',
+            Status::Failing => '✗',
+        }
+    }
+}
+
+pub struct Tree {
+    expanded: BTreeSet<Node>,
+    selected: Node,
+    pub filter: String,
+    pub filtering: bool,
+}
+
+impl Tree {
+    // starts with the newest year open
+    pub fn new(registry: &Registry) -> Self {
+        let newest = registry.years().last().copied().unwrap_or_default();
+        Self {
+            expanded: BTreeSet::from([Node::Year(newest)]),
+            selected: Node::Year(newest),
+            filter: String::new(),
+            filtering: false,
+        }
+    }
+
+    pub fn selected(&self) -> Node {
+        self.selected
+    }
+
+    // years open up by themselves while filtering, so every match is visible, best match first
+    pub fn rows(&self, registry: &Registry) -> Vec<Row> {
+        let mut rows = Vec::new();
+        for year in registry.years() {
+            let mut days = registry
+                .year(year)
+                .filter_map(|(id, solution)| {
+                    filter_score(&self.filter, id).map(|score| (score, id, solution))
+                })
+                .collect::<Vec<_>>();
+            days.sort_by_key(|&(score, id, _)| (std::cmp::Reverse(score), id));
+            if days.is_empty() {
+                continue;
+            }
+
+            let year_open = !self.filter.is_empty() || self.expanded.contains(&Node::Year(year));
+            rows.push(Row {
+                node: Node::Year(year),
+                depth: 0,
+                label: year.to_string(),
+                expanded: Some(year_open),
+            });
+            if !year_open {
+                continue;
+            }
+
+            for (_, id, solution) in days {
+                let day_open = self.expanded.contains(&Node::Day(id));
+                rows.push(Row {
+                    node: Node::Day(id),
+                    depth: 1,
+                    label: match title(id) {
+                        Some(title) => format!("Day {:02} {}", id.day, title),
+                        None => format!("Day {:02}", id.day),
+                    },
+                    expanded: Some(day_open),
+                });
+                if !day_open {
+                    continue;
+                }
+
+                for part in Part::ALL
+                    .into_iter()
+                    .filter(|&part| solution.implements(part))
+                {
+                    rows.push(Row {
+                        node: Node::Part(id, part),
+                        depth: 2,
+                        label: format!("Part {}", part),
+                        expanded: None,
+                    });
+                }
+            }
+        }
+        rows
+    }
+
+    // index of the selection among the rows, falling back to the first row when it got filtered out
+    pub fn selected_index(&mut self, rows: &[Row]) -> usize {
+        match rows.iter().position(|row| row.node == self.selected) {
+            Some(idx) => idx,
+            None => {
+                if let Some(row) = rows.first() {
+                    self.selected = row.node;
+                }
+                0
+            }
+        }
+    }
+
+    pub fn move_down(&mut self, registry: &Registry) {
+        let rows = self.rows(registry);
+        if rows.is_empty() {
+            return;
+        }
+        let idx = self.selected_index(&rows);
+        self.selected = rows[(idx + 1) % rows.len()].node;
+    }
+
+    pub fn move_up(&mut self, registry: &Registry) {
+        let rows = self.rows(registry);
+        if rows.is_empty() {
+            return;
+        }
+        let idx = self.selected_index(&rows);
+        self.selected = rows[(idx + rows.len() - 1) % rows.len()].node;
+    }
+
+    pub fn expand(&mut self) {
+        if !matches!(self.selected, Node::Part(..)) {
+            self.expanded.insert(self.selected);
+        }
+    }
+
+    // collapsing a leaf or a closed node goes up to its parent instead
+    pub fn collapse(&mut self) {
+        if self.expanded.remove(&self.selected) {
+            return;
+        }
+        self.selected = match self.selected {
+            Node::Year(year) => Node::Year(year),
+            Node::Day(id) => Node::Year(id.year),
+            Node::Part(id, _) => Node::Day(id),
+        };
+    }
+
+    pub fn toggle(&mut self) {
+        if self.expanded.contains(&self.selected) {
+            self.expanded.remove(&self.selected);
+        } else {
+            self.expand();
+        }
+    }
+}
+
+// None when the day does not match, higher scores are better matches. A number picks the day,
+// anything else has to appear in order in the title. Letters following each other or starting
+// a word count for more, so "c" ranks "Cube Conundrum" above "Trebuchet?!".
+pub fn filter_score(filter: &str, id: PuzzleId) -> Option<u32> {
+    let filter = filter.trim().to_lowercase();
+    if filter.is_empty() {
+        return Some(0);
+    }
+    if let Ok(day) = filter.parse::<u8>() {
+        return (day == id.day).then_some(0);
+    }
+
+    let title = title(id).unwrap_or_default().to_lowercase();
+    let title = title.chars().collect::<Vec<_>>();
+    let mut score = 0;
+    let mut next = 0;
+    let mut previous_match = None;
+    for c in filter.chars().filter(|c| !c.is_whitespace()) {
+        let found = next + title[next..].iter().position(|&t| t == c)?;
+        score += 1;
+        if previous_match.is_some_and(|previous| previous + 1 == found) {
+            score += 2;
+        }
+        if found == 0 || !title[found - 1].is_alphanumeric() {
+            score += 3;
+        }
+        previous_match = Some(found);
+        next = found + 1;
+    }
+    Some(score)
+}
+
+// a part is only solved once a run on its own input gave the stored answer, until then it is
+// unverified
+pub fn part_status(
+    id: PuzzleId,
+    part: Part,
+    expected: &ExpectedAnswers,
+    results: &Results,
+) -> Status {
+    match (expected.get(id, part), results.get(&(id, part))) {
+        (_, Some(Err(_))) => Status::Failing,
+        (Some(expected), Some(Ok(answer))) if expected == answer.as_str() => Status::Solved,
+        (Some(_), Some(Ok(_))) => Status::Failing,
+        (_, None) | (None, Some(Ok(_))) => Status::Unverified,
+    }
+}
+
+// None when nothing under the node is implemented
+pub fn status(
+    node: Node,
+    registry: &Registry,
+    expected: &ExpectedAnswers,
+    results: &Results,
+) -> Option<Status> {
+    match node {
+        Node::Part(id, part) => Some(part_status(id, part, expected, results)),
+        Node::Day(id) => {
+            let solution = registry.get(id)?;
+            Part::ALL
+                .into_iter()
+                .filter(|&part| solution.implements(part))
+                .map(|part| part_status(id, part, expected, results))
+                .max()
+        }
+        Node::Year(year) => registry
+            .year(year)
+            .filter_map(|(id, _)| status(Node::Day(id), registry, expected, results))
+            .max(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn registry() -> Registry {
+        let mut registry = Registry::new();
+        crate::registry::register(&mut registry);
+        registry
+    }
+
+    fn filtered(filter: &str) -> Vec<Node> {
+        let registry = registry();
+        let mut tree = Tree::new(&registry);
+        tree.filter = String::from(filter);
+        tree.rows(&registry)
+            .into_iter()
+            .map(|row| row.node)
+            .collect()
+    }
+
+    #[test]
+    fn filter_ranks_word_starts_and_runs_first() {
+        let day = |day| Node::Day(PuzzleId::new(2023, day));
+        assert_eq!(vec![Node::Year(2023), day(2), day(1)], filtered("c"));
+        assert_eq!(vec![Node::Year(2023), day(2)], filtered("cc"));
+        assert_eq!(vec![Node::Year(2023), day(3)], filtered("3"));
+        assert!(filtered("zz").is_empty());
+
+        let id = PuzzleId::new(2023, 3);
+        assert!(filter_score("gear", id) > filter_score("gars", id));
+        assert_eq!(None, filter_score("ratios gear", id));
+    }
+
+    #[test]
+    fn parts_are_only_solved_after_a_matching_run() {
+        let id = PuzzleId::new(2023, 2);
+        let mut expected = ExpectedAnswers::default();
+        expected.insert(id, Part::One, "8");
+        let mut results = Results::new();
+
+        assert_eq!(
+            Status::Unverified,
+            part_status(id, Part::One, &expected, &results)
+        );
+        assert_eq!(
+            Status::Unverified,
+            part_status(id, Part::Two, &expected, &results)
+        );
+
+        results.insert((id, Part::One), Ok(Answer::new(8)));
+        results.insert((id, Part::Two), Ok(Answer::new(2286)));
+        assert_eq!(
+            Status::Solved,
+            part_status(id, Part::One, &expected, &results)
+        );
+        assert_eq!(
+            Status::Unverified,
+            part_status(id, Part::Two, &expected, &results)
+        );
+
+        results.insert((id, Part::One), Ok(Answer::new(9)));
+        assert_eq!(
+            Status::Failing,
+            part_status(id, Part::One, &expected, &results)
+        );
+        results.insert((id, Part::Two), Err(SolutionError::NotImplemented));
+        assert_eq!(
+            Status::Failing,
+            part_status(id, Part::Two, &expected, &results)
+        );
+
+        let registry = registry();
+        assert_eq!(
+            Some(Status::Failing),
+            status(Node::Day(id), &registry, &expected, &results)
+        );
+        assert_eq!(
+            Some(Status::Unverified),
+            status(
+                Node::Day(PuzzleId::new(2023, 1)),
+                &registry,
+                &expected,
+                &results
+            )
+        );
+    }
+}
@@ -0,0 +1,111 @@
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::mpsc::{self, Receiver, TryRecvError};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use aoc_core::verify::panic_message;
+
+pub const THREAD_NAME: &str = "day-runner";
+
+const SPINNER: [char; 10] = ['⠋', '⠙', '⠹', '⠸', '⠼', '⠴', '⠦', '⠧', '⠇', '⠏'];
+
+// work running on its own thread, dropping the job abandons it
+pub struct Job<T> {
+    pub label: String,
+    started: Instant,
+    receiver: Receiver<Result<T, String>>,
+}
+
+impl<T: Send + 'static> Job<T> {
+    pub fn spawn(label: String, work: impl FnOnce() -> T + Send + 'static) -> Self {
+        let (sender, receiver) = mpsc::channel();
+        thread::Builder::new()
+            .name(THREAD_NAME.to_string())
+            .spawn(move || {
+                let out = panic::catch_unwind(AssertUnwindSafe(work))
+                    .map_err(|payload| format!("Panicked: {}", panic_message(&*payload)));
+                // nobody listens anymore when the run was abandoned
+                let _ = sender.send(out);
+            })
+            .expect("day runner thread spawned");
+
+        Self {
+            label,
+            started: Instant::now(),
+            receiver,
+        }
+    }
+
+    pub fn elapsed(&self) -> Duration {
+        self.started.elapsed()
+    }
+
+    pub fn spinner(&self) -> char {
+        SPINNER[(self.elapsed().as_millis() / 100) as usize % SPINNER.len()]
+    }
+
+    pub fn try_finish(&self) -> Option<Result<T, String>> {
+        match self.receiver.try_recv() {
+            Ok(out) => Some(out),
+            Err(TryRecvError::Empty) => None,
+            Err(TryRecvError::Disconnected) => {
+                Some(Err(String::from("Runner stopped without a result")))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn wait_for<T: Send + 'static>(job: &Job<T>) -> Result<T, String> {
+        loop {
+            if let Some(out) = job.try_finish() {
+                return out;
+            }
+            thread::sleep(Duration::from_millis(1));
+        }
+    }
+
+    #[test]
+    fn finished_work_is_collected_once() {
+        let job = Job::spawn(String::from("2023 day 1"), || 6 * 7);
+        assert_eq!(Ok(42), wait_for(&job));
+        assert_eq!("2023 day 1", job.label);
+        assert_eq!(
+            Some(Err(String::from("Runner stopped without a result"))),
+            job.try_finish()
+        );
+    }
+
+    #[test]
+    fn panics_are_reported_as_errors() {
+        let job = Job::<()>::spawn(String::new(), || panic!("bad input"));
+        assert_eq!(Err(String::from("Panicked: bad input")), wait_for(&job));
+    }
+
+    // sends a message once the worker thread gets rid of its result
+    struct Finished(mpsc::Sender<()>);
+
+    impl Drop for Finished {
+        fn drop(&mut self) {
+            let _ = self.0.send(());
+        }
+    }
+
+    #[test]
+    fn abandoned_work_runs_to_the_end_without_a_listener() {
+        let (release, released) = mpsc::channel::<()>();
+        let (done, finished) = mpsc::channel();
+        let job = Job::spawn(String::new(), move || {
+            released.recv().unwrap();
+            Finished(done)
+        });
+        assert!(job.try_finish().is_none());
+
+        drop(job);
+        release.send(()).unwrap();
+        assert_eq!(Ok(()), finished.recv_timeout(Duration::from_secs(5)));
+    }
+}
@@ -0,0 +1,202 @@
+use std::{fmt::Display, path::Path};
+
+use crate::{Answer, Part, PuzzleInput, Solution, SolutionError};
+
+type ParseFn<P> = Box<dyn Fn(&PuzzleInput) -> Result<P, SolutionError> + Send + Sync>;
+type PartFn<P> = Box<dyn Fn(&P) -> Result<Answer, SolutionError> + Send + Sync>;
+
+// wraps the existing free functions of a day so they don't need a type of their own
+pub struct FnSolution<P> {
+    parse: ParseFn<P>,
+    parses_input: bool,
+    part1: Option<PartFn<P>>,
+    part2: Option<PartFn<P>>,
+}
+
+impl<P: 'static> FnSolution<P> {
+    pub fn from_text<E, F>(parse: F) -> Self
+    where
+        E: Display,
+        F: Fn(&str) -> Result<P, E> + Send + Sync + 'static,
+    {
+        Self::new(
+            Box::new(move |input: &PuzzleInput| {
+                parse(input.text()).map_err(SolutionError::parse)
+            }),
+            true,
+        )
+    }
+
+    // for days which insist on opening their input file themselves
+    pub fn from_path<E, F>(parse: F) -> Self
+    where
+        E: Display,
+        F: Fn(&Path) -> Result<P, E> + Send + Sync + 'static,
+    {
+        Self::new(
+            Box::new(move |input: &PuzzleInput| {
+                let path = input.path().ok_or(SolutionError::MissingInputPath)?;
+                parse(path).map_err(SolutionError::parse)
+            }),
+            false,
+        )
+    }
+
+    fn new(parse: ParseFn<P>, parses_input: bool) -> Self {
+        Self {
+            parse,
+            parses_input,
+            part1: None,
+            part2: None,
+        }
+    }
+
+    pub fn part1<T, F>(self, solve: F) -> Self
+    where
+        T: Display,
+        F: Fn(&P) -> T + Send + Sync + 'static,
+    {
+        self.try_part1(move |parsed| Ok::<T, String>(solve(parsed)))
+    }
+
+    pub fn part2<T, F>(self, solve: F) -> Self
+    where
+        T: Display,
+        F: Fn(&P) -> T + Send + Sync + 'static,
+    {
+        self.try_part2(move |parsed| Ok::<T, String>(solve(parsed)))
+    }
+
+    pub fn try_part1<T, E, F>(mut self, solve: F) -> Self
+    where
+        T: Display,
+        E: Display,
+        F: Fn(&P) -> Result<T, E> + Send + Sync + 'static,
+    {
+        self.part1 = Some(boxed_part(solve));
+        self
+    }
+
+    pub fn try_part2<T, E, F>(mut self, solve: F) -> Self
+    where
+        T: Display,
+        E: Display,
+        F: Fn(&P) -> Result<T, E> + Send + Sync + 'static,
+    {
+        self.part2 = Some(boxed_part(solve));
+        self
+    }
+}
+
+impl FnSolution<String> {
+    // keeps the input text as is, for days which parse inside each part
+    pub fn raw() -> Self {
+        Self::new(
+            Box::new(|input: &PuzzleInput| Ok(input.text().to_string())),
+            true,
+        )
+    }
+}
+
+fn boxed_part<P, T, E, F>(solve: F) -> PartFn<P>
+where
+    T: Display,
+    E: Display,
+    F: Fn(&P) -> Result<T, E> + Send + Sync + 'static,
+{
+    Box::new(move |parsed| {
+        solve(parsed)
+            .map(Answer::new)
+            .map_err(SolutionError::failed)
+    })
+}
+
+impl<P: 'static> Solution for FnSolution<P> {
+    type Parsed = P;
+
+    fn parse(&self, input: &PuzzleInput) -> Result<P, SolutionError> {
+        (self.parse)(input)
+    }
+
+    fn part1(&self, parsed: &P) -> Result<Answer, SolutionError> {
+        match &self.part1 {
+            Some(solve) => solve(parsed),
+            None => Err(SolutionError::NotImplemented),
+        }
+    }
+
+    fn part2(&self, parsed: &P) -> Result<Answer, SolutionError> {
+        match &self.part2 {
+            Some(solve) => solve(parsed),
+            None => Err(SolutionError::NotImplemented),
+        }
+    }
+
+    fn implements(&self, part: Part) -> bool {
+        match part {
+            Part::One => self.part1.is_some(),
+            Part::Two => self.part2.is_some(),
+        }
+    }
+
+    fn parses_input(&self) -> bool {
+        self.parses_input
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::AnySolution;
+
+    fn numbers() -> FnSolution<Vec<i64>> {
+        FnSolution::from_text(|text| {
+            text.lines()
+                .map(|l| l.parse::<i64>())
+                .collect::<Result<Vec<_>, _>>()
+        })
+    }
+
+    #[test]
+    fn parts_share_parsed_input() {
+        let solution = numbers()
+            .part1(|n| n.iter().sum::<i64>())
+            .try_part2(|n| n.iter().max().copied().ok_or("empty input"));
+        let input = PuzzleInput::from_text("3\n-7\n12");
+
+        assert_eq!("8", solution.run(Part::One, &input).unwrap().as_str());
+        assert_eq!("12", solution.run(Part::Two, &input).unwrap().as_str());
+        assert_eq!(
+            Err(SolutionError::failed("empty input")),
+            solution.run(Part::Two, &PuzzleInput::from_text(""))
+        );
+    }
+
+    #[test]
+    fn errors_are_reported_per_stage() {
+        let solution = numbers().part1(|n| n.len());
+
+        assert!(matches!(
+            solution.run(Part::One, &PuzzleInput::from_text("x")),
+            Err(SolutionError::Parse(_))
+        ));
+        assert_eq!(
+            Err(SolutionError::NotImplemented),
+            solution.run(Part::Two, &PuzzleInput::from_text("1"))
+        );
+        assert!(!AnySolution::implements(&solution, Part::Two));
+    }
+
+    #[test]
+    fn path_based_days_need_a_path() {
+        let solution = FnSolution::from_path(|path| Ok::<_, String>(path.display().to_string()))
+            .part1(|p| p.clone());
+
+        assert_eq!(
+            Err(SolutionError::MissingInputPath),
+            solution.run(Part::One, &PuzzleInput::from_text("ignored"))
+        );
+        assert!(!AnySolution::parses_input(&solution));
+        assert!(AnySolution::parses_input(&numbers()));
+    }
+}
@@ -0,0 +1,53 @@
+use std::fmt::Display;
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Answer(String);
+
+impl Answer {
+    pub fn new(answer: impl Display) -> Self {
+        Self(answer.to_string())
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SolutionError {
+    Parse(String),
+    MissingInputPath,
+    NotImplemented,
+    Failed(String),
+}
+
+impl SolutionError {
+    pub fn parse(msg: impl Display) -> Self {
+        SolutionError::Parse(msg.to_string())
+    }
+
+    pub fn failed(msg: impl Display) -> Self {
+        SolutionError::Failed(msg.to_string())
+    }
+}
+
+impl Display for SolutionError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SolutionError::Parse(msg) => write!(f, "Failed to parse input: {}", msg),
+            SolutionError::MissingInputPath => {
+                f.write_str("Solution reads its input from a file but no input path was given")
+            }
+            SolutionError::NotImplemented => f.write_str("Not implemented"),
+            SolutionError::Failed(msg) => write!(f, "Solution failed: {}", msg),
+        }
+    }
+}
+
+impl std::error::Error for SolutionError {}
@@ -0,0 +1,271 @@
+use std::{
+    collections::BTreeMap,
+    fmt::{Display, Write},
+    path::Path,
+    str::FromStr,
+    time::{Duration, Instant},
+};
+
+use crate::{
+    table::{self, TableError},
+    AnySolution, Part, PuzzleId, PuzzleInput, SolutionError,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BenchOptions {
+    pub warmup: usize,
+    pub iterations: usize,
+}
+
+impl Default for BenchOptions {
+    fn default() -> Self {
+        Self {
+            warmup: 1,
+            iterations: 10,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Step {
+    Parse,
+    Solve(Part),
+}
+
+impl Display for Step {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Step::Parse => f.write_str("parse"),
+            Step::Solve(part) => write!(f, "part{}", part),
+        }
+    }
+}
+
+impl FromStr for Step {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "parse" => Ok(Step::Parse),
+            _ if s.starts_with("part") => s.parse().map(Step::Solve),
+            _ => Err(format!("Unknown step '{}'", s)),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub max: Duration,
+}
+
+impl Stats {
+    pub fn from_samples(mut samples: Vec<Duration>) -> Option<Self> {
+        samples.sort_unstable();
+        let mid = samples.len() / 2;
+        let median = match samples.len() {
+            0 => return None,
+            len if len % 2 == 0 => (samples[mid - 1] + samples[mid]) / 2,
+            _ => samples[mid],
+        };
+
+        Some(Self {
+            min: samples[0],
+            median,
+            max: samples[samples.len() - 1],
+        })
+    }
+}
+
+// parsing is timed on its own, every part then reuses a single parsed input
+pub fn bench(
+    solution: &dyn AnySolution,
+    input: &PuzzleInput,
+    options: BenchOptions,
+) -> Result<Vec<(Step, Stats)>, SolutionError> {
+    let mut results = vec![(
+        Step::Parse,
+        measure(options, || solution.parse_any(input).map(drop))?,
+    )];
+
+    let parsed = solution.parse_any(input)?;
+    for part in Part::ALL {
+        if !solution.implements(part) {
+            continue;
+        }
+
+        match measure(options, || {
+            solution.solve_any(part, parsed.as_ref()).map(drop)
+        }) {
+            Ok(stats) => results.push((Step::Solve(part), stats)),
+            Err(SolutionError::NotImplemented) => continue,
+            Err(e) => return Err(e),
+        }
+    }
+
+    Ok(results)
+}
+
+fn measure(
+    options: BenchOptions,
+    mut step: impl FnMut() -> Result<(), SolutionError>,
+) -> Result<Stats, SolutionError> {
+    for _ in 0..options.warmup {
+        step()?;
+    }
+
+    let mut samples = Vec::with_capacity(options.iterations);
+    for _ in 0..options.iterations.max(1) {
+        let start = Instant::now();
+        step()?;
+        samples.push(start.elapsed());
+    }
+
+    Ok(Stats::from_samples(samples).expect("At least one iteration"))
+}
+
+// median of every step from an earlier run, kept to compare later runs against
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Baseline {
+    medians: BTreeMap<(PuzzleId, Step), Duration>,
+}
+
+impl Baseline {
+    pub fn from_file(path: impl AsRef<Path>) -> Result<Self, TableError> {
+        table::read_file(path.as_ref())?.parse()
+    }
+
+    pub fn save(&self, path: impl AsRef<Path>) -> std::io::Result<()> {
+        std::fs::write(path, self.to_string())
+    }
+
+    pub fn get(&self, id: PuzzleId, step: Step) -> Option<Duration> {
+        self.medians.get(&(id, step)).copied()
+    }
+
+    pub fn insert(&mut self, id: PuzzleId, step: Step, median: Duration) {
+        self.medians.insert((id, step), median);
+    }
+
+    pub fn len(&self) -> usize {
+        self.medians.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.medians.is_empty()
+    }
+}
+
+// written as the same day tables the answers file uses, durations in nanoseconds
+impl Display for Baseline {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut current = None;
+        for ((id, step), median) in &self.medians {
+            if current != Some(*id) {
+                if current.is_some() {
+                    f.write_char('\n')?;
+                }
+                writeln!(f, "[{}.{}]", id.year, id.day)?;
+                current = Some(*id);
+            }
+            writeln!(f, "{} = {}", step, median.as_nanos())?;
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for Baseline {
+    type Err = TableError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut baseline = Baseline::default();
+        for (id, key, value) in table::day_entries(s)? {
+            let step = key
+                .parse::<Step>()
+                .map_err(|_| TableError::new(format!("Invalid step '{}' for {}", key, id)))?;
+            let nanos = value
+                .as_integer()
+                .and_then(|nanos| u64::try_from(nanos).ok())
+                .ok_or_else(|| {
+                    TableError::new(format!(
+                        "Time for {} {} must be a non-negative integer",
+                        id, step
+                    ))
+                })?;
+            baseline.insert(id, step, Duration::from_nanos(nanos));
+        }
+
+        Ok(baseline)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::FnSolution;
+
+    #[test]
+    fn stats_take_median_of_sorted_samples() {
+        let ms = Duration::from_millis;
+        assert_eq!(
+            Some(Stats {
+                min: ms(1),
+                median: ms(3),
+                max: ms(9)
+            }),
+            Stats::from_samples(vec![ms(9), ms(1), ms(3)])
+        );
+        assert_eq!(
+            ms(4),
+            Stats::from_samples(vec![ms(5), ms(1), ms(3), ms(9)])
+                .unwrap()
+                .median
+        );
+        assert_eq!(None, Stats::from_samples(Vec::new()));
+    }
+
+    #[test]
+    fn every_implemented_part_is_timed() {
+        let solution = FnSolution::raw().part1(|text| text.len());
+        let options = BenchOptions {
+            warmup: 0,
+            iterations: 3,
+        };
+
+        let steps = bench(&solution, &PuzzleInput::from_text("abc"), options)
+            .unwrap()
+            .into_iter()
+            .map(|(step, _)| step)
+            .collect::<Vec<_>>();
+        assert_eq!(vec![Step::Parse, Step::Solve(Part::One)], steps);
+    }
+
+    #[test]
+    fn baseline_round_trips_through_text() {
+        let mut baseline = Baseline::default();
+        baseline.insert(
+            PuzzleId::new(2018, 9),
+            Step::Parse,
+            Duration::from_nanos(120),
+        );
+        baseline.insert(
+            PuzzleId::new(2018, 9),
+            Step::Solve(Part::Two),
+            Duration::from_millis(250),
+        );
+        baseline.insert(
+            PuzzleId::new(2023, 1),
+            Step::Solve(Part::One),
+            Duration::from_micros(7),
+        );
+
+        let text = baseline.to_string();
+        assert_eq!(
+            "[2018.9]\nparse = 120\npart2 = 250000000\n\n[2023.1]\npart1 = 7000\n",
+            text
+        );
+        assert_eq!(Ok(baseline), text.parse());
+        assert!("[2018.9]\nparse = -1".parse::<Baseline>().is_err());
+    }
+}
@@ -0,0 +1,107 @@
+use std::{collections::BTreeMap, path::Path, str::FromStr};
+
+use crate::{
+    table::{self, TableError},
+    Part, PuzzleId,
+};
+
+// known correct answers, integers and strings are both accepted
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct ExpectedAnswers {
+    answers: BTreeMap<(PuzzleId, Part), String>,
+}
+
+impl ExpectedAnswers {
+    pub fn from_file(path: impl AsRef<Path>) -> Result<Self, TableError> {
+        table::read_file(path.as_ref())?.parse()
+    }
+
+    pub fn get(&self, id: PuzzleId, part: Part) -> Option<&str> {
+        self.answers.get(&(id, part)).map(|a| a.as_str())
+    }
+
+    pub fn insert(&mut self, id: PuzzleId, part: Part, answer: impl Into<String>) {
+        self.answers.insert((id, part), answer.into());
+    }
+
+    pub fn len(&self) -> usize {
+        self.answers.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.answers.is_empty()
+    }
+}
+
+impl FromStr for ExpectedAnswers {
+    type Err = TableError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut expected = ExpectedAnswers::default();
+        for (id, key, answer) in table::day_entries(s)? {
+            let part = key
+                .parse::<Part>()
+                .map_err(|_| TableError::new(format!("Invalid part '{}' for {}", key, id)))?;
+            let answer = match answer {
+                toml::Value::String(s) => s,
+                toml::Value::Integer(i) => i.to_string(),
+                other => {
+                    return Err(TableError::new(format!(
+                        "Answer for {} part {} must be a string or an integer, got {}",
+                        id,
+                        part,
+                        other.type_str()
+                    )))
+                }
+            };
+
+            if expected.get(id, part).is_some() {
+                return Err(TableError::new(format!(
+                    "Answer for {} part {} is given twice",
+                    id, part
+                )));
+            }
+            expected.insert(id, part, answer);
+        }
+
+        Ok(expected)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn answers_are_keyed_by_year_day_and_part() {
+        let expected =
+            "[2019.5]\npart1 = 6731945\npart2 = \"9571668\"\n\n[2023.01]\npart2 = 54578\n"
+                .parse::<ExpectedAnswers>()
+                .unwrap();
+
+        assert_eq!(3, expected.len());
+        assert_eq!(
+            Some("6731945"),
+            expected.get(PuzzleId::new(2019, 5), Part::One)
+        );
+        assert_eq!(
+            Some("9571668"),
+            expected.get(PuzzleId::new(2019, 5), Part::Two)
+        );
+        assert_eq!(
+            Some("54578"),
+            expected.get(PuzzleId::new(2023, 1), Part::Two)
+        );
+        assert_eq!(None, expected.get(PuzzleId::new(2023, 1), Part::One));
+    }
+
+    #[test]
+    fn malformed_answers_are_rejected() {
+        assert!("[2019.5]\npart3 = 1".parse::<ExpectedAnswers>().is_err());
+        assert!("[2019.x]\npart1 = 1".parse::<ExpectedAnswers>().is_err());
+        assert!("[2019.5]\npart1 = 1.5".parse::<ExpectedAnswers>().is_err());
+        assert!("[2019.5]\npart1 = 1\n[2019.05]\npart1 = 1"
+            .parse::<ExpectedAnswers>()
+            .is_err());
+    }
+}
@@ -0,0 +1,212 @@
+use std::{
+    env,
+    fmt::Display,
+    io::{self, Read},
+    path::{Path, PathBuf},
+};
+
+use crate::PuzzleId;
+
+pub const INPUT_DIR_VAR: &str = "AOC_INPUT_DIR";
+
+// keeps the source path around for days which still open their input file themselves
+#[derive(Debug, Clone)]
+pub struct PuzzleInput {
+    text: String,
+    path: Option<PathBuf>,
+}
+
+impl PuzzleInput {
+    pub fn from_text(text: impl Into<String>) -> Self {
+        Self {
+            text: text.into(),
+            path: None,
+        }
+    }
+
+    pub fn from_file(path: impl AsRef<Path>) -> io::Result<Self> {
+        let path = path.as_ref();
+        let text = std::fs::read_to_string(path)?;
+        Ok(Self {
+            text,
+            path: Some(path.to_path_buf()),
+        })
+    }
+
+    pub fn from_stdin() -> io::Result<Self> {
+        let mut text = String::new();
+        io::stdin().read_to_string(&mut text)?;
+        Ok(Self::from_text(text))
+    }
+
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    pub fn path(&self) -> Option<&Path> {
+        self.path.as_deref()
+    }
+}
+
+// inputs live under one root as {year}/day{NN}.txt, older layouts are only searched after it
+pub struct InputResolver {
+    root: PathBuf,
+    fallbacks: Vec<Box<dyn Fn(PuzzleId) -> Option<PathBuf>>>,
+}
+
+impl InputResolver {
+    pub fn new(root: impl Into<PathBuf>) -> Self {
+        Self {
+            root: root.into(),
+            fallbacks: Vec::new(),
+        }
+    }
+
+    // AOC_INPUT_DIR wins over `input_dir` from the config file, which wins over the default
+    pub fn configured(config: &Path, default_root: impl Into<PathBuf>) -> Result<Self, InputError> {
+        if let Some(root) = env::var_os(INPUT_DIR_VAR).filter(|root| !root.is_empty()) {
+            return Ok(Self::new(root));
+        }
+        if !config.is_file() {
+            return Ok(Self::new(default_root));
+        }
+
+        let config_error = |msg: String| InputError::Config {
+            path: config.to_path_buf(),
+            msg,
+        };
+        let table = std::fs::read_to_string(config)
+            .map_err(|e| config_error(e.to_string()))?
+            .parse::<toml::Table>()
+            .map_err(|e| config_error(e.to_string()))?;
+
+        match table.get("input_dir") {
+            None => Ok(Self::new(default_root)),
+            Some(toml::Value::String(dir)) => {
+                // relative to the config file, not to wherever the binary was started
+                let base = config.parent().unwrap_or(Path::new(""));
+                Ok(Self::new(base.join(dir)))
+            }
+            Some(other) => Err(config_error(format!(
+                "input_dir must be a string, got {}",
+                other.type_str()
+            ))),
+        }
+    }
+
+    pub fn with_fallback(
+        mut self,
+        fallback: impl Fn(PuzzleId) -> Option<PathBuf> + 'static,
+    ) -> Self {
+        self.fallbacks.push(Box::new(fallback));
+        self
+    }
+
+    pub fn root(&self) -> &Path {
+        &self.root
+    }
+
+    pub fn path_for(&self, id: PuzzleId) -> PathBuf {
+        self.root
+            .join(id.year.to_string())
+            .join(format!("day{:02}.txt", id.day))
+    }
+
+    pub fn candidates(&self, id: PuzzleId) -> Vec<PathBuf> {
+        let mut candidates = vec![self.path_for(id)];
+        candidates.extend(self.fallbacks.iter().filter_map(|fallback| fallback(id)));
+        candidates
+    }
+
+    pub fn resolve(&self, id: PuzzleId) -> Result<PuzzleInput, InputError> {
+        let candidates = self.candidates(id);
+        match candidates.iter().find(|path| path.is_file()) {
+            Some(path) => PuzzleInput::from_file(path).map_err(|e| InputError::Read {
+                path: path.clone(),
+                msg: e.to_string(),
+            }),
+            None => Err(InputError::NotFound {
+                id,
+                tried: candidates,
+            }),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InputError {
+    NotFound { id: PuzzleId, tried: Vec<PathBuf> },
+    Read { path: PathBuf, msg: String },
+    Config { path: PathBuf, msg: String },
+}
+
+impl Display for InputError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            InputError::NotFound { id, tried } => {
+                write!(f, "No input for {}, tried:", id)?;
+                for path in tried {
+                    write!(f, "\n\t{}", path.display())?;
+                }
+                Ok(())
+            }
+            InputError::Read { path, msg } => {
+                write!(f, "Could not read input '{}': {}", path.display(), msg)
+            }
+            InputError::Config { path, msg } => {
+                write!(f, "Invalid config file '{}': {}", path.display(), msg)
+            }
+        }
+    }
+}
+
+impl std::error::Error for InputError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn missing_input_lists_every_path_tried() {
+        let resolver = InputResolver::new("/no/such/root")
+            .with_fallback(|id| Some(PathBuf::from(format!("/old/day{}", id.day))))
+            .with_fallback(|id| (id.year == 2018).then(|| PathBuf::from("/older")));
+
+        let id = PuzzleId::new(2019, 7);
+        assert_eq!(
+            PathBuf::from("/no/such/root/2019/day07.txt"),
+            resolver.path_for(id)
+        );
+
+        let err = resolver.resolve(id).unwrap_err();
+        assert_eq!(
+            InputError::NotFound {
+                id,
+                tried: vec![
+                    PathBuf::from("/no/such/root/2019/day07.txt"),
+                    PathBuf::from("/old/day7")
+                ]
+            },
+            err
+        );
+        assert_eq!(
+            "No input for 2019 day 07, tried:\n\t/no/such/root/2019/day07.txt\n\t/old/day7",
+            err.to_string()
+        );
+    }
+
+    #[test]
+    fn first_existing_candidate_is_read() {
+        let root = env::temp_dir().join(format!("aoc-core-input-{}", std::process::id()));
+        std::fs::create_dir_all(root.join("2023")).unwrap();
+        std::fs::write(root.join("2023/day01.txt"), "1abc2").unwrap();
+
+        let resolver = InputResolver::new(&root).with_fallback(|_| Some(PathBuf::from("/old")));
+        let input = resolver.resolve(PuzzleId::new(2023, 1));
+        std::fs::remove_dir_all(&root).unwrap();
+
+        let input = input.unwrap();
+        assert_eq!("1abc2", input.text());
+        assert_eq!(Some(root.join("2023/day01.txt").as_path()), input.path());
+    }
+}
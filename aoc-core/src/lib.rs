@@ -0,0 +1,19 @@
+pub mod adapter;
+pub mod answer;
+pub mod bench;
+pub mod expected;
+pub mod input;
+pub mod registry;
+pub mod solution;
+mod table;
+pub mod verify;
+
+pub use adapter::FnSolution;
+pub use answer::{Answer, SolutionError};
+pub use bench::{bench, Baseline, BenchOptions, Stats, Step};
+pub use expected::ExpectedAnswers;
+pub use input::{InputError, InputResolver, PuzzleInput};
+pub use registry::{PuzzleId, Registry};
+pub use solution::{AnySolution, Part, Solution};
+pub use table::TableError;
+pub use verify::{verify, Verdict};
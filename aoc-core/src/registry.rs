@@ -0,0 +1,117 @@
+use std::{collections::BTreeMap, fmt::Display};
+
+use crate::{AnySolution, Solution};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct PuzzleId {
+    pub year: u16,
+    pub day: u8,
+}
+
+impl PuzzleId {
+    pub fn new(year: u16, day: u8) -> Self {
+        Self { year, day }
+    }
+}
+
+impl Display for PuzzleId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} day {:02}", self.year, self.day)
+    }
+}
+
+#[derive(Default)]
+pub struct Registry {
+    solutions: BTreeMap<PuzzleId, Box<dyn AnySolution>>,
+}
+
+impl Registry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn register<S: Solution + 'static>(&mut self, year: u16, day: u8, solution: S) {
+        let id = PuzzleId::new(year, day);
+        let previous = self.solutions.insert(id, Box::new(solution));
+        assert!(previous.is_none(), "Solution for {} registered twice", id);
+    }
+
+    pub fn get(&self, id: PuzzleId) -> Option<&dyn AnySolution> {
+        self.solutions.get(&id).map(|s| s.as_ref())
+    }
+
+    // ordered by year, then day
+    pub fn iter(&self) -> impl Iterator<Item = (PuzzleId, &dyn AnySolution)> {
+        self.solutions.iter().map(|(id, s)| (*id, s.as_ref()))
+    }
+
+    pub fn year(&self, year: u16) -> impl Iterator<Item = (PuzzleId, &dyn AnySolution)> {
+        self.iter().filter(move |(id, _)| id.year == year)
+    }
+
+    pub fn years(&self) -> Vec<u16> {
+        let mut years = self.solutions.keys().map(|id| id.year).collect::<Vec<_>>();
+        years.dedup();
+        years
+    }
+
+    pub fn len(&self) -> usize {
+        self.solutions.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.solutions.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{FnSolution, Part, PuzzleInput};
+
+    fn line_count() -> FnSolution<String> {
+        FnSolution::raw().part1(|text| text.lines().count())
+    }
+
+    #[test]
+    fn registered_solutions_are_ordered_by_year_and_day() {
+        let mut registry = Registry::new();
+        registry.register(2022, 3, line_count());
+        registry.register(2019, 10, line_count());
+        registry.register(2019, 2, line_count());
+
+        let ids = registry.iter().map(|(id, _)| id).collect::<Vec<_>>();
+        assert_eq!(
+            vec![
+                PuzzleId::new(2019, 2),
+                PuzzleId::new(2019, 10),
+                PuzzleId::new(2022, 3)
+            ],
+            ids
+        );
+        assert_eq!(vec![2019, 2022], registry.years());
+        assert_eq!(2, registry.year(2019).count());
+    }
+
+    #[test]
+    fn registered_solution_runs_through_registry() {
+        let mut registry = Registry::new();
+        registry.register(2023, 1, line_count());
+
+        let solution = registry.get(PuzzleId::new(2023, 1)).unwrap();
+        let answer = solution
+            .run(Part::One, &PuzzleInput::from_text("a\nb\nc"))
+            .unwrap();
+        assert_eq!("3", answer.as_str());
+        assert!(!solution.implements(Part::Two));
+        assert!(registry.get(PuzzleId::new(2023, 2)).is_none());
+    }
+
+    #[test]
+    #[should_panic]
+    fn registering_same_day_twice_panics() {
+        let mut registry = Registry::new();
+        registry.register(2023, 1, line_count());
+        registry.register(2023, 1, line_count());
+    }
+}
@@ -0,0 +1,101 @@
+use std::{any::Any, fmt::Display, str::FromStr};
+
+use crate::{Answer, PuzzleInput, SolutionError};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub const ALL: [Part; 2] = [Part::One, Part::Two];
+
+    pub fn number(&self) -> u8 {
+        match self {
+            Part::One => 1,
+            Part::Two => 2,
+        }
+    }
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.number())
+    }
+}
+
+impl FromStr for Part {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().trim_start_matches(|c: char| !c.is_ascii_digit()) {
+            "1" => Ok(Part::One),
+            "2" => Ok(Part::Two),
+            _ => Err(format!("Unknown part '{}', expected 1 or 2", s)),
+        }
+    }
+}
+
+// parsing is split from solving so both parts can share the parsed input
+pub trait Solution: Send + Sync {
+    type Parsed: 'static;
+
+    fn parse(&self, input: &PuzzleInput) -> Result<Self::Parsed, SolutionError>;
+
+    fn part1(&self, parsed: &Self::Parsed) -> Result<Answer, SolutionError>;
+
+    fn part2(&self, _parsed: &Self::Parsed) -> Result<Answer, SolutionError> {
+        Err(SolutionError::NotImplemented)
+    }
+
+    fn implements(&self, part: Part) -> bool {
+        part == Part::One
+    }
+
+    // false for days that only pass their input path on and read the file inside each part
+    fn parses_input(&self) -> bool {
+        true
+    }
+}
+
+// object safe view of `Solution` so days with different parsed types fit in one registry
+pub trait AnySolution: Send + Sync {
+    fn parse_any(&self, input: &PuzzleInput) -> Result<Box<dyn Any>, SolutionError>;
+
+    fn solve_any(&self, part: Part, parsed: &dyn Any) -> Result<Answer, SolutionError>;
+
+    fn implements(&self, part: Part) -> bool;
+
+    fn parses_input(&self) -> bool;
+
+    fn run(&self, part: Part, input: &PuzzleInput) -> Result<Answer, SolutionError> {
+        let parsed = self.parse_any(input)?;
+        self.solve_any(part, parsed.as_ref())
+    }
+}
+
+impl<S: Solution> AnySolution for S {
+    fn parse_any(&self, input: &PuzzleInput) -> Result<Box<dyn Any>, SolutionError> {
+        Ok(Box::new(self.parse(input)?))
+    }
+
+    fn solve_any(&self, part: Part, parsed: &dyn Any) -> Result<Answer, SolutionError> {
+        let parsed = parsed
+            .downcast_ref::<S::Parsed>()
+            .expect("Parsed input passed to the solution that produced it");
+
+        match part {
+            Part::One => self.part1(parsed),
+            Part::Two => self.part2(parsed),
+        }
+    }
+
+    fn implements(&self, part: Part) -> bool {
+        Solution::implements(self, part)
+    }
+
+    fn parses_input(&self) -> bool {
+        Solution::parses_input(self)
+    }
+}
@@ -0,0 +1,70 @@
+use std::{fmt::Display, path::Path};
+
+use crate::PuzzleId;
+
+// answers and benchmark baselines share one layout, a table per day:
+//
+//   [2019.5]
+//   part1 = 6731945
+pub(crate) fn read_file(path: &Path) -> Result<String, TableError> {
+    std::fs::read_to_string(path)
+        .map_err(|e| TableError::new(format!("Could not read '{}': {}", path.display(), e)))
+}
+
+pub(crate) fn day_entries(s: &str) -> Result<Vec<(PuzzleId, String, toml::Value)>, TableError> {
+    let table = s
+        .parse::<toml::Table>()
+        .map_err(|e| TableError::new(format!("Invalid TOML: {}", e)))?;
+
+    let mut entries = Vec::new();
+    let mut seen = Vec::new();
+    for (year_key, days) in table {
+        let year = year_key
+            .parse::<u16>()
+            .map_err(|_| TableError::new(format!("Invalid year '{}'", year_key)))?;
+        let days = match days {
+            toml::Value::Table(days) => days,
+            _ => return Err(TableError::new(format!("Year {} is not a table", year))),
+        };
+
+        for (day_key, values) in days {
+            let id = day_key
+                .parse::<u8>()
+                .map(|day| PuzzleId::new(year, day))
+                .map_err(|_| TableError::new(format!("Invalid day '{}.{}'", year, day_key)))?;
+            let values = match values {
+                toml::Value::Table(values) => values,
+                _ => return Err(TableError::new(format!("{} is not a table", id))),
+            };
+
+            // 5 and 05 are different keys to TOML but the same day
+            if seen.contains(&id) {
+                return Err(TableError::new(format!("{} is given twice", id)));
+            }
+            seen.push(id);
+
+            entries.extend(values.into_iter().map(|(key, value)| (id, key, value)));
+        }
+    }
+
+    Ok(entries)
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TableError {
+    msg: String,
+}
+
+impl TableError {
+    pub(crate) fn new(msg: String) -> Self {
+        Self { msg }
+    }
+}
+
+impl Display for TableError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.msg)
+    }
+}
+
+impl std::error::Error for TableError {}
@@ -0,0 +1,187 @@
+use std::{
+    any::Any,
+    fmt::Display,
+    panic::{self, AssertUnwindSafe},
+};
+
+use crate::{AnySolution, ExpectedAnswers, Part, PuzzleId, PuzzleInput, SolutionError};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Verdict {
+    Pass,
+    Fail { expected: String, actual: String },
+    Unverified { actual: String },
+    NotImplemented,
+    MissingInput(String),
+    Error(SolutionError),
+    Panic(String),
+}
+
+impl Verdict {
+    pub fn is_regression(&self) -> bool {
+        matches!(
+            self,
+            Verdict::Fail { .. } | Verdict::MissingInput(_) | Verdict::Error(_) | Verdict::Panic(_)
+        )
+    }
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Verdict::Pass => f.write_str("PASS"),
+            Verdict::Fail { expected, actual } => {
+                write!(
+                    f,
+                    "FAIL (expected {}, got {})",
+                    single_line(expected),
+                    single_line(actual)
+                )
+            }
+            Verdict::Unverified { actual } => {
+                write!(f, "UNVERIFIED (got {})", single_line(actual))
+            }
+            Verdict::NotImplemented => f.write_str("SKIP (not implemented)"),
+            Verdict::MissingInput(msg) => write!(f, "NO INPUT ({})", msg),
+            Verdict::Error(e) => write!(f, "ERROR ({})", e),
+            Verdict::Panic(msg) => write!(f, "PANIC ({})", msg),
+        }
+    }
+}
+
+// parses once and checks every part, a panicking day is reported instead of aborting the run
+pub fn verify(
+    id: PuzzleId,
+    solution: &dyn AnySolution,
+    input: &PuzzleInput,
+    expected: &ExpectedAnswers,
+) -> Vec<(Part, Verdict)> {
+    let parsed = match panic::catch_unwind(AssertUnwindSafe(|| solution.parse_any(input))) {
+        Ok(Ok(parsed)) => parsed,
+        Ok(Err(e)) => return for_all_parts(solution, |_| Verdict::Error(e.clone())),
+        Err(payload) => {
+            let msg = panic_message(payload.as_ref());
+            return for_all_parts(solution, |_| Verdict::Panic(msg.clone()));
+        }
+    };
+
+    for_all_parts(solution, |part| {
+        let result = panic::catch_unwind(AssertUnwindSafe(|| {
+            solution.solve_any(part, parsed.as_ref())
+        }));
+
+        match result {
+            Ok(Ok(answer)) => check(answer.as_str(), expected.get(id, part)),
+            Ok(Err(SolutionError::NotImplemented)) => Verdict::NotImplemented,
+            Ok(Err(e)) => Verdict::Error(e),
+            Err(payload) => Verdict::Panic(panic_message(payload.as_ref())),
+        }
+    })
+}
+
+pub fn check(actual: &str, expected: Option<&str>) -> Verdict {
+    match expected {
+        Some(expected) if expected == actual => Verdict::Pass,
+        Some(expected) => Verdict::Fail {
+            expected: expected.to_string(),
+            actual: actual.to_string(),
+        },
+        None => Verdict::Unverified {
+            actual: actual.to_string(),
+        },
+    }
+}
+
+fn for_all_parts(
+    solution: &dyn AnySolution,
+    mut verdict: impl FnMut(Part) -> Verdict,
+) -> Vec<(Part, Verdict)> {
+    Part::ALL
+        .iter()
+        .map(|&part| {
+            if solution.implements(part) {
+                (part, verdict(part))
+            } else {
+                (part, Verdict::NotImplemented)
+            }
+        })
+        .collect()
+}
+
+// pictures drawn by some days would break up a table row
+fn single_line(answer: &str) -> String {
+    let answer = answer.trim();
+    if answer.contains('\n') {
+        format!("{} lines of output", answer.lines().count())
+    } else {
+        answer.to_string()
+    }
+}
+
+pub fn panic_message(payload: &(dyn Any + Send)) -> String {
+    if let Some(msg) = payload.downcast_ref::<&str>() {
+        msg.to_string()
+    } else if let Some(msg) = payload.downcast_ref::<String>() {
+        msg.clone()
+    } else {
+        String::from("unknown panic")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::FnSolution;
+
+    #[test]
+    fn each_part_gets_its_own_verdict() {
+        let id = PuzzleId::new(2023, 1);
+        let mut expected = ExpectedAnswers::default();
+        expected.insert(id, Part::One, "3");
+        let solution = FnSolution::raw()
+            .part1(|text| text.lines().count())
+            .part2(|text| text.len());
+
+        let verdicts = verify(id, &solution, &PuzzleInput::from_text("a\nb\nc"), &expected);
+        assert_eq!(
+            vec![
+                (Part::One, Verdict::Pass),
+                (
+                    Part::Two,
+                    Verdict::Unverified {
+                        actual: "5".to_string()
+                    }
+                )
+            ],
+            verdicts
+        );
+
+        expected.insert(id, Part::Two, "4");
+        let verdicts = verify(id, &solution, &PuzzleInput::from_text("a\nb\nc"), &expected);
+        assert!(verdicts[1].1.is_regression());
+    }
+
+    #[test]
+    fn panics_are_reported_as_verdicts() {
+        let id = PuzzleId::new(2019, 1);
+        let solution = FnSolution::raw().part1(|_| -> u32 { panic!("boom") });
+
+        let previous_hook = panic::take_hook();
+        panic::set_hook(Box::new(|_| {}));
+        let verdicts = verify(
+            id,
+            &solution,
+            &PuzzleInput::from_text(""),
+            &ExpectedAnswers::default(),
+        );
+        panic::set_hook(previous_hook);
+
+        assert_eq!(
+            vec![
+                (Part::One, Verdict::Panic("boom".to_string())),
+                (Part::Two, Verdict::NotImplemented)
+            ],
+            verdicts
+        );
+    }
+}
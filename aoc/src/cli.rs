@@ -0,0 +1,444 @@
+use std::{fmt::Display, path::PathBuf};
+
+use aoc_core::{BenchOptions, Part, PuzzleId};
+
+pub const USAGE: &str = "Usage: aoc <command> [options]
+
+Commands:
+    run YEAR DAY     run a single day
+    run YEAR         run every registered day of a year
+    run --all        run every registered day
+    verify YEAR DAY  check a single day against the expected answers
+    verify YEAR      check every registered day of a year
+    verify --all     check every registered day
+    bench YEAR DAY   time parsing and both parts of a single day
+    bench YEAR       time every registered day of a year
+    bench --all      time every registered day
+    list             list registered days and their implemented parts
+    help             print this message
+
+Options for run:
+    --part P         part to execute: 1 or 2 (default: both)
+    --input PATH     file with puzzle input, only for a single day, - for stdin
+
+Inputs are looked up as {year}/day{NN}.txt under $AOC_INPUT_DIR, or under the
+input_dir set in aoc.toml in the repository root, or under input/ there, and then
+in each year's own input folder. A single day with no input file reads stdin.
+
+Only 2022 and 2023 are built by default. 2018 to 2020 need a nightly toolchain old
+enough for their features and are added with --features year-2018, year-2019 or
+year-2020, `list` shows which years a build leaves out.
+
+Options for verify:
+    --answers PATH   TOML file with expected answers (default: answers.toml in the repository root)
+
+Options for bench:
+    --warmup N       untimed runs before measuring (default: 1)
+    --iterations N   timed runs, min/median/max are taken over these (default: 10)
+    --save PATH      store medians as a baseline, merging with an existing file
+    --baseline PATH  compare medians against an earlier saved baseline";
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Command {
+    Run(RunArgs),
+    Verify(VerifyArgs),
+    Bench(BenchArgs),
+    List,
+    Help,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct RunArgs {
+    pub target: Target,
+    pub part: Option<Part>,
+    pub input: Option<PathBuf>,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct VerifyArgs {
+    pub target: Target,
+    pub answers: Option<PathBuf>,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct BenchArgs {
+    pub target: Target,
+    pub options: BenchOptions,
+    pub save: Option<PathBuf>,
+    pub baseline: Option<PathBuf>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Target {
+    All,
+    Year(u16),
+    Day(PuzzleId),
+}
+
+impl Target {
+    pub fn includes(&self, id: PuzzleId) -> bool {
+        match self {
+            Target::All => true,
+            Target::Year(year) => id.year == *year,
+            Target::Day(day) => *day == id,
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum CliError {
+    MissingCommand,
+    UnknownCommand(String),
+    MissingTarget(String),
+    MissingValue(String),
+    UnknownArgument(String),
+    InvalidYear(String),
+    InvalidDay(String),
+    InvalidPart(String),
+    InvalidCount(String, String),
+    InputNeedsSingleDay,
+}
+
+impl Display for CliError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CliError::MissingCommand => f.write_str("Missing command"),
+            CliError::UnknownCommand(cmd) => write!(f, "Unknown command '{}'", cmd),
+            CliError::MissingTarget(cmd) => {
+                write!(f, "Missing YEAR [DAY] or --all for {}", cmd)
+            }
+            CliError::MissingValue(opt) => write!(f, "Missing value for argument {}", opt),
+            CliError::UnknownArgument(arg) => write!(f, "Unknown argument '{}'", arg),
+            CliError::InvalidYear(arg) => write!(f, "Wrong argument for selecting year: '{}'", arg),
+            CliError::InvalidDay(arg) => {
+                write!(
+                    f,
+                    "Wrong argument for selecting day: '{}', expected 1-25",
+                    arg
+                )
+            }
+            CliError::InvalidPart(arg) => {
+                write!(
+                    f,
+                    "Wrong argument for selecting part: '{}', expected 1 or 2",
+                    arg
+                )
+            }
+            CliError::InvalidCount(opt, arg) => {
+                write!(
+                    f,
+                    "Wrong argument for {}: '{}', expected a number",
+                    opt, arg
+                )
+            }
+            CliError::InputNeedsSingleDay => {
+                f.write_str("--input can only be used when running a single day")
+            }
+        }
+    }
+}
+
+impl std::error::Error for CliError {}
+
+pub fn parse_args(args: impl IntoIterator<Item = String>) -> Result<Command, CliError> {
+    let mut args = args.into_iter();
+
+    match args.next().as_deref() {
+        Some("run") => parse_run(args).map(Command::Run),
+        Some("verify") => parse_verify(args).map(Command::Verify),
+        Some("bench") => parse_bench(args).map(Command::Bench),
+        Some("list") => match args.next() {
+            None => Ok(Command::List),
+            Some(arg) => Err(CliError::UnknownArgument(arg)),
+        },
+        Some("help" | "--help" | "-h") => Ok(Command::Help),
+        Some(cmd) => Err(CliError::UnknownCommand(cmd.to_owned())),
+        None => Err(CliError::MissingCommand),
+    }
+}
+
+fn parse_run(mut args: impl Iterator<Item = String>) -> Result<RunArgs, CliError> {
+    let mut all = false;
+    let mut positional = Vec::new();
+    let mut part = None;
+    let mut input = None;
+
+    while let Some(arg) = args.next() {
+        let (name, inline_value) = match arg.split_once('=') {
+            Some((name, value)) => (name.to_owned(), Some(value.to_owned())),
+            None => (arg.clone(), None),
+        };
+
+        match name.as_str() {
+            "--all" | "-a" => all = true,
+            "--part" | "-p" => {
+                let value = take_value(name, inline_value, &mut args)?;
+                part = Some(value.parse().map_err(|_| CliError::InvalidPart(value))?);
+            }
+            "--input" | "-i" => {
+                let value = take_value(name, inline_value, &mut args)?;
+                input = Some(PathBuf::from(value));
+            }
+            _ if name.starts_with('-') => return Err(CliError::UnknownArgument(arg)),
+            _ => positional.push(arg),
+        }
+    }
+
+    let target = parse_target("run", all, &positional)?;
+    if input.is_some() && !matches!(target, Target::Day(_)) {
+        return Err(CliError::InputNeedsSingleDay);
+    }
+
+    Ok(RunArgs {
+        target,
+        part,
+        input,
+    })
+}
+
+fn parse_verify(mut args: impl Iterator<Item = String>) -> Result<VerifyArgs, CliError> {
+    let mut all = false;
+    let mut positional = Vec::new();
+    let mut answers = None;
+
+    while let Some(arg) = args.next() {
+        let (name, inline_value) = match arg.split_once('=') {
+            Some((name, value)) => (name.to_owned(), Some(value.to_owned())),
+            None => (arg.clone(), None),
+        };
+
+        match name.as_str() {
+            "--all" | "-a" => all = true,
+            "--answers" => {
+                let value = take_value(name, inline_value, &mut args)?;
+                answers = Some(PathBuf::from(value));
+            }
+            _ if name.starts_with('-') => return Err(CliError::UnknownArgument(arg)),
+            _ => positional.push(arg),
+        }
+    }
+
+    Ok(VerifyArgs {
+        target: parse_target("verify", all, &positional)?,
+        answers,
+    })
+}
+
+fn parse_bench(mut args: impl Iterator<Item = String>) -> Result<BenchArgs, CliError> {
+    let mut all = false;
+    let mut positional = Vec::new();
+    let mut options = BenchOptions::default();
+    let mut save = None;
+    let mut baseline = None;
+
+    while let Some(arg) = args.next() {
+        let (name, inline_value) = match arg.split_once('=') {
+            Some((name, value)) => (name.to_owned(), Some(value.to_owned())),
+            None => (arg.clone(), None),
+        };
+
+        match name.as_str() {
+            "--all" | "-a" => all = true,
+            "--warmup" => {
+                let value = take_value(name.clone(), inline_value, &mut args)?;
+                options.warmup = value
+                    .parse()
+                    .map_err(|_| CliError::InvalidCount(name, value))?;
+            }
+            "--iterations" | "-n" => {
+                let value = take_value(name.clone(), inline_value, &mut args)?;
+                options.iterations = match value.parse() {
+                    Ok(n) if n > 0 => n,
+                    _ => return Err(CliError::InvalidCount(name, value)),
+                };
+            }
+            "--save" => {
+                let value = take_value(name, inline_value, &mut args)?;
+                save = Some(PathBuf::from(value));
+            }
+            "--baseline" => {
+                let value = take_value(name, inline_value, &mut args)?;
+                baseline = Some(PathBuf::from(value));
+            }
+            _ if name.starts_with('-') => return Err(CliError::UnknownArgument(arg)),
+            _ => positional.push(arg),
+        }
+    }
+
+    Ok(BenchArgs {
+        target: parse_target("bench", all, &positional)?,
+        options,
+        save,
+        baseline,
+    })
+}
+
+// value either follows the option or is given inline as --option=value
+fn take_value(
+    name: String,
+    inline_value: Option<String>,
+    args: &mut impl Iterator<Item = String>,
+) -> Result<String, CliError> {
+    inline_value
+        .or_else(|| args.next())
+        .ok_or(CliError::MissingValue(name))
+}
+
+fn parse_target(cmd: &str, all: bool, positional: &[String]) -> Result<Target, CliError> {
+    match (all, positional) {
+        (true, []) => Ok(Target::All),
+        (false, [year]) => Ok(Target::Year(parse_year(year)?)),
+        (false, [year, day]) => Ok(Target::Day(PuzzleId::new(
+            parse_year(year)?,
+            parse_day(day)?,
+        ))),
+        (false, []) => Err(CliError::MissingTarget(cmd.to_owned())),
+        (_, [.., extra]) => Err(CliError::UnknownArgument(extra.clone())),
+    }
+}
+
+// accepts both 2019 and the short 19
+fn parse_year(arg: &str) -> Result<u16, CliError> {
+    match arg.parse::<u16>() {
+        Ok(year @ 15..=99) => Ok(2000 + year),
+        Ok(year @ 2015..=2099) => Ok(year),
+        _ => Err(CliError::InvalidYear(arg.to_owned())),
+    }
+}
+
+// accepts both plain numbers and prefixed forms such as "day5"
+fn parse_day(arg: &str) -> Result<u8, CliError> {
+    match arg
+        .trim_start_matches(|c: char| !c.is_ascii_digit())
+        .parse::<u8>()
+    {
+        Ok(day @ 1..=25) => Ok(day),
+        _ => Err(CliError::InvalidDay(arg.to_owned())),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(line: &str) -> Result<Command, CliError> {
+        parse_args(line.split_whitespace().map(str::to_owned))
+    }
+
+    #[test]
+    fn run_single_day_with_part() {
+        assert_eq!(
+            Ok(Command::Run(RunArgs {
+                target: Target::Day(PuzzleId::new(2019, 5)),
+                part: Some(Part::Two),
+                input: None,
+            })),
+            parse("run 2019 5 --part 2")
+        );
+        assert_eq!(
+            Ok(Command::Run(RunArgs {
+                target: Target::Day(PuzzleId::new(2022, 13)),
+                part: None,
+                input: Some(PathBuf::from("in.txt")),
+            })),
+            parse("run 22 day13 --input=in.txt")
+        );
+    }
+
+    #[test]
+    fn run_whole_year_or_everything() {
+        assert!(matches!(
+            parse("run 2020"),
+            Ok(Command::Run(RunArgs {
+                target: Target::Year(2020),
+                ..
+            }))
+        ));
+        assert!(matches!(
+            parse("run --all -p 1"),
+            Ok(Command::Run(RunArgs {
+                target: Target::All,
+                part: Some(Part::One),
+                ..
+            }))
+        ));
+        assert_eq!(Ok(Command::List), parse("list"));
+    }
+
+    #[test]
+    fn verify_with_answers_file() {
+        assert_eq!(
+            Ok(Command::Verify(VerifyArgs {
+                target: Target::All,
+                answers: Some(PathBuf::from("mine.toml")),
+            })),
+            parse("verify --all --answers mine.toml")
+        );
+        assert_eq!(
+            Ok(Command::Verify(VerifyArgs {
+                target: Target::Day(PuzzleId::new(2020, 3)),
+                answers: None,
+            })),
+            parse("verify 20 3")
+        );
+        assert_eq!(
+            Err(CliError::MissingTarget("verify".into())),
+            parse("verify")
+        );
+        assert_eq!(
+            Err(CliError::UnknownArgument("--part".into())),
+            parse("verify 2020 --part 1")
+        );
+    }
+
+    #[test]
+    fn bench_options() {
+        assert_eq!(
+            Ok(Command::Bench(BenchArgs {
+                target: Target::Day(PuzzleId::new(2018, 9)),
+                options: BenchOptions {
+                    warmup: 0,
+                    iterations: 5,
+                },
+                save: Some(PathBuf::from("base.toml")),
+                baseline: None,
+            })),
+            parse("bench 2018 9 --warmup=0 -n 5 --save base.toml")
+        );
+        assert!(matches!(
+            parse("bench --all --baseline base.toml"),
+            Ok(Command::Bench(BenchArgs {
+                target: Target::All,
+                baseline: Some(_),
+                ..
+            }))
+        ));
+        assert_eq!(
+            Err(CliError::InvalidCount("-n".into(), "0".into())),
+            parse("bench 2018 -n 0")
+        );
+        assert_eq!(
+            Err(CliError::MissingValue("--save".into())),
+            parse("bench 2018 --save")
+        );
+    }
+
+    #[test]
+    fn invalid_arguments_are_reported() {
+        assert_eq!(Err(CliError::MissingCommand), parse(""));
+        assert_eq!(Err(CliError::MissingTarget("run".into())), parse("run"));
+        assert_eq!(Err(CliError::InvalidDay("26".into())), parse("run 2019 26"));
+        assert_eq!(
+            Err(CliError::InvalidPart("3".into())),
+            parse("run 2019 1 -p 3")
+        );
+        assert_eq!(
+            Err(CliError::InputNeedsSingleDay),
+            parse("run --all --input x")
+        );
+        assert_eq!(
+            Err(CliError::UnknownArgument("5".into())),
+            parse("run --all 5")
+        );
+    }
+}
@@ -0,0 +1,85 @@
+use std::{
+    env, fs,
+    io::{self, IsTerminal, Read},
+    path::{Path, PathBuf},
+    process,
+};
+
+use aoc_core::{InputError, InputResolver, PuzzleId, PuzzleInput};
+
+use crate::{cli::Target, years};
+
+// a path of "-" reads stdin, which is also the last resort when a single day has no input file
+pub struct Inputs {
+    resolver: InputResolver,
+    stdin_fallback: bool,
+    // stdin is copied here, days that still open their input file themselves need a path
+    spool: PathBuf,
+}
+
+impl Inputs {
+    pub fn new(target: Target) -> Result<Self, InputError> {
+        Ok(Self {
+            resolver: years::input_resolver()?,
+            stdin_fallback: matches!(target, Target::Day(_)) && !io::stdin().is_terminal(),
+            spool: env::temp_dir().join(format!("aoc-stdin-{}.txt", process::id())),
+        })
+    }
+
+    pub fn load(&self, id: PuzzleId, explicit: Option<&Path>) -> Result<PuzzleInput, InputError> {
+        match explicit {
+            Some(path) if path == Path::new("-") => self.read_stdin(),
+            Some(path) => PuzzleInput::from_file(path).map_err(|e| InputError::Read {
+                path: path.to_path_buf(),
+                msg: e.to_string(),
+            }),
+            None => match self.resolver.resolve(id) {
+                Err(e @ InputError::NotFound { .. }) if self.stdin_fallback => {
+                    // nothing piped in means there was no input after all
+                    let input = self.read_stdin()?;
+                    if input.text().is_empty() {
+                        Err(e)
+                    } else {
+                        Ok(input)
+                    }
+                }
+                result => result,
+            },
+        }
+    }
+
+    fn read_stdin(&self) -> Result<PuzzleInput, InputError> {
+        let read_error = |path: &Path, e: io::Error| InputError::Read {
+            path: path.to_path_buf(),
+            msg: e.to_string(),
+        };
+
+        let mut text = String::new();
+        io::stdin()
+            .read_to_string(&mut text)
+            .map_err(|e| read_error(Path::new("<stdin>"), e))?;
+        fs::write(&self.spool, text).map_err(|e| read_error(&self.spool, e))?;
+        PuzzleInput::from_file(&self.spool).map_err(|e| read_error(&self.spool, e))
+    }
+}
+
+impl Drop for Inputs {
+    fn drop(&mut self) {
+        // usually never written
+        let _ = fs::remove_file(&self.spool);
+    }
+}
+
+// single line version of the error for table rows
+pub fn short_error(e: &InputError) -> String {
+    match e {
+        InputError::NotFound { tried, .. } => {
+            let tried = tried
+                .iter()
+                .map(|path| path.display().to_string())
+                .collect::<Vec<_>>();
+            format!("not found, tried {}", tried.join(", "))
+        }
+        e => e.to_string(),
+    }
+}
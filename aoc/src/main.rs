@@ -0,0 +1,320 @@
+use std::{panic, time::Duration};
+
+use aoc_core::{
+    bench, verify, AnySolution, Baseline, ExpectedAnswers, Part, PuzzleId, Registry, Step,
+    Verdict,
+};
+
+use crate::{
+    cli::{parse_args, BenchArgs, Command, RunArgs, Target, VerifyArgs},
+    inputs::{short_error, Inputs},
+};
+
+mod cli;
+mod inputs;
+mod years;
+
+fn main() {
+    let command = match parse_args(std::env::args().skip(1)) {
+        Ok(command) => command,
+        Err(e) => {
+            eprintln!("{}\n\n{}", e, cli::USAGE);
+            std::process::exit(2);
+        }
+    };
+
+    let registry = years::registry();
+    let all_ok = match command {
+        Command::Help => {
+            println!("{}", cli::USAGE);
+            true
+        }
+        Command::List => {
+            list(&registry);
+            true
+        }
+        Command::Run(args) => run(&registry, &args),
+        Command::Verify(args) => verify_all(&registry, &args),
+        Command::Bench(args) => bench_all(&registry, &args),
+    };
+
+    if !all_ok {
+        std::process::exit(1);
+    }
+}
+
+fn list(registry: &Registry) {
+    for year in registry.years() {
+        println!("{}", year);
+        for (id, solution) in registry.year(year) {
+            let parts = Part::ALL
+                .iter()
+                .map(|&part| {
+                    if solution.implements(part) {
+                        format!("part {}", part)
+                    } else {
+                        String::from("------")
+                    }
+                })
+                .collect::<Vec<_>>();
+            println!("    day {:02}  {}", id.day, parts.join("  "));
+        }
+    }
+    for (year, feature) in years::disabled() {
+        println!("{}  disabled, build with --features {}", year, feature);
+    }
+}
+
+fn select(registry: &Registry, target: Target) -> Vec<(PuzzleId, &dyn AnySolution)> {
+    let selected = registry
+        .iter()
+        .filter(|(id, _)| target.includes(*id))
+        .collect::<Vec<_>>();
+
+    if selected.is_empty() {
+        match target {
+            Target::All => eprintln!("No solutions are registered"),
+            Target::Year(year) => eprintln!(
+                "No solutions are registered for {}{}",
+                year,
+                years::feature_hint(year)
+            ),
+            Target::Day(id) => eprintln!(
+                "No solution is registered for {}{}",
+                id,
+                years::feature_hint(id.year)
+            ),
+        }
+    }
+    selected
+}
+
+fn run(registry: &Registry, args: &RunArgs) -> bool {
+    let selected = select(registry, args.target);
+    if selected.is_empty() {
+        return false;
+    }
+
+    let inputs = match Inputs::new(args.target) {
+        Ok(inputs) => inputs,
+        Err(e) => {
+            eprintln!("{}", e);
+            return false;
+        }
+    };
+
+    let mut all_ok = true;
+    for (id, solution) in selected {
+        all_ok &= run_day(id, solution, &inputs, args);
+    }
+    all_ok
+}
+
+fn run_day(id: PuzzleId, solution: &dyn AnySolution, inputs: &Inputs, args: &RunArgs) -> bool {
+    println!("{}", id);
+
+    let input = match inputs.load(id, args.input.as_deref()) {
+        Ok(input) => input,
+        Err(e) => {
+            println!("\t{}", e.to_string().replace('\n', "\n\t"));
+            return false;
+        }
+    };
+
+    let parsed = match solution.parse_any(&input) {
+        Ok(parsed) => parsed,
+        Err(e) => {
+            println!("\t{}", e);
+            return false;
+        }
+    };
+
+    let mut all_ok = true;
+    for part in Part::ALL {
+        if args.part.is_some_and(|p| p != part) {
+            continue;
+        }
+
+        // a year run skips missing parts silently, asking for one explicitly is an error
+        if !solution.implements(part) {
+            if args.part.is_some() {
+                println!("Part {}:\n\tNot implemented", part);
+                all_ok = false;
+            }
+            continue;
+        }
+
+        match solution.solve_any(part, parsed.as_ref()) {
+            Ok(answer) => println!("Part {}:\n\t{}", part, answer),
+            Err(e) => {
+                println!("Part {}:\n\t{}", part, e);
+                all_ok = false;
+            }
+        }
+    }
+    all_ok
+}
+
+fn verify_all(registry: &Registry, args: &VerifyArgs) -> bool {
+    let selected = select(registry, args.target);
+    if selected.is_empty() {
+        return false;
+    }
+
+    let answers_path = args.answers.clone().unwrap_or_else(years::default_answers);
+    let expected = match ExpectedAnswers::from_file(&answers_path) {
+        Ok(expected) => expected,
+        Err(e) => {
+            eprintln!("{}", e);
+            return false;
+        }
+    };
+    let inputs = match Inputs::new(args.target) {
+        Ok(inputs) => inputs,
+        Err(e) => {
+            eprintln!("{}", e);
+            return false;
+        }
+    };
+
+    // panics are reported in the table, the default hook would only scramble it
+    let previous_hook = panic::take_hook();
+    panic::set_hook(Box::new(|_| {}));
+
+    println!("{:<6}{:<5}{:<6}RESULT", "YEAR", "DAY", "PART");
+    let mut counts = [0usize; 3];
+    for (id, solution) in selected {
+        let verdicts = match inputs.load(id, None) {
+            Ok(input) => verify(id, solution, &input, &expected),
+            Err(e) => Part::ALL
+                .iter()
+                .filter(|&&part| solution.implements(part))
+                .map(|&part| (part, Verdict::MissingInput(short_error(&e))))
+                .collect(),
+        };
+
+        for (part, verdict) in verdicts {
+            if verdict == Verdict::NotImplemented {
+                continue;
+            }
+            println!("{:<6}{:<5}{:<6}{}", id.year, id.day, part.number(), verdict);
+
+            let slot = match verdict {
+                Verdict::Pass => 0,
+                _ if verdict.is_regression() => 2,
+                _ => 1,
+            };
+            counts[slot] += 1;
+        }
+    }
+
+    panic::set_hook(previous_hook);
+
+    println!(
+        "\n{} passed, {} unverified, {} failed",
+        counts[0], counts[1], counts[2]
+    );
+    counts[2] == 0
+}
+
+fn bench_all(registry: &Registry, args: &BenchArgs) -> bool {
+    let selected = select(registry, args.target);
+    if selected.is_empty() {
+        return false;
+    }
+
+    let baseline = match &args.baseline {
+        Some(path) => match Baseline::from_file(path) {
+            Ok(baseline) => Some(baseline),
+            Err(e) => {
+                eprintln!("{}", e);
+                return false;
+            }
+        },
+        None => None,
+    };
+
+    // runs of a few days at a time add up to a full baseline
+    let mut measured = match &args.save {
+        Some(path) if path.exists() => match Baseline::from_file(path) {
+            Ok(saved) => saved,
+            Err(e) => {
+                eprintln!("{}", e);
+                return false;
+            }
+        },
+        _ => Baseline::default(),
+    };
+    let inputs = match Inputs::new(args.target) {
+        Ok(inputs) => inputs,
+        Err(e) => {
+            eprintln!("{}", e);
+            return false;
+        }
+    };
+
+    println!(
+        "{:<6}{:<5}{:<7}{:>12}{:>12}{:>12}{:>10}",
+        "YEAR", "DAY", "STEP", "MIN", "MEDIAN", "MAX", "CHANGE"
+    );
+    let mut all_ok = true;
+    for (id, solution) in selected {
+        let timings = inputs
+            .load(id, None)
+            .map_err(|e| short_error(&e))
+            .and_then(|input| bench(solution, &input, args.options).map_err(|e| e.to_string()));
+
+        let timings = match timings {
+            Ok(timings) => timings,
+            Err(e) => {
+                println!("{:<6}{:<5}{}", id.year, id.day, e);
+                all_ok = false;
+                continue;
+            }
+        };
+
+        for (step, stats) in timings {
+            // timing how long it takes to hand over a path says nothing about parsing
+            if step == Step::Parse && !solution.parses_input() {
+                println!(
+                    "{:<6}{:<5}{:<7}{:>12}{:>12}{:>12}",
+                    id.year, id.day, step, "n/a", "n/a", "n/a"
+                );
+                continue;
+            }
+
+            let change = baseline
+                .as_ref()
+                .and_then(|baseline| baseline.get(id, step))
+                .map(|before| relative_change(before, stats.median))
+                .unwrap_or_default();
+            println!(
+                "{:<6}{:<5}{:<7}{:>12}{:>12}{:>12}{:>10}",
+                id.year,
+                id.day,
+                step,
+                format!("{:.1?}", stats.min),
+                format!("{:.1?}", stats.median),
+                format!("{:.1?}", stats.max),
+                change
+            );
+            measured.insert(id, step, stats.median);
+        }
+    }
+
+    if let Some(path) = &args.save {
+        if let Err(e) = measured.save(path) {
+            eprintln!("Could not save baseline '{}': {}", path.display(), e);
+            return false;
+        }
+    }
+    all_ok
+}
+
+fn relative_change(before: Duration, after: Duration) -> String {
+    if before.is_zero() {
+        return String::from("n/a");
+    }
+    let change = (after.as_secs_f64() / before.as_secs_f64() - 1.0) * 100.0;
+    format!("{:+.1}%", change)
+}
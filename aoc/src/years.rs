@@ -0,0 +1,72 @@
+use std::path::{Path, PathBuf};
+
+use aoc_core::{InputError, InputResolver, PuzzleId, Registry};
+
+const REPO_ROOT: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/..");
+
+const FEATURES: [(u16, &str, bool); 5] = [
+    (2018, "year-2018", cfg!(feature = "year-2018")),
+    (2019, "year-2019", cfg!(feature = "year-2019")),
+    (2020, "year-2020", cfg!(feature = "year-2020")),
+    (2022, "year-2022", cfg!(feature = "year-2022")),
+    (2023, "year-2023", cfg!(feature = "year-2023")),
+];
+
+pub fn registry() -> Registry {
+    let mut registry = Registry::new();
+
+    #[cfg(feature = "year-2018")]
+    solutions::registry::register(&mut registry);
+    #[cfg(feature = "year-2019")]
+    aoc_19::registry::register(&mut registry);
+    #[cfg(feature = "year-2020")]
+    aoc_20::registry::register(&mut registry);
+    #[cfg(feature = "year-2022")]
+    aoc_22::registry::register(&mut registry);
+    #[cfg(feature = "year-2023")]
+    aoc_23::registry::register(&mut registry);
+
+    registry
+}
+
+// years left out of this build, with the feature that brings each of them back
+pub fn disabled() -> impl Iterator<Item = (u16, &'static str)> {
+    FEATURES
+        .iter()
+        .filter(|(_, _, enabled)| !enabled)
+        .map(|&(year, feature, _)| (year, feature))
+}
+
+pub fn feature_hint(year: u16) -> String {
+    disabled()
+        .find(|&(disabled, _)| disabled == year)
+        .map(|(_, feature)| format!(", build with --features {} to include it", feature))
+        .unwrap_or_default()
+}
+
+pub fn input_resolver() -> Result<InputResolver, InputError> {
+    let root = Path::new(REPO_ROOT);
+    Ok(
+        InputResolver::configured(&root.join("aoc.toml"), root.join("input"))?
+            .with_fallback(legacy_input),
+    )
+}
+
+// where every year crate kept its inputs before they had a common root
+fn legacy_input(id: PuzzleId) -> Option<PathBuf> {
+    let root = Path::new(REPO_ROOT);
+    let day = id.day;
+
+    match id.year {
+        2018 => Some(root.join(format!("aoc-18/solutions/input/day{day}"))),
+        2019 => Some(root.join(format!("aoc-19/input/day{day}.txt"))),
+        2020 => Some(root.join(format!("aoc-20/input/day_{day:02}.input.txt"))),
+        2022 => Some(root.join(format!("aoc-22/input/day{day:02}.part1.part2.txt"))),
+        2023 => Some(root.join(format!("aoc-23/src/bin/input{day}.txt"))),
+        _ => None,
+    }
+}
+
+pub fn default_answers() -> PathBuf {
+    Path::new(REPO_ROOT).join("answers.toml")
+}